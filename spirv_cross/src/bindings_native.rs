@@ -58,6 +58,8 @@ pub mod root {
             ExecutionModelClosestHitKHR = 5316,
             ExecutionModelMissKHR = 5317,
             ExecutionModelCallableKHR = 5318,
+            ExecutionModelTaskEXT = 5364,
+            ExecutionModelMeshEXT = 5365,
             ExecutionModelMax = 2147483647,
         }
         pub const AddressingModel_AddressingModelLogical: root::spv::AddressingModel = 0;
@@ -124,12 +126,15 @@ pub mod root {
         pub const ExecutionMode_ExecutionModeStencilRefReplacingEXT: root::spv::ExecutionMode =
             5027;
         pub const ExecutionMode_ExecutionModeOutputLinesNV: root::spv::ExecutionMode = 5269;
+        pub const ExecutionMode_ExecutionModeOutputLinesEXT: root::spv::ExecutionMode = 5269;
         pub const ExecutionMode_ExecutionModeOutputPrimitivesNV: root::spv::ExecutionMode = 5270;
+        pub const ExecutionMode_ExecutionModeOutputPrimitivesEXT: root::spv::ExecutionMode = 5270;
         pub const ExecutionMode_ExecutionModeDerivativeGroupQuadsNV: root::spv::ExecutionMode =
             5289;
         pub const ExecutionMode_ExecutionModeDerivativeGroupLinearNV: root::spv::ExecutionMode =
             5290;
         pub const ExecutionMode_ExecutionModeOutputTrianglesNV: root::spv::ExecutionMode = 5298;
+        pub const ExecutionMode_ExecutionModeOutputTrianglesEXT: root::spv::ExecutionMode = 5298;
         pub const ExecutionMode_ExecutionModePixelInterlockOrderedEXT: root::spv::ExecutionMode =
             5366;
         pub const ExecutionMode_ExecutionModePixelInterlockUnorderedEXT: root::spv::ExecutionMode =
@@ -561,6 +566,10 @@ pub mod root {
             pub const DecorationUserSemantic: root::spv::Decoration =
                 Decoration::DecorationHlslSemanticGOOGLE;
         }
+        impl root::spv::Decoration {
+            pub const DecorationPerPrimitiveEXT: root::spv::Decoration =
+                Decoration::DecorationPerPrimitiveNV;
+        }
         #[repr(u32)]
         #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
         pub enum Decoration {
@@ -792,6 +801,10 @@ pub mod root {
             BuiltInBaryCoordNoPerspNV = 5287,
             BuiltInFragSizeEXT = 5292,
             BuiltInFragInvocationCountEXT = 5293,
+            BuiltInPrimitivePointIndicesEXT = 5294,
+            BuiltInPrimitiveLineIndicesEXT = 5295,
+            BuiltInPrimitiveTriangleIndicesEXT = 5296,
+            BuiltInCullPrimitiveEXT = 5299,
             BuiltInLaunchIdKHR = 5319,
             BuiltInLaunchSizeKHR = 5320,
             BuiltInWorldRayOriginKHR = 5321,
@@ -1497,6 +1510,7 @@ pub mod root {
         pub const Capability_CapabilityFragmentFullyCoveredEXT: root::spv::Capability = 5265;
         pub const Capability_CapabilityMeshShadingNV: root::spv::Capability = 5266;
         pub const Capability_CapabilityImageFootprintNV: root::spv::Capability = 5282;
+        pub const Capability_CapabilityMeshShadingEXT: root::spv::Capability = 5283;
         pub const Capability_CapabilityFragmentBarycentricNV: root::spv::Capability = 5284;
         pub const Capability_CapabilityComputeDerivativeGroupQuadsNV: root::spv::Capability = 5288;
         pub const Capability_CapabilityFragmentDensityEXT: root::spv::Capability = 5291;
@@ -2127,6 +2141,8 @@ pub mod root {
         pub const Op_OpFragmentFetchAMD: root::spv::Op = 5012;
         pub const Op_OpReadClockKHR: root::spv::Op = 5056;
         pub const Op_OpImageSampleFootprintNV: root::spv::Op = 5283;
+        pub const Op_OpEmitMeshTasksEXT: root::spv::Op = 5294;
+        pub const Op_OpSetMeshOutputsEXT: root::spv::Op = 5295;
         pub const Op_OpGroupNonUniformPartitionNV: root::spv::Op = 5296;
         pub const Op_OpWritePackedPrimitiveIndices4x8NV: root::spv::Op = 5299;
         pub const Op_OpReportIntersectionKHR: root::spv::Op = 5334;
@@ -2566,6 +2582,7 @@ pub mod root {
     pub type ScInternalCompilerHlsl = ::std::os::raw::c_void;
     pub type ScInternalCompilerMsl = ::std::os::raw::c_void;
     pub type ScInternalCompilerGlsl = ::std::os::raw::c_void;
+    pub type ScInternalCompiledShader = ::std::os::raw::c_void;
     #[repr(u32)]
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
     pub enum ScInternalResult {
@@ -2584,6 +2601,39 @@ pub mod root {
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
+    pub struct ScGeometryExecutionModes {
+        pub invocations: u32,
+        pub max_vertices: u32,
+        pub input_primitive: root::spv::ExecutionMode,
+        pub output_primitive: root::spv::ExecutionMode,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScDepthExecutionModes {
+        pub conservative_depth: root::spv::ExecutionMode,
+        pub early_fragment_tests: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScImageType {
+        pub arrayed: bool,
+        pub multisampled: bool,
+        pub dim: root::spv::Dim,
+        pub depth: bool,
+        pub storage: bool,
+        pub format: root::spv::ImageFormat,
+        pub access: root::spv::AccessQualifier,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScMeshOutput {
+        pub id: u32,
+        pub location: u32,
+        pub built_in: root::spv::BuiltIn,
+        pub per_primitive: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
     pub struct ScBufferRange {
         pub index: ::std::os::raw::c_uint,
         pub offset: usize,
@@ -2606,6 +2656,21 @@ pub mod root {
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
+    pub struct ScHlslResourceBinding {
+        pub stage: root::spv::ExecutionModel,
+        pub desc_set: u32,
+        pub binding: u32,
+        pub cbv_register: u32,
+        pub cbv_space: u32,
+        pub uav_register: u32,
+        pub uav_space: u32,
+        pub srv_register: u32,
+        pub srv_space: u32,
+        pub sampler_register: u32,
+        pub sampler_space: u32,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
     pub struct ScHlslCompilerOptions {
         pub shader_model: i32,
         pub point_size_compat: bool,
@@ -2615,6 +2680,14 @@ pub mod root {
         pub force_storage_buffer_as_uav: bool,
         pub nonwritable_uav_texture_as_srv: bool,
         pub force_zero_initialized_variables: bool,
+        pub enable_storage_image_qualifier_deduction: bool,
+        pub support_nonzero_base_vertex_base_instance: bool,
+        pub enable_16bit_types: bool,
+        pub flatten_matrix_vertex_input_semantics: bool,
+        pub use_entry_point_name: bool,
+        pub preserve_structured_buffers: bool,
+        pub relax_nan_checks: bool,
+        pub emit_line_directives: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2638,6 +2711,35 @@ pub mod root {
         pub pad_fragment_output_components: bool,
         pub force_native_arrays: bool,
         pub force_zero_initialized_variables: bool,
+        pub check_discarded_frag_stores: bool,
+        pub readwrite_texture_fences: bool,
+        pub enable_storage_image_qualifier_deduction: bool,
+        pub texel_buffer_texture_width: u32,
+        pub pad_argument_buffer_resources: bool,
+        pub enable_decoration_binding: bool,
+        pub arrayed_subpass_input: bool,
+        pub ios_use_simdgroup_functions: bool,
+        pub use_framebuffer_fetch_subpasses: bool,
+        pub relax_nan_checks: bool,
+        pub emit_line_directives: bool,
+        pub runtime_array_rich_descriptor: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScMslCompilerOptionsUsage {
+        pub swizzle_buffer_index: bool,
+        pub buffer_size_buffer_index: bool,
+        pub output_buffer_index: bool,
+        pub patch_output_buffer_index: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScMslFeatureRequirements {
+        pub argument_buffers: bool,
+        pub function_constants: bool,
+        pub framebuffer_fetch: bool,
+        pub tessellation: bool,
+        pub ray_queries: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2659,6 +2761,9 @@ pub mod root {
         pub emit_line_directives: bool,
         pub enable_storage_image_qualifier_deduction: bool,
         pub force_zero_initialized_variables: bool,
+        pub ovr_multiview_view_count: u32,
+        pub force_flattened_io_blocks: bool,
+        pub relax_nan_checks: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2688,6 +2793,8 @@ pub mod root {
         pub push_constant_buffers: root::ScResourceArray,
         pub separate_images: root::ScResourceArray,
         pub separate_samplers: root::ScResourceArray,
+        pub acceleration_structures: root::ScResourceArray,
+        pub shader_record_buffers: root::ScResourceArray,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2705,6 +2812,10 @@ pub mod root {
         pub member_types_size: usize,
         pub array: *mut u32,
         pub array_size: usize,
+        pub array_size_literal: *mut bool,
+        pub pointer: bool,
+        pub storage: root::spv::StorageClass,
+        pub parent_type: u32,
     }
     extern "C" {
         pub fn sc_internal_get_latest_exception_message(
@@ -2731,6 +2842,63 @@ pub mod root {
             count: usize,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_add_vertex_attribute_remap(
+            compiler: *const root::ScInternalCompilerHlsl,
+            location: u32,
+            semantic: *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_set_base_vertex_instance_binding(
+            compiler: *const root::ScInternalCompilerHlsl,
+            register_index: u32,
+            register_space: u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_is_base_vertex_instance_binding_used(
+            compiler: *const root::ScInternalCompilerHlsl,
+            is_used: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_add_resource_bindings(
+            compiler: *const root::ScInternalCompilerHlsl,
+            bindings: *const root::ScHlslResourceBinding,
+            count: usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_is_resource_binding_used(
+            compiler: *const root::ScInternalCompilerHlsl,
+            model: root::spv::ExecutionModel,
+            desc_set: u32,
+            binding: u32,
+            is_used: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_remap_num_workgroups_builtin(
+            compiler: *const root::ScInternalCompilerHlsl,
+            id: *mut u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_buffer_is_counter_buffer(
+            compiler: *const root::ScInternalCompilerHlsl,
+            id: u32,
+            is_counter_buffer: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_buffer_get_counter_buffer(
+            compiler: *const root::ScInternalCompilerHlsl,
+            id: u32,
+            has_counter_buffer: *mut bool,
+            counter_id: *mut u32,
+        ) -> root::ScInternalResult;
+    }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
     pub struct ScMslConstSamplerMapping {
@@ -2757,10 +2925,66 @@ pub mod root {
             is_rasterization_disabled: *mut bool,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_set_combined_sampler_suffix(
+            compiler: *const root::ScInternalCompilerMsl,
+            suffix: *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_get_combined_sampler_suffix(
+            compiler: *const root::ScInternalCompilerMsl,
+            suffix: *mut *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_is_shader_input_used(
+            compiler: *const root::ScInternalCompilerMsl,
+            location: u32,
+            is_used: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_is_resource_binding_used(
+            compiler: *const root::ScInternalCompilerMsl,
+            model: root::spv::ExecutionModel,
+            desc_set: u32,
+            binding: u32,
+            is_used: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_buffer_requires_array_length(
+            compiler: *const root::ScInternalCompilerMsl,
+            id: u32,
+            requires_array_length: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_get_resource_indices(
+            compiler: *const root::ScInternalCompilerMsl,
+            id: u32,
+            primary: *mut u32,
+            secondary: *mut u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_get_options_usage(
+            compiler: *const root::ScInternalCompilerMsl,
+            usage: *mut root::ScMslCompilerOptionsUsage,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_get_feature_requirements(
+            compiler: *const root::ScInternalCompilerMsl,
+            requirements: *mut root::ScMslFeatureRequirements,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_msl_compile(
             compiler: *const root::ScInternalCompilerBase,
-            shader: *mut *const ::std::os::raw::c_char,
+            shader: *mut *mut root::ScInternalCompiledShader,
+            length: *mut usize,
             p_vat_overrides: *const root::spirv_cross::MSLShaderInput,
             vat_override_count: usize,
             p_res_overrides: *const root::spirv_cross::MSLResourceBinding,
@@ -2782,6 +3006,12 @@ pub mod root {
             options: *const root::ScGlslCompilerOptions,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_build_dummy_sampler_for_combined_images(
+            compiler: *const root::ScInternalCompilerBase,
+            sampler_id: *mut u32,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_glsl_build_combined_image_samplers(
             compiler: *const root::ScInternalCompilerBase,
@@ -2800,12 +3030,40 @@ pub mod root {
             str: *const ::std::os::raw::c_char,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_require_extension(
+            compiler: *const root::ScInternalCompilerBase,
+            extension: *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_remap_ext_framebuffer_fetch(
+            compiler: *const root::ScInternalCompilerBase,
+            input_attachment_index: u32,
+            color_location: u32,
+            coherent: bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_set_variable_type_remaps(
+            compiler: *const root::ScInternalCompilerBase,
+            variable_names: *const *const ::std::os::raw::c_char,
+            type_names: *const *const ::std::os::raw::c_char,
+            size: usize,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_glsl_flatten_buffer_block(
             compiler: *const root::ScInternalCompilerBase,
             id: u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_is_base_instance_used(
+            compiler: *const root::ScInternalCompilerBase,
+            is_used: *mut bool,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_decoration(
             compiler: *const root::ScInternalCompilerBase,
@@ -2822,6 +3080,14 @@ pub mod root {
             argument: u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_has_decoration(
+            compiler: *const root::ScInternalCompilerBase,
+            result: *mut bool,
+            id: u32,
+            decoration: root::spv::Decoration,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_unset_decoration(
             compiler: *const root::ScInternalCompilerBase,
@@ -2843,6 +3109,22 @@ pub mod root {
             name: *const ::std::os::raw::c_char,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_decoration_string(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            decoration: root::spv::Decoration,
+            argument: *mut *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_set_decoration_string(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            decoration: root::spv::Decoration,
+            argument: *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_entry_points(
             compiler: *const root::ScInternalCompilerBase,
@@ -2872,6 +3154,26 @@ pub mod root {
             shader_resources: *mut root::ScShaderResources,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_active_shader_resources(
+            compiler: *const root::ScInternalCompilerBase,
+            shader_resources: *mut root::ScShaderResources,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_active_interface_variables(
+            compiler: *const root::ScInternalCompilerBase,
+            ids: *mut *mut u32,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_set_enabled_interface_variables(
+            compiler: *const root::ScInternalCompilerBase,
+            ids: *const u32,
+            size: usize,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_specialization_constants(
             compiler: *const root::ScInternalCompilerBase,
@@ -2902,6 +3204,23 @@ pub mod root {
             name: *mut *const ::std::os::raw::c_char,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_set_member_name(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            index: u32,
+            name: *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_has_member_decoration(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            index: u32,
+            decoration: root::spv::Decoration,
+            result: *mut bool,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_member_decoration(
             compiler: *const root::ScInternalCompilerBase,
@@ -2920,6 +3239,14 @@ pub mod root {
             argument: u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_unset_member_decoration(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            index: u32,
+            decoration: root::spv::Decoration,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_declared_struct_size(
             compiler: *const root::ScInternalCompilerBase,
@@ -2950,6 +3277,117 @@ pub mod root {
             constants: *mut *mut root::ScSpecializationConstant,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_buffer_block_flags(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            decorations: *mut *mut u32,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_declared_capabilities(
+            compiler: *const root::ScInternalCompilerBase,
+            capabilities: *mut *mut u32,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_declared_extensions(
+            compiler: *const root::ScInternalCompilerBase,
+            extensions: *mut *mut *const ::std::os::raw::c_char,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_geometry_execution_modes(
+            compiler: *const root::ScInternalCompilerBase,
+            modes: *mut root::ScGeometryExecutionModes,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_depth_execution_modes(
+            compiler: *const root::ScInternalCompilerBase,
+            modes: *mut root::ScDepthExecutionModes,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_execution_modes(
+            compiler: *const root::ScInternalCompilerBase,
+            modes: *mut *mut root::spv::ExecutionMode,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_execution_mode_argument(
+            compiler: *const root::ScInternalCompilerBase,
+            mode: root::spv::ExecutionMode,
+            index: u32,
+            result: *mut u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_set_execution_mode(
+            compiler: *const root::ScInternalCompilerBase,
+            mode: root::spv::ExecutionMode,
+            arg0: u32,
+            arg1: u32,
+            arg2: u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_unset_execution_mode(
+            compiler: *const root::ScInternalCompilerBase,
+            mode: root::spv::ExecutionMode,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_mesh_outputs(
+            compiler: *const root::ScInternalCompilerBase,
+            outputs: *mut *mut root::ScMeshOutput,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_current_id_bound(
+            compiler: *const root::ScInternalCompilerBase,
+            bound: *mut u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_execution_model(
+            compiler: *const root::ScInternalCompilerBase,
+            execution_model: *mut root::spv::ExecutionModel,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_is_builtin_output_active(
+            compiler: *const root::ScInternalCompilerBase,
+            builtin: root::spv::BuiltIn,
+            is_active: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_active_builtins(
+            compiler: *const root::ScInternalCompilerBase,
+            builtins: *mut *mut u32,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_type_self(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            self_id: *mut u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_image_type(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            image_type: *mut root::ScImageType,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_set_entry_point(
             compiler: *const root::ScInternalCompilerBase,
@@ -2963,6 +3401,24 @@ pub mod root {
             shader: *mut *const ::std::os::raw::c_char,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_compile_retained(
+            compiler: *const root::ScInternalCompilerBase,
+            shader: *mut *mut root::ScInternalCompiledShader,
+            length: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiled_shader_copy(
+            shader: *const root::ScInternalCompiledShader,
+            buffer: *mut ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiled_shader_delete(
+            shader: *mut root::ScInternalCompiledShader,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_delete(
             compiler: *mut root::ScInternalCompilerBase,
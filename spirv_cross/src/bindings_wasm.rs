@@ -58,6 +58,8 @@ pub mod root {
             ExecutionModelClosestHitKHR = 5316,
             ExecutionModelMissKHR = 5317,
             ExecutionModelCallableKHR = 5318,
+            ExecutionModelTaskEXT = 5364,
+            ExecutionModelMeshEXT = 5365,
             ExecutionModelMax = 2147483647,
         }
         pub const AddressingModel_AddressingModelLogical: root::spv::AddressingModel = 0;
@@ -124,12 +126,15 @@ pub mod root {
         pub const ExecutionMode_ExecutionModeStencilRefReplacingEXT: root::spv::ExecutionMode =
             5027;
         pub const ExecutionMode_ExecutionModeOutputLinesNV: root::spv::ExecutionMode = 5269;
+        pub const ExecutionMode_ExecutionModeOutputLinesEXT: root::spv::ExecutionMode = 5269;
         pub const ExecutionMode_ExecutionModeOutputPrimitivesNV: root::spv::ExecutionMode = 5270;
+        pub const ExecutionMode_ExecutionModeOutputPrimitivesEXT: root::spv::ExecutionMode = 5270;
         pub const ExecutionMode_ExecutionModeDerivativeGroupQuadsNV: root::spv::ExecutionMode =
             5289;
         pub const ExecutionMode_ExecutionModeDerivativeGroupLinearNV: root::spv::ExecutionMode =
             5290;
         pub const ExecutionMode_ExecutionModeOutputTrianglesNV: root::spv::ExecutionMode = 5298;
+        pub const ExecutionMode_ExecutionModeOutputTrianglesEXT: root::spv::ExecutionMode = 5298;
         pub const ExecutionMode_ExecutionModePixelInterlockOrderedEXT: root::spv::ExecutionMode =
             5366;
         pub const ExecutionMode_ExecutionModePixelInterlockUnorderedEXT: root::spv::ExecutionMode =
@@ -561,6 +566,10 @@ pub mod root {
             pub const DecorationUserSemantic: root::spv::Decoration =
                 Decoration::DecorationHlslSemanticGOOGLE;
         }
+        impl root::spv::Decoration {
+            pub const DecorationPerPrimitiveEXT: root::spv::Decoration =
+                Decoration::DecorationPerPrimitiveNV;
+        }
         #[repr(u32)]
         #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
         pub enum Decoration {
@@ -792,6 +801,10 @@ pub mod root {
             BuiltInBaryCoordNoPerspNV = 5287,
             BuiltInFragSizeEXT = 5292,
             BuiltInFragInvocationCountEXT = 5293,
+            BuiltInPrimitivePointIndicesEXT = 5294,
+            BuiltInPrimitiveLineIndicesEXT = 5295,
+            BuiltInPrimitiveTriangleIndicesEXT = 5296,
+            BuiltInCullPrimitiveEXT = 5299,
             BuiltInLaunchIdKHR = 5319,
             BuiltInLaunchSizeKHR = 5320,
             BuiltInWorldRayOriginKHR = 5321,
@@ -1497,6 +1510,7 @@ pub mod root {
         pub const Capability_CapabilityFragmentFullyCoveredEXT: root::spv::Capability = 5265;
         pub const Capability_CapabilityMeshShadingNV: root::spv::Capability = 5266;
         pub const Capability_CapabilityImageFootprintNV: root::spv::Capability = 5282;
+        pub const Capability_CapabilityMeshShadingEXT: root::spv::Capability = 5283;
         pub const Capability_CapabilityFragmentBarycentricNV: root::spv::Capability = 5284;
         pub const Capability_CapabilityComputeDerivativeGroupQuadsNV: root::spv::Capability = 5288;
         pub const Capability_CapabilityFragmentDensityEXT: root::spv::Capability = 5291;
@@ -2127,6 +2141,8 @@ pub mod root {
         pub const Op_OpFragmentFetchAMD: root::spv::Op = 5012;
         pub const Op_OpReadClockKHR: root::spv::Op = 5056;
         pub const Op_OpImageSampleFootprintNV: root::spv::Op = 5283;
+        pub const Op_OpEmitMeshTasksEXT: root::spv::Op = 5294;
+        pub const Op_OpSetMeshOutputsEXT: root::spv::Op = 5295;
         pub const Op_OpGroupNonUniformPartitionNV: root::spv::Op = 5296;
         pub const Op_OpWritePackedPrimitiveIndices4x8NV: root::spv::Op = 5299;
         pub const Op_OpReportIntersectionKHR: root::spv::Op = 5334;
@@ -2528,6 +2544,7 @@ pub mod root {
     pub type ScInternalCompilerHlsl = ::std::os::raw::c_void;
     pub type ScInternalCompilerMsl = ::std::os::raw::c_void;
     pub type ScInternalCompilerGlsl = ::std::os::raw::c_void;
+    pub type ScInternalCompiledShader = ::std::os::raw::c_void;
     #[repr(u32)]
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
     pub enum ScInternalResult {
@@ -2546,6 +2563,39 @@ pub mod root {
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
+    pub struct ScGeometryExecutionModes {
+        pub invocations: u32,
+        pub max_vertices: u32,
+        pub input_primitive: root::spv::ExecutionMode,
+        pub output_primitive: root::spv::ExecutionMode,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScDepthExecutionModes {
+        pub conservative_depth: root::spv::ExecutionMode,
+        pub early_fragment_tests: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScImageType {
+        pub arrayed: bool,
+        pub multisampled: bool,
+        pub dim: root::spv::Dim,
+        pub depth: bool,
+        pub storage: bool,
+        pub format: root::spv::ImageFormat,
+        pub access: root::spv::AccessQualifier,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScMeshOutput {
+        pub id: u32,
+        pub location: u32,
+        pub built_in: root::spv::BuiltIn,
+        pub per_primitive: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
     pub struct ScBufferRange {
         pub index: ::std::os::raw::c_uint,
         pub offset: usize,
@@ -2568,6 +2618,21 @@ pub mod root {
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
+    pub struct ScHlslResourceBinding {
+        pub stage: root::spv::ExecutionModel,
+        pub desc_set: u32,
+        pub binding: u32,
+        pub cbv_register: u32,
+        pub cbv_space: u32,
+        pub uav_register: u32,
+        pub uav_space: u32,
+        pub srv_register: u32,
+        pub srv_space: u32,
+        pub sampler_register: u32,
+        pub sampler_space: u32,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
     pub struct ScHlslCompilerOptions {
         pub shader_model: i32,
         pub point_size_compat: bool,
@@ -2577,6 +2642,14 @@ pub mod root {
         pub force_storage_buffer_as_uav: bool,
         pub nonwritable_uav_texture_as_srv: bool,
         pub force_zero_initialized_variables: bool,
+        pub enable_storage_image_qualifier_deduction: bool,
+        pub support_nonzero_base_vertex_base_instance: bool,
+        pub enable_16bit_types: bool,
+        pub flatten_matrix_vertex_input_semantics: bool,
+        pub use_entry_point_name: bool,
+        pub preserve_structured_buffers: bool,
+        pub relax_nan_checks: bool,
+        pub emit_line_directives: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2600,6 +2673,35 @@ pub mod root {
         pub pad_fragment_output_components: bool,
         pub force_native_arrays: bool,
         pub force_zero_initialized_variables: bool,
+        pub check_discarded_frag_stores: bool,
+        pub readwrite_texture_fences: bool,
+        pub enable_storage_image_qualifier_deduction: bool,
+        pub texel_buffer_texture_width: u32,
+        pub pad_argument_buffer_resources: bool,
+        pub enable_decoration_binding: bool,
+        pub arrayed_subpass_input: bool,
+        pub ios_use_simdgroup_functions: bool,
+        pub use_framebuffer_fetch_subpasses: bool,
+        pub relax_nan_checks: bool,
+        pub emit_line_directives: bool,
+        pub runtime_array_rich_descriptor: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScMslCompilerOptionsUsage {
+        pub swizzle_buffer_index: bool,
+        pub buffer_size_buffer_index: bool,
+        pub output_buffer_index: bool,
+        pub patch_output_buffer_index: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ScMslFeatureRequirements {
+        pub argument_buffers: bool,
+        pub function_constants: bool,
+        pub framebuffer_fetch: bool,
+        pub tessellation: bool,
+        pub ray_queries: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2621,6 +2723,9 @@ pub mod root {
         pub emit_line_directives: bool,
         pub enable_storage_image_qualifier_deduction: bool,
         pub force_zero_initialized_variables: bool,
+        pub ovr_multiview_view_count: u32,
+        pub force_flattened_io_blocks: bool,
+        pub relax_nan_checks: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2650,6 +2755,8 @@ pub mod root {
         pub push_constant_buffers: root::ScResourceArray,
         pub separate_images: root::ScResourceArray,
         pub separate_samplers: root::ScResourceArray,
+        pub acceleration_structures: root::ScResourceArray,
+        pub shader_record_buffers: root::ScResourceArray,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2667,6 +2774,10 @@ pub mod root {
         pub member_types_size: usize,
         pub array: *mut u32,
         pub array_size: usize,
+        pub array_size_literal: *mut bool,
+        pub pointer: bool,
+        pub storage: root::spv::StorageClass,
+        pub parent_type: u32,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -23,6 +23,12 @@ extern "C" {
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_glsl_set_options(compiler: u32, options: u32) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_glsl_build_dummy_sampler_for_combined_images(
+        compiler: u32,
+        sampler_id: u32,
+    ) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_glsl_build_combined_image_samplers(compiler: u32) -> u32;
 
@@ -36,9 +42,31 @@ extern "C" {
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_glsl_add_header_line(compiler: u32, str: u32) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_glsl_require_extension(compiler: u32, extension: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_glsl_remap_ext_framebuffer_fetch(
+        compiler: u32,
+        input_attachment_index: u32,
+        color_location: u32,
+        coherent: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_glsl_set_variable_type_remaps(
+        compiler: u32,
+        variable_names: u32,
+        type_names: u32,
+        size: u32,
+    ) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_glsl_flatten_buffer_block(compiler: u32, id: u32) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_glsl_is_base_instance_used(compiler: u32, is_used: u32) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_get_decoration(
         compiler: u32,
@@ -55,6 +83,14 @@ extern "C" {
         argument: u32,
     ) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_has_decoration(
+        compiler: u32,
+        result: u32,
+        id: u32,
+        decoration: u32,
+    ) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_unset_decoration(compiler: u32, id: u32, decoration: u32) -> u32;
 
@@ -64,6 +100,22 @@ extern "C" {
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_set_name(compiler: u32, id: u32, name: u32) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_decoration_string(
+        compiler: u32,
+        id: u32,
+        decoration: u32,
+        argument: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_set_decoration_string(
+        compiler: u32,
+        id: u32,
+        decoration: u32,
+        argument: u32,
+    ) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_get_entry_points(compiler: u32, entry_points: u32, size: u32) -> u32;
 
@@ -86,6 +138,26 @@ extern "C" {
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_get_shader_resources(compiler: u32, shader_resources: u32) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_active_shader_resources(
+        compiler: u32,
+        shader_resources: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_active_interface_variables(
+        compiler: u32,
+        ids: u32,
+        size: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_set_enabled_interface_variables(
+        compiler: u32,
+        ids: u32,
+        size: u32,
+    ) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_get_specialization_constants(
         compiler: u32,
@@ -107,6 +179,18 @@ extern "C" {
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_get_member_name(compiler: u32, id: u32, index: u32, name: u32) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_set_member_name(compiler: u32, id: u32, index: u32, name: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_has_member_decoration(
+        compiler: u32,
+        id: u32,
+        index: u32,
+        decoration: u32,
+        result: u32,
+    ) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_get_member_decoration(
         compiler: u32,
@@ -125,6 +209,14 @@ extern "C" {
         argument: u32,
     ) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_unset_member_decoration(
+        compiler: u32,
+        id: u32,
+        index: u32,
+        decoration: u32,
+    ) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_get_declared_struct_size(compiler: u32, id: u32, result: u32) -> u32;
 
@@ -151,6 +243,82 @@ extern "C" {
         constants: u32,
     ) -> u32;
 
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_buffer_block_flags(
+        compiler: u32,
+        id: u32,
+        decorations: u32,
+        size: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_declared_capabilities(
+        compiler: u32,
+        capabilities: u32,
+        size: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_declared_extensions(
+        compiler: u32,
+        extensions: u32,
+        size: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_geometry_execution_modes(compiler: u32, modes: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_depth_execution_modes(compiler: u32, modes: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_execution_modes(compiler: u32, modes: u32, size: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_execution_mode_argument(
+        compiler: u32,
+        mode: u32,
+        index: u32,
+        result: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_set_execution_mode(
+        compiler: u32,
+        mode: u32,
+        arg0: u32,
+        arg1: u32,
+        arg2: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_unset_execution_mode(compiler: u32, mode: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_mesh_outputs(compiler: u32, outputs: u32, size: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_current_id_bound(compiler: u32, bound: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_execution_model(compiler: u32, execution_model: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_is_builtin_output_active(
+        compiler: u32,
+        builtin: u32,
+        is_active: u32,
+    ) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_active_builtins(compiler: u32, builtins: u32, size: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_type_self(compiler: u32, id: u32, self_id: u32) -> u32;
+
+    #[wasm_bindgen(js_namespace = sc_internal)]
+    fn _sc_internal_compiler_get_image_type(compiler: u32, id: u32, image_type: u32) -> u32;
+
     #[wasm_bindgen(js_namespace = sc_internal)]
     fn _sc_internal_compiler_set_entry_point(compiler: u32, name: u32, execution_model: u32)
         -> u32;
@@ -230,6 +398,25 @@ pub fn sc_internal_compiler_glsl_set_options(
     }
 }
 
+pub fn sc_internal_compiler_glsl_build_dummy_sampler_for_combined_images(
+    compiler: *const bindings::ScInternalCompilerBase,
+    sampler_id: *mut u32,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let sampler_id_ptr = module.allocate(U32_SIZE);
+        let result = map_internal_result(
+            _sc_internal_compiler_glsl_build_dummy_sampler_for_combined_images(
+                compiler as u32,
+                sampler_id_ptr.as_offset(),
+            ),
+        );
+        *sampler_id = module.get_u32(sampler_id_ptr) as u32;
+        module.free(sampler_id_ptr);
+        result
+    }
+}
+
 pub fn sc_internal_compiler_glsl_build_combined_image_samplers(
     compiler: *const bindings::ScInternalCompilerBase,
 ) -> bindings::ScInternalResult {
@@ -281,6 +468,79 @@ pub fn sc_internal_compiler_glsl_add_header_line(
     }
 }
 
+pub fn sc_internal_compiler_glsl_require_extension(
+    compiler: *const bindings::ScInternalCompilerBase,
+    extension: *const ::std::os::raw::c_char,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let extension_bytes = CStr::from_ptr(extension).to_bytes();
+        let extension_ptr = module.allocate(extension_bytes.len() as u32);
+        module.set_from_u8_slice(extension_ptr, extension_bytes);
+        let result = map_internal_result(_sc_internal_compiler_glsl_require_extension(
+            compiler as u32,
+            extension_ptr.as_offset(),
+        ));
+        module.free(extension_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_glsl_remap_ext_framebuffer_fetch(
+    compiler: *const bindings::ScInternalCompilerBase,
+    input_attachment_index: u32,
+    color_location: u32,
+    coherent: bool,
+) -> bindings::ScInternalResult {
+    unsafe {
+        map_internal_result(_sc_internal_compiler_glsl_remap_ext_framebuffer_fetch(
+            compiler as u32,
+            input_attachment_index,
+            color_location,
+            coherent as u32,
+        ))
+    }
+}
+
+pub fn sc_internal_compiler_glsl_set_variable_type_remaps(
+    compiler: *const bindings::ScInternalCompilerBase,
+    variable_names: *const *const ::std::os::raw::c_char,
+    type_names: *const *const ::std::os::raw::c_char,
+    size: usize,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let mut string_ptrs = Vec::with_capacity(size * 2);
+        let mut allocate_names = |names: *const *const ::std::os::raw::c_char| {
+            let mut offsets = Vec::with_capacity(size * U32_SIZE as usize);
+            for i in 0..size {
+                let name_bytes = CStr::from_ptr(*names.add(i)).to_bytes_with_nul();
+                let name_ptr = module.allocate(name_bytes.len() as u32);
+                module.set_from_u8_slice(name_ptr, name_bytes);
+                offsets.extend_from_slice(&name_ptr.as_offset().to_le_bytes());
+                string_ptrs.push(name_ptr);
+            }
+            let names_ptr = module.allocate(offsets.len() as u32);
+            module.set_from_u8_slice(names_ptr, &offsets);
+            names_ptr
+        };
+        let variable_names_ptr = allocate_names(variable_names);
+        let type_names_ptr = allocate_names(type_names);
+        let result = map_internal_result(_sc_internal_compiler_glsl_set_variable_type_remaps(
+            compiler as u32,
+            variable_names_ptr.as_offset(),
+            type_names_ptr.as_offset(),
+            size as u32,
+        ));
+        for string_ptr in string_ptrs {
+            module.free(string_ptr);
+        }
+        module.free(variable_names_ptr);
+        module.free(type_names_ptr);
+        result
+    }
+}
+
 pub fn sc_internal_compiler_glsl_flatten_buffer_block(
     compiler: *const bindings::ScInternalCompilerBase,
     id: u32,
@@ -295,6 +555,29 @@ pub fn sc_internal_compiler_glsl_flatten_buffer_block(
     }
 }
 
+pub fn sc_internal_compiler_glsl_is_base_instance_used(
+    compiler: *const bindings::ScInternalCompilerBase,
+    is_used: *mut bool,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let num_bytes = std::mem::size_of::<bool>();
+        let is_used_ptr = module.allocate(num_bytes as u32);
+        let result = map_internal_result(_sc_internal_compiler_glsl_is_base_instance_used(
+            compiler as u32,
+            is_used_ptr.as_offset(),
+        ));
+        module.read_bytes_into_pointer_while(
+            is_used_ptr,
+            |_, bytes_read| bytes_read < num_bytes,
+            false,
+            is_used as *mut u8,
+        );
+        module.free(is_used_ptr);
+        result
+    }
+}
+
 pub fn sc_internal_compiler_get_decoration(
     compiler: *const bindings::ScInternalCompilerBase,
     result: *mut u32,
@@ -330,6 +613,33 @@ pub fn sc_internal_compiler_set_decoration(
     ))
 }
 
+pub fn sc_internal_compiler_has_decoration(
+    compiler: *const bindings::ScInternalCompilerBase,
+    result: *mut bool,
+    id: u32,
+    decoration: bindings::spv::Decoration,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let num_bytes = std::mem::size_of::<bool>();
+        let result_ptr = module.allocate(num_bytes as u32);
+        let ret = map_internal_result(_sc_internal_compiler_has_decoration(
+            compiler as u32,
+            result_ptr.as_offset(),
+            id,
+            decoration as u32,
+        ));
+        module.read_bytes_into_pointer_while(
+            result_ptr,
+            |_, bytes_read| bytes_read < num_bytes,
+            false,
+            result as *mut u8,
+        );
+        module.free(result_ptr);
+        ret
+    }
+}
+
 pub fn sc_internal_compiler_unset_decoration(
     compiler: *const bindings::ScInternalCompilerBase,
     id: u32,
@@ -382,6 +692,50 @@ pub fn sc_internal_compiler_set_name(
     }
 }
 
+pub fn sc_internal_compiler_get_decoration_string(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    decoration: bindings::spv::Decoration,
+    argument: *mut *const ::std::os::raw::c_char,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let argument_ptr_to_ptr = module.allocate(U32_SIZE);
+        let result = map_internal_result(_sc_internal_compiler_get_decoration_string(
+            compiler as u32,
+            id,
+            decoration as u32,
+            argument_ptr_to_ptr.as_offset(),
+        ));
+        let argument_ptr = module.get_u32(argument_ptr_to_ptr);
+        *argument = argument_ptr as *const ::std::os::raw::c_char;
+        module.free(argument_ptr_to_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_set_decoration_string(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    decoration: bindings::spv::Decoration,
+    argument: *const ::std::os::raw::c_char,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let argument_bytes = CStr::from_ptr(argument).to_bytes_with_nul();
+        let argument_ptr = module.allocate(argument_bytes.len() as u32);
+        module.set_from_u8_slice(argument_ptr, argument_bytes);
+        let result = map_internal_result(_sc_internal_compiler_set_decoration_string(
+            compiler as u32,
+            id,
+            decoration as u32,
+            argument_ptr.as_offset(),
+        ));
+        module.free(argument_ptr);
+        result
+    }
+}
+
 pub fn sc_internal_compiler_get_entry_points(
     compiler: *const bindings::ScInternalCompilerBase,
     entry_points: *mut *mut bindings::ScEntryPoint,
@@ -489,41 +843,111 @@ pub fn sc_internal_compiler_get_shader_resources(
     }
 }
 
-pub fn sc_internal_compiler_get_specialization_constants(
+pub fn sc_internal_compiler_get_active_shader_resources(
     compiler: *const bindings::ScInternalCompilerBase,
-    constants: *mut *mut bindings::ScSpecializationConstant,
-    size: *mut usize,
+    shader_resources: *mut bindings::ScShaderResources,
 ) -> bindings::ScInternalResult {
     let module = emscripten::get_module();
     unsafe {
-        let constants_ptr_to_ptr = module.allocate(U32_SIZE);
-        let constants_size_ptr = module.allocate(U32_SIZE);
-        let result = map_internal_result(_sc_internal_compiler_get_specialization_constants(
+        let num_bytes = std::mem::size_of::<bindings::ScShaderResources>();
+        let shader_resources_ptr = module.allocate(num_bytes as u32);
+        let result = map_internal_result(_sc_internal_compiler_get_active_shader_resources(
             compiler as u32,
-            constants_ptr_to_ptr.as_offset(),
-            constants_size_ptr.as_offset() as u32,
+            shader_resources_ptr.as_offset(),
         ));
-        *constants =
-            module.get_u32(constants_ptr_to_ptr) as *mut bindings::ScSpecializationConstant;
-        *size = module.get_u32(constants_size_ptr) as usize;
-        module.free(constants_size_ptr);
-        module.free(constants_ptr_to_ptr);
+        module.read_bytes_into_pointer_while(
+            shader_resources_ptr,
+            |byte, bytes_read| bytes_read < num_bytes,
+            false,
+            shader_resources as *mut u8,
+        );
+        module.free(shader_resources_ptr);
         result
     }
 }
 
-pub fn sc_internal_compiler_set_scalar_constant(
+pub fn sc_internal_compiler_get_active_interface_variables(
     compiler: *const bindings::ScInternalCompilerBase,
-    id: u32,
-    constant_high_bits: u32,
-    constant_low_bits: u32,
+    ids: *mut *mut u32,
+    size: *mut usize,
 ) -> bindings::ScInternalResult {
-    map_internal_result(_sc_internal_compiler_set_scalar_constant(
-        compiler as u32,
-        id,
-        constant_high_bits,
-        constant_low_bits,
-    ))
+    let module = emscripten::get_module();
+    unsafe {
+        let ids_ptr_to_ptr = module.allocate(U32_SIZE);
+        let size_ptr = module.allocate(U32_SIZE);
+
+        let result = map_internal_result(_sc_internal_compiler_get_active_interface_variables(
+            compiler as u32,
+            ids_ptr_to_ptr.as_offset(),
+            size_ptr.as_offset(),
+        ));
+
+        *ids = module.get_u32(ids_ptr_to_ptr) as *mut u32;
+        *size = module.get_u32(size_ptr) as usize;
+
+        module.free(size_ptr);
+        module.free(ids_ptr_to_ptr);
+
+        result
+    }
+}
+
+pub fn sc_internal_compiler_set_enabled_interface_variables(
+    compiler: *const bindings::ScInternalCompilerBase,
+    ids: *const u32,
+    size: usize,
+) -> bindings::ScInternalResult {
+    let ids_bytes = size * (U32_SIZE as usize);
+    unsafe {
+        let ids = std::slice::from_raw_parts(ids as *const u8, ids_bytes);
+        let module = emscripten::get_module();
+        let ids_ptr = module.allocate(ids_bytes as u32);
+        module.set_from_u8_slice(ids_ptr, ids);
+        let result = map_internal_result(_sc_internal_compiler_set_enabled_interface_variables(
+            compiler as u32,
+            ids_ptr.as_offset(),
+            size as u32,
+        ));
+        module.free(ids_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_specialization_constants(
+    compiler: *const bindings::ScInternalCompilerBase,
+    constants: *mut *mut bindings::ScSpecializationConstant,
+    size: *mut usize,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let constants_ptr_to_ptr = module.allocate(U32_SIZE);
+        let constants_size_ptr = module.allocate(U32_SIZE);
+        let result = map_internal_result(_sc_internal_compiler_get_specialization_constants(
+            compiler as u32,
+            constants_ptr_to_ptr.as_offset(),
+            constants_size_ptr.as_offset() as u32,
+        ));
+        *constants =
+            module.get_u32(constants_ptr_to_ptr) as *mut bindings::ScSpecializationConstant;
+        *size = module.get_u32(constants_size_ptr) as usize;
+        module.free(constants_size_ptr);
+        module.free(constants_ptr_to_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_set_scalar_constant(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    constant_high_bits: u32,
+    constant_low_bits: u32,
+) -> bindings::ScInternalResult {
+    map_internal_result(_sc_internal_compiler_set_scalar_constant(
+        compiler as u32,
+        id,
+        constant_high_bits,
+        constant_low_bits,
+    ))
 }
 
 pub fn sc_internal_compiler_get_type(
@@ -568,6 +992,57 @@ pub fn sc_internal_compiler_get_member_name(
     }
 }
 
+pub fn sc_internal_compiler_set_member_name(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    index: u32,
+    name: *const ::std::os::raw::c_char,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let name_bytes = CStr::from_ptr(name).to_bytes();
+        let name_ptr = module.allocate(name_bytes.len() as u32);
+        module.set_from_u8_slice(name_ptr, name_bytes);
+        let result = map_internal_result(_sc_internal_compiler_set_member_name(
+            compiler as u32,
+            id,
+            index,
+            name_ptr.as_offset(),
+        ));
+        module.free(name_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_has_member_decoration(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    index: u32,
+    decoration: bindings::spv::Decoration,
+    result: *mut bool,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let num_bytes = std::mem::size_of::<bool>();
+        let result_ptr = module.allocate(num_bytes as u32);
+        let ret = map_internal_result(_sc_internal_compiler_has_member_decoration(
+            compiler as u32,
+            id,
+            index,
+            decoration as u32,
+            result_ptr.as_offset(),
+        ));
+        module.read_bytes_into_pointer_while(
+            result_ptr,
+            |_, bytes_read| bytes_read < num_bytes,
+            false,
+            result as *mut u8,
+        );
+        module.free(result_ptr);
+        ret
+    }
+}
+
 pub fn sc_internal_compiler_get_member_decoration(
     compiler: *const bindings::ScInternalCompilerBase,
     id: u32,
@@ -607,6 +1082,20 @@ pub fn sc_internal_compiler_set_member_decoration(
     ))
 }
 
+pub fn sc_internal_compiler_unset_member_decoration(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    index: u32,
+    decoration: bindings::spv::Decoration,
+) -> bindings::ScInternalResult {
+    map_internal_result(_sc_internal_compiler_unset_member_decoration(
+        compiler as u32,
+        id,
+        index,
+        decoration as u32,
+    ))
+}
+
 pub fn sc_internal_compiler_get_declared_struct_size(
     compiler: *const bindings::ScInternalCompilerBase,
     id: u32,
@@ -717,6 +1206,367 @@ pub fn sc_internal_compiler_get_work_group_size_specialization_constants(
     }
 }
 
+pub fn sc_internal_compiler_get_buffer_block_flags(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    decorations: *mut *mut u32,
+    size: *mut usize,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let decorations_ptr_to_ptr = module.allocate(U32_SIZE);
+        let size_ptr = module.allocate(U32_SIZE);
+
+        let result = map_internal_result(_sc_internal_compiler_get_buffer_block_flags(
+            compiler as u32,
+            id,
+            decorations_ptr_to_ptr.as_offset(),
+            size_ptr.as_offset(),
+        ));
+
+        *decorations = module.get_u32(decorations_ptr_to_ptr) as *mut u32;
+        *size = module.get_u32(size_ptr) as usize;
+
+        module.free(size_ptr);
+        module.free(decorations_ptr_to_ptr);
+
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_declared_capabilities(
+    compiler: *const bindings::ScInternalCompilerBase,
+    capabilities: *mut *mut u32,
+    size: *mut usize,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let capabilities_ptr_to_ptr = module.allocate(U32_SIZE);
+        let size_ptr = module.allocate(U32_SIZE);
+
+        let result = map_internal_result(_sc_internal_compiler_get_declared_capabilities(
+            compiler as u32,
+            capabilities_ptr_to_ptr.as_offset(),
+            size_ptr.as_offset(),
+        ));
+
+        *capabilities = module.get_u32(capabilities_ptr_to_ptr) as *mut u32;
+        *size = module.get_u32(size_ptr) as usize;
+
+        module.free(size_ptr);
+        module.free(capabilities_ptr_to_ptr);
+
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_declared_extensions(
+    compiler: *const bindings::ScInternalCompilerBase,
+    extensions: *mut *mut *const ::std::os::raw::c_char,
+    size: *mut usize,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let extensions_ptr_to_ptr = module.allocate(U32_SIZE);
+        let size_ptr = module.allocate(U32_SIZE);
+
+        let result = map_internal_result(_sc_internal_compiler_get_declared_extensions(
+            compiler as u32,
+            extensions_ptr_to_ptr.as_offset(),
+            size_ptr.as_offset(),
+        ));
+
+        *extensions =
+            module.get_u32(extensions_ptr_to_ptr) as *mut *const ::std::os::raw::c_char;
+        *size = module.get_u32(size_ptr) as usize;
+
+        module.free(size_ptr);
+        module.free(extensions_ptr_to_ptr);
+
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_geometry_execution_modes(
+    compiler: *const bindings::ScInternalCompilerBase,
+    modes: *mut bindings::ScGeometryExecutionModes,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let num_bytes = std::mem::size_of::<bindings::ScGeometryExecutionModes>();
+        let modes_ptr = module.allocate(num_bytes as u32);
+        let result = map_internal_result(_sc_internal_compiler_get_geometry_execution_modes(
+            compiler as u32,
+            modes_ptr.as_offset(),
+        ));
+        module.read_bytes_into_pointer_while(
+            modes_ptr,
+            |_, bytes_read| bytes_read < num_bytes,
+            false,
+            modes as *mut u8,
+        );
+        module.free(modes_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_depth_execution_modes(
+    compiler: *const bindings::ScInternalCompilerBase,
+    modes: *mut bindings::ScDepthExecutionModes,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let num_bytes = std::mem::size_of::<bindings::ScDepthExecutionModes>();
+        let modes_ptr = module.allocate(num_bytes as u32);
+        let result = map_internal_result(_sc_internal_compiler_get_depth_execution_modes(
+            compiler as u32,
+            modes_ptr.as_offset(),
+        ));
+        module.read_bytes_into_pointer_while(
+            modes_ptr,
+            |_, bytes_read| bytes_read < num_bytes,
+            false,
+            modes as *mut u8,
+        );
+        module.free(modes_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_execution_modes(
+    compiler: *const bindings::ScInternalCompilerBase,
+    modes: *mut *mut bindings::spv::ExecutionMode,
+    size: *mut usize,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let modes_ptr_to_ptr = module.allocate(U32_SIZE);
+        let size_ptr = module.allocate(U32_SIZE);
+
+        let result = map_internal_result(_sc_internal_compiler_get_execution_modes(
+            compiler as u32,
+            modes_ptr_to_ptr.as_offset(),
+            size_ptr.as_offset(),
+        ));
+
+        *modes = module.get_u32(modes_ptr_to_ptr) as *mut bindings::spv::ExecutionMode;
+        *size = module.get_u32(size_ptr) as usize;
+
+        module.free(size_ptr);
+        module.free(modes_ptr_to_ptr);
+
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_execution_mode_argument(
+    compiler: *const bindings::ScInternalCompilerBase,
+    mode: bindings::spv::ExecutionMode,
+    index: u32,
+    result: *mut u32,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let result_ptr = module.allocate(U32_SIZE);
+        let ret = map_internal_result(_sc_internal_compiler_get_execution_mode_argument(
+            compiler as u32,
+            mode,
+            index,
+            result_ptr.as_offset(),
+        ));
+        *result = module.get_u32(result_ptr) as u32;
+        module.free(result_ptr);
+        ret
+    }
+}
+
+pub fn sc_internal_compiler_set_execution_mode(
+    compiler: *const bindings::ScInternalCompilerBase,
+    mode: bindings::spv::ExecutionMode,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+) -> bindings::ScInternalResult {
+    map_internal_result(_sc_internal_compiler_set_execution_mode(
+        compiler as u32,
+        mode,
+        arg0,
+        arg1,
+        arg2,
+    ))
+}
+
+pub fn sc_internal_compiler_unset_execution_mode(
+    compiler: *const bindings::ScInternalCompilerBase,
+    mode: bindings::spv::ExecutionMode,
+) -> bindings::ScInternalResult {
+    map_internal_result(_sc_internal_compiler_unset_execution_mode(
+        compiler as u32,
+        mode,
+    ))
+}
+
+pub fn sc_internal_compiler_get_mesh_outputs(
+    compiler: *const bindings::ScInternalCompilerBase,
+    outputs: *mut *mut bindings::ScMeshOutput,
+    size: *mut usize,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let outputs_ptr_to_ptr = module.allocate(U32_SIZE);
+        let size_ptr = module.allocate(U32_SIZE);
+
+        let result = map_internal_result(_sc_internal_compiler_get_mesh_outputs(
+            compiler as u32,
+            outputs_ptr_to_ptr.as_offset(),
+            size_ptr.as_offset(),
+        ));
+
+        *outputs = module.get_u32(outputs_ptr_to_ptr) as *mut bindings::ScMeshOutput;
+        *size = module.get_u32(size_ptr) as usize;
+
+        module.free(size_ptr);
+        module.free(outputs_ptr_to_ptr);
+
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_current_id_bound(
+    compiler: *const bindings::ScInternalCompilerBase,
+    bound: *mut u32,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let bound_ptr = module.allocate(U32_SIZE);
+        let result = map_internal_result(_sc_internal_compiler_get_current_id_bound(
+            compiler as u32,
+            bound_ptr.as_offset(),
+        ));
+        *bound = module.get_u32(bound_ptr) as u32;
+        module.free(bound_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_execution_model(
+    compiler: *const bindings::ScInternalCompilerBase,
+    execution_model: *mut bindings::spv::ExecutionModel,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let num_bytes = std::mem::size_of::<bindings::spv::ExecutionModel>();
+        let execution_model_ptr = module.allocate(num_bytes as u32);
+        let result = map_internal_result(_sc_internal_compiler_get_execution_model(
+            compiler as u32,
+            execution_model_ptr.as_offset(),
+        ));
+        module.read_bytes_into_pointer_while(
+            execution_model_ptr,
+            |_, bytes_read| bytes_read < num_bytes,
+            false,
+            execution_model as *mut u8,
+        );
+        module.free(execution_model_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_is_builtin_output_active(
+    compiler: *const bindings::ScInternalCompilerBase,
+    builtin: bindings::spv::BuiltIn,
+    is_active: *mut bool,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let num_bytes = std::mem::size_of::<bool>();
+        let is_active_ptr = module.allocate(num_bytes as u32);
+        let result = map_internal_result(_sc_internal_compiler_is_builtin_output_active(
+            compiler as u32,
+            builtin as u32,
+            is_active_ptr.as_offset(),
+        ));
+        module.read_bytes_into_pointer_while(
+            is_active_ptr,
+            |_, bytes_read| bytes_read < num_bytes,
+            false,
+            is_active as *mut u8,
+        );
+        module.free(is_active_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_active_builtins(
+    compiler: *const bindings::ScInternalCompilerBase,
+    builtins: *mut *mut u32,
+    size: *mut usize,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let builtins_ptr_to_ptr = module.allocate(U32_SIZE);
+        let size_ptr = module.allocate(U32_SIZE);
+
+        let result = map_internal_result(_sc_internal_compiler_get_active_builtins(
+            compiler as u32,
+            builtins_ptr_to_ptr.as_offset(),
+            size_ptr.as_offset(),
+        ));
+
+        *builtins = module.get_u32(builtins_ptr_to_ptr) as *mut u32;
+        *size = module.get_u32(size_ptr) as usize;
+
+        module.free(size_ptr);
+        module.free(builtins_ptr_to_ptr);
+
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_type_self(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    self_id: *mut u32,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let self_id_ptr = module.allocate(U32_SIZE);
+        let result = map_internal_result(_sc_internal_compiler_get_type_self(
+            compiler as u32,
+            id,
+            self_id_ptr.as_offset(),
+        ));
+        *self_id = module.get_u32(self_id_ptr) as u32;
+        module.free(self_id_ptr);
+        result
+    }
+}
+
+pub fn sc_internal_compiler_get_image_type(
+    compiler: *const bindings::ScInternalCompilerBase,
+    id: u32,
+    image_type: *mut bindings::ScImageType,
+) -> bindings::ScInternalResult {
+    let module = emscripten::get_module();
+    unsafe {
+        let num_bytes = std::mem::size_of::<bindings::ScImageType>();
+        let image_type_ptr = module.allocate(num_bytes as u32);
+        let result = map_internal_result(_sc_internal_compiler_get_image_type(
+            compiler as u32,
+            id,
+            image_type_ptr.as_offset(),
+        ));
+        module.read_bytes_into_pointer_while(
+            image_type_ptr,
+            |_, bytes_read| bytes_read < num_bytes,
+            false,
+            image_type as *mut u8,
+        );
+        module.free(image_type_ptr);
+        result
+    }
+}
+
 pub fn sc_internal_compiler_set_entry_point(
     compiler: *const bindings::ScInternalCompilerBase,
     name: *const ::std::os::raw::c_char,
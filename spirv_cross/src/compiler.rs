@@ -3,8 +3,12 @@ use crate::bindings as br;
 use crate::ptr_util::{read_from_ptr, read_into_vec_from_ptr, read_string_from_ptr};
 use crate::spirv::{self, Decoration, Type};
 use crate::ErrorCode;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ffi::CString;
 use std::os::raw::c_void;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{mem::MaybeUninit, ptr};
 
 impl spirv::ExecutionModel {
@@ -19,6 +23,8 @@ impl spirv::ExecutionModel {
             Em::ExecutionModelFragment => Ok(Fragment),
             Em::ExecutionModelGLCompute => Ok(GlCompute),
             Em::ExecutionModelKernel => Ok(Kernel),
+            Em::ExecutionModelTaskEXT => Ok(Task),
+            Em::ExecutionModelMeshEXT => Ok(Mesh),
             _ => Err(ErrorCode::Unhandled),
         }
     }
@@ -34,6 +40,558 @@ impl spirv::ExecutionModel {
             Fragment => Em::ExecutionModelFragment,
             GlCompute => Em::ExecutionModelGLCompute,
             Kernel => Em::ExecutionModelKernel,
+            Task => Em::ExecutionModelTaskEXT,
+            Mesh => Em::ExecutionModelMeshEXT,
+        }
+    }
+}
+
+impl spirv::ImageDimension {
+    fn from_raw(raw: br::spv::Dim) -> Self {
+        use crate::spirv::ImageDimension::*;
+        match raw {
+            br::spv::Dim_Dim1D => Dim1D,
+            br::spv::Dim_Dim2D => Dim2D,
+            br::spv::Dim_Dim3D => Dim3D,
+            br::spv::Dim_DimCube => Cube,
+            br::spv::Dim_DimRect => Rect,
+            br::spv::Dim_DimBuffer => Buffer,
+            br::spv::Dim_DimSubpassData => SubpassData,
+            _ => Unknown(raw),
+        }
+    }
+}
+
+impl spirv::ImageFormat {
+    fn from_raw(raw: br::spv::ImageFormat) -> Option<Self> {
+        use crate::spirv::ImageFormat::*;
+        match raw {
+            br::spv::ImageFormat_ImageFormatUnknown => None,
+            br::spv::ImageFormat_ImageFormatRgba32f => Some(Rgba32f),
+            br::spv::ImageFormat_ImageFormatRgba16f => Some(Rgba16f),
+            br::spv::ImageFormat_ImageFormatR32f => Some(R32f),
+            br::spv::ImageFormat_ImageFormatRgba8 => Some(Rgba8),
+            br::spv::ImageFormat_ImageFormatRgba8Snorm => Some(Rgba8Snorm),
+            br::spv::ImageFormat_ImageFormatRg32f => Some(Rg32f),
+            br::spv::ImageFormat_ImageFormatRg16f => Some(Rg16f),
+            br::spv::ImageFormat_ImageFormatR11fG11fB10f => Some(R11fG11fB10f),
+            br::spv::ImageFormat_ImageFormatR16f => Some(R16f),
+            br::spv::ImageFormat_ImageFormatRgba16 => Some(Rgba16),
+            br::spv::ImageFormat_ImageFormatRgb10A2 => Some(Rgb10A2),
+            br::spv::ImageFormat_ImageFormatRg16 => Some(Rg16),
+            br::spv::ImageFormat_ImageFormatRg8 => Some(Rg8),
+            br::spv::ImageFormat_ImageFormatR16 => Some(R16),
+            br::spv::ImageFormat_ImageFormatR8 => Some(R8),
+            br::spv::ImageFormat_ImageFormatRgba16Snorm => Some(Rgba16Snorm),
+            br::spv::ImageFormat_ImageFormatRg16Snorm => Some(Rg16Snorm),
+            br::spv::ImageFormat_ImageFormatRg8Snorm => Some(Rg8Snorm),
+            br::spv::ImageFormat_ImageFormatR16Snorm => Some(R16Snorm),
+            br::spv::ImageFormat_ImageFormatR8Snorm => Some(R8Snorm),
+            br::spv::ImageFormat_ImageFormatRgba32i => Some(Rgba32i),
+            br::spv::ImageFormat_ImageFormatRgba16i => Some(Rgba16i),
+            br::spv::ImageFormat_ImageFormatRgba8i => Some(Rgba8i),
+            br::spv::ImageFormat_ImageFormatR32i => Some(R32i),
+            br::spv::ImageFormat_ImageFormatRg32i => Some(Rg32i),
+            br::spv::ImageFormat_ImageFormatRg16i => Some(Rg16i),
+            br::spv::ImageFormat_ImageFormatRg8i => Some(Rg8i),
+            br::spv::ImageFormat_ImageFormatR16i => Some(R16i),
+            br::spv::ImageFormat_ImageFormatR8i => Some(R8i),
+            br::spv::ImageFormat_ImageFormatRgba32ui => Some(Rgba32ui),
+            br::spv::ImageFormat_ImageFormatRgba16ui => Some(Rgba16ui),
+            br::spv::ImageFormat_ImageFormatRgba8ui => Some(Rgba8ui),
+            br::spv::ImageFormat_ImageFormatR32ui => Some(R32ui),
+            br::spv::ImageFormat_ImageFormatRgb10a2ui => Some(Rgb10a2ui),
+            br::spv::ImageFormat_ImageFormatRg32ui => Some(Rg32ui),
+            br::spv::ImageFormat_ImageFormatRg16ui => Some(Rg16ui),
+            br::spv::ImageFormat_ImageFormatRg8ui => Some(Rg8ui),
+            br::spv::ImageFormat_ImageFormatR16ui => Some(R16ui),
+            br::spv::ImageFormat_ImageFormatR8ui => Some(R8ui),
+            br::spv::ImageFormat_ImageFormatR64ui => Some(R64ui),
+            br::spv::ImageFormat_ImageFormatR64i => Some(R64i),
+            _ => Some(Unknown(raw)),
+        }
+    }
+}
+
+impl spirv::ExecutionMode {
+    fn from_raw(raw: br::spv::ExecutionMode) -> Self {
+        use crate::spirv::ExecutionMode::*;
+        match raw {
+            br::spv::ExecutionMode_ExecutionModeInvocations => Invocations,
+            br::spv::ExecutionMode_ExecutionModeSpacingEqual => SpacingEqual,
+            br::spv::ExecutionMode_ExecutionModeSpacingFractionalEven => SpacingFractionalEven,
+            br::spv::ExecutionMode_ExecutionModeSpacingFractionalOdd => SpacingFractionalOdd,
+            br::spv::ExecutionMode_ExecutionModeVertexOrderCw => VertexOrderCw,
+            br::spv::ExecutionMode_ExecutionModeVertexOrderCcw => VertexOrderCcw,
+            br::spv::ExecutionMode_ExecutionModePixelCenterInteger => PixelCenterInteger,
+            br::spv::ExecutionMode_ExecutionModeOriginUpperLeft => OriginUpperLeft,
+            br::spv::ExecutionMode_ExecutionModeOriginLowerLeft => OriginLowerLeft,
+            br::spv::ExecutionMode_ExecutionModeEarlyFragmentTests => EarlyFragmentTests,
+            br::spv::ExecutionMode_ExecutionModePointMode => PointMode,
+            br::spv::ExecutionMode_ExecutionModeXfb => Xfb,
+            br::spv::ExecutionMode_ExecutionModeDepthReplacing => DepthReplacing,
+            br::spv::ExecutionMode_ExecutionModeDepthGreater => DepthGreater,
+            br::spv::ExecutionMode_ExecutionModeDepthLess => DepthLess,
+            br::spv::ExecutionMode_ExecutionModeDepthUnchanged => DepthUnchanged,
+            br::spv::ExecutionMode_ExecutionModeLocalSize => LocalSize,
+            br::spv::ExecutionMode_ExecutionModeLocalSizeHint => LocalSizeHint,
+            br::spv::ExecutionMode_ExecutionModeInputPoints => InputPoints,
+            br::spv::ExecutionMode_ExecutionModeInputLines => InputLines,
+            br::spv::ExecutionMode_ExecutionModeInputLinesAdjacency => InputLinesAdjacency,
+            br::spv::ExecutionMode_ExecutionModeTriangles => Triangles,
+            br::spv::ExecutionMode_ExecutionModeInputTrianglesAdjacency => InputTrianglesAdjacency,
+            br::spv::ExecutionMode_ExecutionModeQuads => Quads,
+            br::spv::ExecutionMode_ExecutionModeIsolines => Isolines,
+            br::spv::ExecutionMode_ExecutionModeOutputVertices => OutputVertices,
+            br::spv::ExecutionMode_ExecutionModeOutputPoints => OutputPoints,
+            br::spv::ExecutionMode_ExecutionModeOutputLineStrip => OutputLineStrip,
+            br::spv::ExecutionMode_ExecutionModeOutputTriangleStrip => OutputTriangleStrip,
+            br::spv::ExecutionMode_ExecutionModeVecTypeHint => VecTypeHint,
+            br::spv::ExecutionMode_ExecutionModeContractionOff => ContractionOff,
+            br::spv::ExecutionMode_ExecutionModeInitializer => Initializer,
+            br::spv::ExecutionMode_ExecutionModeFinalizer => Finalizer,
+            br::spv::ExecutionMode_ExecutionModeSubgroupSize => SubgroupSize,
+            br::spv::ExecutionMode_ExecutionModeSubgroupsPerWorkgroup => SubgroupsPerWorkgroup,
+            br::spv::ExecutionMode_ExecutionModeSubgroupsPerWorkgroupId => SubgroupsPerWorkgroupId,
+            br::spv::ExecutionMode_ExecutionModeLocalSizeId => LocalSizeId,
+            br::spv::ExecutionMode_ExecutionModeLocalSizeHintId => LocalSizeHintId,
+            br::spv::ExecutionMode_ExecutionModePostDepthCoverage => PostDepthCoverage,
+            br::spv::ExecutionMode_ExecutionModeDenormPreserve => DenormPreserve,
+            br::spv::ExecutionMode_ExecutionModeDenormFlushToZero => DenormFlushToZero,
+            br::spv::ExecutionMode_ExecutionModeSignedZeroInfNanPreserve => {
+                SignedZeroInfNanPreserve
+            }
+            br::spv::ExecutionMode_ExecutionModeRoundingModeRTE => RoundingModeRte,
+            br::spv::ExecutionMode_ExecutionModeRoundingModeRTZ => RoundingModeRtz,
+            br::spv::ExecutionMode_ExecutionModeStencilRefReplacingEXT => StencilRefReplacingExt,
+            br::spv::ExecutionMode_ExecutionModeOutputLinesEXT => OutputLinesExt,
+            br::spv::ExecutionMode_ExecutionModeOutputPrimitivesEXT => OutputPrimitivesExt,
+            br::spv::ExecutionMode_ExecutionModeDerivativeGroupQuadsNV => DerivativeGroupQuadsNv,
+            br::spv::ExecutionMode_ExecutionModeDerivativeGroupLinearNV => DerivativeGroupLinearNv,
+            br::spv::ExecutionMode_ExecutionModeOutputTrianglesEXT => OutputTrianglesExt,
+            br::spv::ExecutionMode_ExecutionModePixelInterlockOrderedEXT => {
+                PixelInterlockOrderedExt
+            }
+            br::spv::ExecutionMode_ExecutionModePixelInterlockUnorderedEXT => {
+                PixelInterlockUnorderedExt
+            }
+            br::spv::ExecutionMode_ExecutionModeSampleInterlockOrderedEXT => {
+                SampleInterlockOrderedExt
+            }
+            br::spv::ExecutionMode_ExecutionModeSampleInterlockUnorderedEXT => {
+                SampleInterlockUnorderedExt
+            }
+            br::spv::ExecutionMode_ExecutionModeShadingRateInterlockOrderedEXT => {
+                ShadingRateInterlockOrderedExt
+            }
+            br::spv::ExecutionMode_ExecutionModeShadingRateInterlockUnorderedEXT => {
+                ShadingRateInterlockUnorderedExt
+            }
+            br::spv::ExecutionMode_ExecutionModeMaxWorkgroupSizeINTEL => MaxWorkgroupSizeIntel,
+            br::spv::ExecutionMode_ExecutionModeMaxWorkDimINTEL => MaxWorkDimIntel,
+            br::spv::ExecutionMode_ExecutionModeNoGlobalOffsetINTEL => NoGlobalOffsetIntel,
+            br::spv::ExecutionMode_ExecutionModeNumSIMDWorkitemsINTEL => NumSimdWorkitemsIntel,
+            _ => Unknown(raw),
+        }
+    }
+
+    fn as_raw(self) -> br::spv::ExecutionMode {
+        use crate::spirv::ExecutionMode::*;
+        match self {
+            Invocations => br::spv::ExecutionMode_ExecutionModeInvocations,
+            SpacingEqual => br::spv::ExecutionMode_ExecutionModeSpacingEqual,
+            SpacingFractionalEven => br::spv::ExecutionMode_ExecutionModeSpacingFractionalEven,
+            SpacingFractionalOdd => br::spv::ExecutionMode_ExecutionModeSpacingFractionalOdd,
+            VertexOrderCw => br::spv::ExecutionMode_ExecutionModeVertexOrderCw,
+            VertexOrderCcw => br::spv::ExecutionMode_ExecutionModeVertexOrderCcw,
+            PixelCenterInteger => br::spv::ExecutionMode_ExecutionModePixelCenterInteger,
+            OriginUpperLeft => br::spv::ExecutionMode_ExecutionModeOriginUpperLeft,
+            OriginLowerLeft => br::spv::ExecutionMode_ExecutionModeOriginLowerLeft,
+            EarlyFragmentTests => br::spv::ExecutionMode_ExecutionModeEarlyFragmentTests,
+            PointMode => br::spv::ExecutionMode_ExecutionModePointMode,
+            Xfb => br::spv::ExecutionMode_ExecutionModeXfb,
+            DepthReplacing => br::spv::ExecutionMode_ExecutionModeDepthReplacing,
+            DepthGreater => br::spv::ExecutionMode_ExecutionModeDepthGreater,
+            DepthLess => br::spv::ExecutionMode_ExecutionModeDepthLess,
+            DepthUnchanged => br::spv::ExecutionMode_ExecutionModeDepthUnchanged,
+            LocalSize => br::spv::ExecutionMode_ExecutionModeLocalSize,
+            LocalSizeHint => br::spv::ExecutionMode_ExecutionModeLocalSizeHint,
+            InputPoints => br::spv::ExecutionMode_ExecutionModeInputPoints,
+            InputLines => br::spv::ExecutionMode_ExecutionModeInputLines,
+            InputLinesAdjacency => br::spv::ExecutionMode_ExecutionModeInputLinesAdjacency,
+            Triangles => br::spv::ExecutionMode_ExecutionModeTriangles,
+            InputTrianglesAdjacency => br::spv::ExecutionMode_ExecutionModeInputTrianglesAdjacency,
+            Quads => br::spv::ExecutionMode_ExecutionModeQuads,
+            Isolines => br::spv::ExecutionMode_ExecutionModeIsolines,
+            OutputVertices => br::spv::ExecutionMode_ExecutionModeOutputVertices,
+            OutputPoints => br::spv::ExecutionMode_ExecutionModeOutputPoints,
+            OutputLineStrip => br::spv::ExecutionMode_ExecutionModeOutputLineStrip,
+            OutputTriangleStrip => br::spv::ExecutionMode_ExecutionModeOutputTriangleStrip,
+            VecTypeHint => br::spv::ExecutionMode_ExecutionModeVecTypeHint,
+            ContractionOff => br::spv::ExecutionMode_ExecutionModeContractionOff,
+            Initializer => br::spv::ExecutionMode_ExecutionModeInitializer,
+            Finalizer => br::spv::ExecutionMode_ExecutionModeFinalizer,
+            SubgroupSize => br::spv::ExecutionMode_ExecutionModeSubgroupSize,
+            SubgroupsPerWorkgroup => br::spv::ExecutionMode_ExecutionModeSubgroupsPerWorkgroup,
+            SubgroupsPerWorkgroupId => br::spv::ExecutionMode_ExecutionModeSubgroupsPerWorkgroupId,
+            LocalSizeId => br::spv::ExecutionMode_ExecutionModeLocalSizeId,
+            LocalSizeHintId => br::spv::ExecutionMode_ExecutionModeLocalSizeHintId,
+            PostDepthCoverage => br::spv::ExecutionMode_ExecutionModePostDepthCoverage,
+            DenormPreserve => br::spv::ExecutionMode_ExecutionModeDenormPreserve,
+            DenormFlushToZero => br::spv::ExecutionMode_ExecutionModeDenormFlushToZero,
+            SignedZeroInfNanPreserve => {
+                br::spv::ExecutionMode_ExecutionModeSignedZeroInfNanPreserve
+            }
+            RoundingModeRte => br::spv::ExecutionMode_ExecutionModeRoundingModeRTE,
+            RoundingModeRtz => br::spv::ExecutionMode_ExecutionModeRoundingModeRTZ,
+            StencilRefReplacingExt => br::spv::ExecutionMode_ExecutionModeStencilRefReplacingEXT,
+            OutputLinesExt => br::spv::ExecutionMode_ExecutionModeOutputLinesEXT,
+            OutputPrimitivesExt => br::spv::ExecutionMode_ExecutionModeOutputPrimitivesEXT,
+            DerivativeGroupQuadsNv => br::spv::ExecutionMode_ExecutionModeDerivativeGroupQuadsNV,
+            DerivativeGroupLinearNv => br::spv::ExecutionMode_ExecutionModeDerivativeGroupLinearNV,
+            OutputTrianglesExt => br::spv::ExecutionMode_ExecutionModeOutputTrianglesEXT,
+            PixelInterlockOrderedExt => {
+                br::spv::ExecutionMode_ExecutionModePixelInterlockOrderedEXT
+            }
+            PixelInterlockUnorderedExt => {
+                br::spv::ExecutionMode_ExecutionModePixelInterlockUnorderedEXT
+            }
+            SampleInterlockOrderedExt => {
+                br::spv::ExecutionMode_ExecutionModeSampleInterlockOrderedEXT
+            }
+            SampleInterlockUnorderedExt => {
+                br::spv::ExecutionMode_ExecutionModeSampleInterlockUnorderedEXT
+            }
+            ShadingRateInterlockOrderedExt => {
+                br::spv::ExecutionMode_ExecutionModeShadingRateInterlockOrderedEXT
+            }
+            ShadingRateInterlockUnorderedExt => {
+                br::spv::ExecutionMode_ExecutionModeShadingRateInterlockUnorderedEXT
+            }
+            MaxWorkgroupSizeIntel => br::spv::ExecutionMode_ExecutionModeMaxWorkgroupSizeINTEL,
+            MaxWorkDimIntel => br::spv::ExecutionMode_ExecutionModeMaxWorkDimINTEL,
+            NoGlobalOffsetIntel => br::spv::ExecutionMode_ExecutionModeNoGlobalOffsetINTEL,
+            NumSimdWorkitemsIntel => br::spv::ExecutionMode_ExecutionModeNumSIMDWorkitemsINTEL,
+            Unknown(raw) => raw,
+        }
+    }
+
+    /// The number of arguments SPIRV-Cross keeps for the mode.
+    fn argument_count(self) -> u32 {
+        use crate::spirv::ExecutionMode::*;
+        match self {
+            LocalSize | LocalSizeId => 3,
+            Invocations | OutputVertices | OutputPrimitivesExt => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl spirv::Capability {
+    fn from_raw(raw: br::spv::Capability) -> Self {
+        use crate::spirv::Capability::*;
+        match raw {
+            br::spv::Capability_CapabilityMatrix => Matrix,
+            br::spv::Capability_CapabilityShader => Shader,
+            br::spv::Capability_CapabilityGeometry => Geometry,
+            br::spv::Capability_CapabilityTessellation => Tessellation,
+            br::spv::Capability_CapabilityAddresses => Addresses,
+            br::spv::Capability_CapabilityLinkage => Linkage,
+            br::spv::Capability_CapabilityKernel => Kernel,
+            br::spv::Capability_CapabilityVector16 => Vector16,
+            br::spv::Capability_CapabilityFloat16Buffer => Float16Buffer,
+            br::spv::Capability_CapabilityFloat16 => Float16,
+            br::spv::Capability_CapabilityFloat64 => Float64,
+            br::spv::Capability_CapabilityInt64 => Int64,
+            br::spv::Capability_CapabilityInt64Atomics => Int64Atomics,
+            br::spv::Capability_CapabilityImageBasic => ImageBasic,
+            br::spv::Capability_CapabilityImageReadWrite => ImageReadWrite,
+            br::spv::Capability_CapabilityImageMipmap => ImageMipmap,
+            br::spv::Capability_CapabilityPipes => Pipes,
+            br::spv::Capability_CapabilityGroups => Groups,
+            br::spv::Capability_CapabilityDeviceEnqueue => DeviceEnqueue,
+            br::spv::Capability_CapabilityLiteralSampler => LiteralSampler,
+            br::spv::Capability_CapabilityAtomicStorage => AtomicStorage,
+            br::spv::Capability_CapabilityInt16 => Int16,
+            br::spv::Capability_CapabilityTessellationPointSize => TessellationPointSize,
+            br::spv::Capability_CapabilityGeometryPointSize => GeometryPointSize,
+            br::spv::Capability_CapabilityImageGatherExtended => ImageGatherExtended,
+            br::spv::Capability_CapabilityStorageImageMultisample => StorageImageMultisample,
+            br::spv::Capability_CapabilityUniformBufferArrayDynamicIndexing => {
+                UniformBufferArrayDynamicIndexing
+            }
+            br::spv::Capability_CapabilitySampledImageArrayDynamicIndexing => {
+                SampledImageArrayDynamicIndexing
+            }
+            br::spv::Capability_CapabilityStorageBufferArrayDynamicIndexing => {
+                StorageBufferArrayDynamicIndexing
+            }
+            br::spv::Capability_CapabilityStorageImageArrayDynamicIndexing => {
+                StorageImageArrayDynamicIndexing
+            }
+            br::spv::Capability_CapabilityClipDistance => ClipDistance,
+            br::spv::Capability_CapabilityCullDistance => CullDistance,
+            br::spv::Capability_CapabilityImageCubeArray => ImageCubeArray,
+            br::spv::Capability_CapabilitySampleRateShading => SampleRateShading,
+            br::spv::Capability_CapabilityImageRect => ImageRect,
+            br::spv::Capability_CapabilitySampledRect => SampledRect,
+            br::spv::Capability_CapabilityGenericPointer => GenericPointer,
+            br::spv::Capability_CapabilityInt8 => Int8,
+            br::spv::Capability_CapabilityInputAttachment => InputAttachment,
+            br::spv::Capability_CapabilitySparseResidency => SparseResidency,
+            br::spv::Capability_CapabilityMinLod => MinLod,
+            br::spv::Capability_CapabilitySampled1D => Sampled1D,
+            br::spv::Capability_CapabilityImage1D => Image1D,
+            br::spv::Capability_CapabilitySampledCubeArray => SampledCubeArray,
+            br::spv::Capability_CapabilitySampledBuffer => SampledBuffer,
+            br::spv::Capability_CapabilityImageBuffer => ImageBuffer,
+            br::spv::Capability_CapabilityImageMSArray => ImageMSArray,
+            br::spv::Capability_CapabilityStorageImageExtendedFormats => {
+                StorageImageExtendedFormats
+            }
+            br::spv::Capability_CapabilityImageQuery => ImageQuery,
+            br::spv::Capability_CapabilityDerivativeControl => DerivativeControl,
+            br::spv::Capability_CapabilityInterpolationFunction => InterpolationFunction,
+            br::spv::Capability_CapabilityTransformFeedback => TransformFeedback,
+            br::spv::Capability_CapabilityGeometryStreams => GeometryStreams,
+            br::spv::Capability_CapabilityStorageImageReadWithoutFormat => {
+                StorageImageReadWithoutFormat
+            }
+            br::spv::Capability_CapabilityStorageImageWriteWithoutFormat => {
+                StorageImageWriteWithoutFormat
+            }
+            br::spv::Capability_CapabilityMultiViewport => MultiViewport,
+            br::spv::Capability_CapabilitySubgroupDispatch => SubgroupDispatch,
+            br::spv::Capability_CapabilityNamedBarrier => NamedBarrier,
+            br::spv::Capability_CapabilityPipeStorage => PipeStorage,
+            br::spv::Capability_CapabilityGroupNonUniform => GroupNonUniform,
+            br::spv::Capability_CapabilityGroupNonUniformVote => GroupNonUniformVote,
+            br::spv::Capability_CapabilityGroupNonUniformArithmetic => GroupNonUniformArithmetic,
+            br::spv::Capability_CapabilityGroupNonUniformBallot => GroupNonUniformBallot,
+            br::spv::Capability_CapabilityGroupNonUniformShuffle => GroupNonUniformShuffle,
+            br::spv::Capability_CapabilityGroupNonUniformShuffleRelative => {
+                GroupNonUniformShuffleRelative
+            }
+            br::spv::Capability_CapabilityGroupNonUniformClustered => GroupNonUniformClustered,
+            br::spv::Capability_CapabilityGroupNonUniformQuad => GroupNonUniformQuad,
+            br::spv::Capability_CapabilityShaderLayer => ShaderLayer,
+            br::spv::Capability_CapabilityShaderViewportIndex => ShaderViewportIndex,
+            br::spv::Capability_CapabilityFragmentShadingRateKHR => FragmentShadingRate,
+            br::spv::Capability_CapabilitySubgroupBallotKHR => SubgroupBallot,
+            br::spv::Capability_CapabilityDrawParameters => DrawParameters,
+            br::spv::Capability_CapabilitySubgroupVoteKHR => SubgroupVote,
+            br::spv::Capability_CapabilityStorageBuffer16BitAccess => StorageBuffer16BitAccess,
+            br::spv::Capability_CapabilityStorageUniform16 => StorageUniform16,
+            br::spv::Capability_CapabilityStoragePushConstant16 => StoragePushConstant16,
+            br::spv::Capability_CapabilityStorageInputOutput16 => StorageInputOutput16,
+            br::spv::Capability_CapabilityDeviceGroup => DeviceGroup,
+            br::spv::Capability_CapabilityMultiView => MultiView,
+            br::spv::Capability_CapabilityVariablePointersStorageBuffer => {
+                VariablePointersStorageBuffer
+            }
+            br::spv::Capability_CapabilityVariablePointers => VariablePointers,
+            br::spv::Capability_CapabilityAtomicStorageOps => AtomicStorageOps,
+            br::spv::Capability_CapabilitySampleMaskPostDepthCoverage => {
+                SampleMaskPostDepthCoverage
+            }
+            br::spv::Capability_CapabilityStorageBuffer8BitAccess => StorageBuffer8BitAccess,
+            br::spv::Capability_CapabilityUniformAndStorageBuffer8BitAccess => {
+                UniformAndStorageBuffer8BitAccess
+            }
+            br::spv::Capability_CapabilityStoragePushConstant8 => StoragePushConstant8,
+            br::spv::Capability_CapabilityDenormPreserve => DenormPreserve,
+            br::spv::Capability_CapabilityDenormFlushToZero => DenormFlushToZero,
+            br::spv::Capability_CapabilitySignedZeroInfNanPreserve => SignedZeroInfNanPreserve,
+            br::spv::Capability_CapabilityRoundingModeRTE => RoundingModeRTE,
+            br::spv::Capability_CapabilityRoundingModeRTZ => RoundingModeRTZ,
+            br::spv::Capability_CapabilityRayQueryProvisionalKHR => RayQueryProvisional,
+            br::spv::Capability_CapabilityRayQueryKHR => RayQuery,
+            br::spv::Capability_CapabilityRayTraversalPrimitiveCullingKHR => {
+                RayTraversalPrimitiveCulling
+            }
+            br::spv::Capability_CapabilityRayTracingKHR => RayTracing,
+            br::spv::Capability_CapabilityFloat16ImageAMD => Float16ImageAMD,
+            br::spv::Capability_CapabilityImageGatherBiasLodAMD => ImageGatherBiasLodAMD,
+            br::spv::Capability_CapabilityFragmentMaskAMD => FragmentMaskAMD,
+            br::spv::Capability_CapabilityStencilExportEXT => StencilExport,
+            br::spv::Capability_CapabilityImageReadWriteLodAMD => ImageReadWriteLodAMD,
+            br::spv::Capability_CapabilityInt64ImageEXT => Int64Image,
+            br::spv::Capability_CapabilityShaderClockKHR => ShaderClock,
+            br::spv::Capability_CapabilitySampleMaskOverrideCoverageNV => {
+                SampleMaskOverrideCoverageNV
+            }
+            br::spv::Capability_CapabilityGeometryShaderPassthroughNV => {
+                GeometryShaderPassthroughNV
+            }
+            br::spv::Capability_CapabilityShaderViewportIndexLayerEXT => ShaderViewportIndexLayer,
+            br::spv::Capability_CapabilityShaderViewportMaskNV => ShaderViewportMaskNV,
+            br::spv::Capability_CapabilityShaderStereoViewNV => ShaderStereoViewNV,
+            br::spv::Capability_CapabilityPerViewAttributesNV => PerViewAttributesNV,
+            br::spv::Capability_CapabilityFragmentFullyCoveredEXT => FragmentFullyCovered,
+            br::spv::Capability_CapabilityMeshShadingNV => MeshShadingNV,
+            br::spv::Capability_CapabilityImageFootprintNV => ImageFootprintNV,
+            br::spv::Capability_CapabilityMeshShadingEXT => MeshShading,
+            br::spv::Capability_CapabilityFragmentBarycentricNV => FragmentBarycentricNV,
+            br::spv::Capability_CapabilityComputeDerivativeGroupQuadsNV => {
+                ComputeDerivativeGroupQuadsNV
+            }
+            br::spv::Capability_CapabilityFragmentDensityEXT => FragmentDensity,
+            br::spv::Capability_CapabilityGroupNonUniformPartitionedNV => {
+                GroupNonUniformPartitionedNV
+            }
+            br::spv::Capability_CapabilityShaderNonUniform => ShaderNonUniform,
+            br::spv::Capability_CapabilityRuntimeDescriptorArray => RuntimeDescriptorArray,
+            br::spv::Capability_CapabilityInputAttachmentArrayDynamicIndexing => {
+                InputAttachmentArrayDynamicIndexing
+            }
+            br::spv::Capability_CapabilityUniformTexelBufferArrayDynamicIndexing => {
+                UniformTexelBufferArrayDynamicIndexing
+            }
+            br::spv::Capability_CapabilityStorageTexelBufferArrayDynamicIndexing => {
+                StorageTexelBufferArrayDynamicIndexing
+            }
+            br::spv::Capability_CapabilityUniformBufferArrayNonUniformIndexing => {
+                UniformBufferArrayNonUniformIndexing
+            }
+            br::spv::Capability_CapabilitySampledImageArrayNonUniformIndexing => {
+                SampledImageArrayNonUniformIndexing
+            }
+            br::spv::Capability_CapabilityStorageBufferArrayNonUniformIndexing => {
+                StorageBufferArrayNonUniformIndexing
+            }
+            br::spv::Capability_CapabilityStorageImageArrayNonUniformIndexing => {
+                StorageImageArrayNonUniformIndexing
+            }
+            br::spv::Capability_CapabilityInputAttachmentArrayNonUniformIndexing => {
+                InputAttachmentArrayNonUniformIndexing
+            }
+            br::spv::Capability_CapabilityUniformTexelBufferArrayNonUniformIndexing => {
+                UniformTexelBufferArrayNonUniformIndexing
+            }
+            br::spv::Capability_CapabilityStorageTexelBufferArrayNonUniformIndexing => {
+                StorageTexelBufferArrayNonUniformIndexing
+            }
+            br::spv::Capability_CapabilityRayTracingNV => RayTracingNV,
+            br::spv::Capability_CapabilityVulkanMemoryModel => VulkanMemoryModel,
+            br::spv::Capability_CapabilityVulkanMemoryModelDeviceScope => {
+                VulkanMemoryModelDeviceScope
+            }
+            br::spv::Capability_CapabilityPhysicalStorageBufferAddresses => {
+                PhysicalStorageBufferAddresses
+            }
+            br::spv::Capability_CapabilityComputeDerivativeGroupLinearNV => {
+                ComputeDerivativeGroupLinearNV
+            }
+            br::spv::Capability_CapabilityRayTracingProvisionalKHR => RayTracingProvisional,
+            br::spv::Capability_CapabilityCooperativeMatrixNV => CooperativeMatrixNV,
+            br::spv::Capability_CapabilityFragmentShaderSampleInterlockEXT => {
+                FragmentShaderSampleInterlock
+            }
+            br::spv::Capability_CapabilityFragmentShaderShadingRateInterlockEXT => {
+                FragmentShaderShadingRateInterlock
+            }
+            br::spv::Capability_CapabilityShaderSMBuiltinsNV => ShaderSMBuiltinsNV,
+            br::spv::Capability_CapabilityFragmentShaderPixelInterlockEXT => {
+                FragmentShaderPixelInterlock
+            }
+            br::spv::Capability_CapabilityDemoteToHelperInvocationEXT => DemoteToHelperInvocation,
+            br::spv::Capability_CapabilitySubgroupShuffleINTEL => SubgroupShuffleINTEL,
+            br::spv::Capability_CapabilitySubgroupBufferBlockIOINTEL => SubgroupBufferBlockIOINTEL,
+            br::spv::Capability_CapabilitySubgroupImageBlockIOINTEL => SubgroupImageBlockIOINTEL,
+            br::spv::Capability_CapabilitySubgroupImageMediaBlockIOINTEL => {
+                SubgroupImageMediaBlockIOINTEL
+            }
+            br::spv::Capability_CapabilityIntegerFunctions2INTEL => IntegerFunctions2INTEL,
+            br::spv::Capability_CapabilityFunctionPointersINTEL => FunctionPointersINTEL,
+            br::spv::Capability_CapabilityIndirectReferencesINTEL => IndirectReferencesINTEL,
+            br::spv::Capability_CapabilitySubgroupAvcMotionEstimationINTEL => {
+                SubgroupAvcMotionEstimationINTEL
+            }
+            br::spv::Capability_CapabilitySubgroupAvcMotionEstimationIntraINTEL => {
+                SubgroupAvcMotionEstimationIntraINTEL
+            }
+            br::spv::Capability_CapabilitySubgroupAvcMotionEstimationChromaINTEL => {
+                SubgroupAvcMotionEstimationChromaINTEL
+            }
+            br::spv::Capability_CapabilityFPGAMemoryAttributesINTEL => FPGAMemoryAttributesINTEL,
+            br::spv::Capability_CapabilityUnstructuredLoopControlsINTEL => {
+                UnstructuredLoopControlsINTEL
+            }
+            br::spv::Capability_CapabilityFPGALoopControlsINTEL => FPGALoopControlsINTEL,
+            br::spv::Capability_CapabilityKernelAttributesINTEL => KernelAttributesINTEL,
+            br::spv::Capability_CapabilityFPGAKernelAttributesINTEL => FPGAKernelAttributesINTEL,
+            br::spv::Capability_CapabilityBlockingPipesINTEL => BlockingPipesINTEL,
+            br::spv::Capability_CapabilityFPGARegINTEL => FPGARegINTEL,
+            br::spv::Capability_CapabilityAtomicFloat32AddEXT => AtomicFloat32Add,
+            br::spv::Capability_CapabilityAtomicFloat64AddEXT => AtomicFloat64Add,
+            _ => Unknown(raw),
+        }
+    }
+}
+
+impl spirv::StorageClass {
+    fn from_raw(raw: br::spv::StorageClass) -> Self {
+        use crate::spirv::StorageClass::*;
+        match raw {
+            br::spv::StorageClass_StorageClassUniformConstant => UniformConstant,
+            br::spv::StorageClass_StorageClassInput => Input,
+            br::spv::StorageClass_StorageClassUniform => Uniform,
+            br::spv::StorageClass_StorageClassOutput => Output,
+            br::spv::StorageClass_StorageClassWorkgroup => Workgroup,
+            br::spv::StorageClass_StorageClassCrossWorkgroup => CrossWorkgroup,
+            br::spv::StorageClass_StorageClassPrivate => Private,
+            br::spv::StorageClass_StorageClassFunction => Function,
+            br::spv::StorageClass_StorageClassGeneric => Generic,
+            br::spv::StorageClass_StorageClassPushConstant => PushConstant,
+            br::spv::StorageClass_StorageClassAtomicCounter => AtomicCounter,
+            br::spv::StorageClass_StorageClassImage => Image,
+            br::spv::StorageClass_StorageClassStorageBuffer => StorageBuffer,
+            br::spv::StorageClass_StorageClassCallableDataKHR => CallableData,
+            br::spv::StorageClass_StorageClassIncomingCallableDataKHR => IncomingCallableData,
+            br::spv::StorageClass_StorageClassRayPayloadKHR => RayPayload,
+            br::spv::StorageClass_StorageClassHitAttributeKHR => HitAttribute,
+            br::spv::StorageClass_StorageClassIncomingRayPayloadKHR => IncomingRayPayload,
+            br::spv::StorageClass_StorageClassShaderRecordBufferKHR => ShaderRecordBuffer,
+            br::spv::StorageClass_StorageClassPhysicalStorageBuffer => PhysicalStorageBuffer,
+            _ => Unknown(raw),
+        }
+    }
+}
+
+impl spirv::GeometryInputPrimitive {
+    fn from_raw(raw: br::spv::ExecutionMode) -> Option<Self> {
+        use crate::spirv::GeometryInputPrimitive::*;
+        match raw {
+            br::spv::ExecutionMode_ExecutionModeInputPoints => Some(Points),
+            br::spv::ExecutionMode_ExecutionModeInputLines => Some(Lines),
+            br::spv::ExecutionMode_ExecutionModeInputLinesAdjacency => Some(LinesAdjacency),
+            br::spv::ExecutionMode_ExecutionModeTriangles => Some(Triangles),
+            br::spv::ExecutionMode_ExecutionModeInputTrianglesAdjacency => Some(TrianglesAdjacency),
+            _ => None,
+        }
+    }
+}
+
+impl spirv::GeometryOutputPrimitive {
+    fn from_raw(raw: br::spv::ExecutionMode) -> Option<Self> {
+        use crate::spirv::GeometryOutputPrimitive::*;
+        match raw {
+            br::spv::ExecutionMode_ExecutionModeOutputPoints => Some(Points),
+            br::spv::ExecutionMode_ExecutionModeOutputLineStrip => Some(LineStrip),
+            br::spv::ExecutionMode_ExecutionModeOutputTriangleStrip => Some(TriangleStrip),
+            _ => None,
+        }
+    }
+}
+
+impl spirv::ConservativeDepth {
+    fn from_raw(raw: br::spv::ExecutionMode) -> Option<Self> {
+        use crate::spirv::ConservativeDepth::*;
+        match raw {
+            br::spv::ExecutionMode_ExecutionModeDepthGreater => Some(Greater),
+            br::spv::ExecutionMode_ExecutionModeDepthLess => Some(Less),
+            br::spv::ExecutionMode_ExecutionModeDepthUnchanged => Some(Unchanged),
+            _ => None,
         }
     }
 }
@@ -68,6 +626,7 @@ impl spirv::Decoration {
             Decoration::NonWritable => D::DecorationNonWritable,
             Decoration::NonReadable => D::DecorationNonReadable,
             Decoration::Uniform => D::DecorationUniform,
+            Decoration::UniformId => D::DecorationUniformId,
             Decoration::SaturatedConversion => D::DecorationSaturatedConversion,
             Decoration::Stream => D::DecorationStream,
             Decoration::Location => D::DecorationLocation,
@@ -85,10 +644,39 @@ impl spirv::Decoration {
             Decoration::NoContraction => D::DecorationNoContraction,
             Decoration::InputAttachmentIndex => D::DecorationInputAttachmentIndex,
             Decoration::Alignment => D::DecorationAlignment,
+            Decoration::MaxByteOffset => D::DecorationMaxByteOffset,
+            Decoration::AlignmentId => D::DecorationAlignmentId,
+            Decoration::MaxByteOffsetId => D::DecorationMaxByteOffsetId,
+            Decoration::NoSignedWrap => D::DecorationNoSignedWrap,
+            Decoration::NoUnsignedWrap => D::DecorationNoUnsignedWrap,
+            Decoration::ExplicitInterpAmd => D::DecorationExplicitInterpAMD,
             Decoration::OverrideCoverageNv => D::DecorationOverrideCoverageNV,
             Decoration::PassthroughNv => D::DecorationPassthroughNV,
             Decoration::ViewportRelativeNv => D::DecorationViewportRelativeNV,
             Decoration::SecondaryViewportRelativeNv => D::DecorationSecondaryViewportRelativeNV,
+            Decoration::PerPrimitiveNv => D::DecorationPerPrimitiveNV,
+            Decoration::PerViewNv => D::DecorationPerViewNV,
+            Decoration::PerTaskNv => D::DecorationPerTaskNV,
+            Decoration::PerVertexNv => D::DecorationPerVertexNV,
+            Decoration::NonUniform => D::DecorationNonUniform,
+            Decoration::RestrictPointer => D::DecorationRestrictPointer,
+            Decoration::AliasedPointer => D::DecorationAliasedPointer,
+            Decoration::ReferencedIndirectlyIntel => D::DecorationReferencedIndirectlyINTEL,
+            Decoration::CounterBuffer => D::DecorationCounterBuffer,
+            Decoration::HlslSemanticGoogle => D::DecorationHlslSemanticGOOGLE,
+            Decoration::UserTypeGoogle => D::DecorationUserTypeGOOGLE,
+            Decoration::RegisterIntel => D::DecorationRegisterINTEL,
+            Decoration::MemoryIntel => D::DecorationMemoryINTEL,
+            Decoration::NumbanksIntel => D::DecorationNumbanksINTEL,
+            Decoration::BankwidthIntel => D::DecorationBankwidthINTEL,
+            Decoration::MaxPrivateCopiesIntel => D::DecorationMaxPrivateCopiesINTEL,
+            Decoration::SinglepumpIntel => D::DecorationSinglepumpINTEL,
+            Decoration::DoublepumpIntel => D::DecorationDoublepumpINTEL,
+            Decoration::MaxReplicatesIntel => D::DecorationMaxReplicatesINTEL,
+            Decoration::SimpleDualPortIntel => D::DecorationSimpleDualPortINTEL,
+            Decoration::MergeIntel => D::DecorationMergeINTEL,
+            Decoration::BankBitsIntel => D::DecorationBankBitsINTEL,
+            Decoration::ForcePow2DepthIntel => D::DecorationForcePow2DepthINTEL,
         }
     }
 }
@@ -159,26 +747,210 @@ impl spirv::Type {
     }
 }
 
+/// Upper bound on the total length of names held by a `NameCache`.
+const NAME_CACHE_MAX_BYTES: usize = 1 << 20;
+
+/// An ID, and the index of the member for member names.
+type NameKey = (u32, Option<u32>);
+
+/// Interned names (and member names) of IDs, so repeated reflection queries don't allocate.
+/// The cache fills behind a shared reference, so interned names can be held at once.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NameCache {
+    names: RefCell<HashMap<NameKey, Rc<str>>>,
+    num_bytes: Cell<usize>,
+}
+
+impl NameCache {
+    fn get_or_insert_with<F>(&self, key: NameKey, f: F) -> Result<Rc<str>, ErrorCode>
+    where
+        F: FnOnce() -> Result<String, ErrorCode>,
+    {
+        if let Some(name) = self.names.borrow().get(&key) {
+            return Ok(Rc::clone(name));
+        }
+        let name: Rc<str> = f()?.into();
+        // Start over rather than grow without bound when reflecting huge modules.
+        if self.num_bytes.get() + name.len() > NAME_CACHE_MAX_BYTES {
+            self.clear();
+        }
+        self.num_bytes.set(self.num_bytes.get() + name.len());
+        self.names.borrow_mut().insert(key, Rc::clone(&name));
+        Ok(name)
+    }
+
+    pub(crate) fn invalidate(&self, id: u32) {
+        let mut num_bytes = self.num_bytes.get();
+        self.names.borrow_mut().retain(|&(name_id, _), name| {
+            if name_id == id {
+                num_bytes -= name.len();
+            }
+            name_id != id
+        });
+        self.num_bytes.set(num_bytes);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.names.borrow_mut().clear();
+        self.num_bytes.set(0);
+    }
+}
+
+/// Identifies the `Ast` that created a `ResourceHandle`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(crate) struct AstTag(usize);
+
+impl Default for AstTag {
+    fn default() -> Self {
+        static NEXT_TAG: AtomicUsize = AtomicUsize::new(0);
+        AstTag(NEXT_TAG.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// A stage variable with the locations it takes, for `check_interface_compatibility`.
+struct LocatedVariable {
+    location: u32,
+    component: u32,
+    slots: u32,
+    /// Whether the variable has an outer array dimension with an element per vertex.
+    per_vertex: bool,
+    resource: spirv::Resource,
+}
+
 #[derive(Debug, Clone)]
 pub struct Compiler<TTargetData> {
     pub(crate) sc_compiler: *mut br::ScInternalCompilerBase,
     pub(crate) target_data: TTargetData,
     pub(crate) has_been_compiled: bool,
+    pub(crate) has_compiler_options: bool,
+    /// Whether names, decorations, constants or other parts of the module were changed since
+    /// `parse`, other than by compiler options.
+    pub(crate) has_been_modified: bool,
+    /// The parsed module, kept so that `Ast::reset_to_parsed` can parse it again.
+    pub(crate) module_words: Vec<u32>,
+    pub(crate) name_cache: NameCache,
+    pub(crate) clip_space_fixups: spirv::ClipSpaceFixups,
+    pub(crate) ast_tag: AstTag,
+    /// Found when the module is parsed, as HLSL and MSL check it on every compile.
+    pub(crate) shader_clock_usage: spirv::ShaderClockUsage,
+    pub(crate) preprocessor_defines: Vec<(String, Option<String>)>,
+    pub(crate) specialization_constant_macros: BTreeMap<u32, String>,
+    /// Whether the defines and macros were added as header lines, which can't be removed again.
+    pub(crate) header_macros_added: bool,
+}
+
+/// Copies a shader compiled with `sc_internal_compiler_compile_retained` into `shader`, growing
+/// it once to `length` bytes, and frees the bridge's copy.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) unsafe fn read_compiled_shader(
+    compiled: *mut br::ScInternalCompiledShader,
+    length: usize,
+    shader: &mut String,
+) -> Result<(), ErrorCode> {
+    let mut bytes = std::mem::take(shader).into_bytes();
+    bytes.clear();
+    bytes.reserve(length);
+    let result = br::sc_internal_compiled_shader_copy(compiled, bytes.as_mut_ptr() as *mut _);
+    check!(br::sc_internal_compiled_shader_delete(compiled));
+    check!(result);
+    bytes.set_len(length);
+    *shader = String::from_utf8(bytes).map_err(|_| ErrorCode::Unhandled)?;
+    Ok(())
 }
 
 impl<TTargetData> Compiler<TTargetData> {
-    #[cfg(any(feature = "glsl", feature = "hlsl"))]
-    pub fn compile(&mut self) -> Result<String, ErrorCode> {
+    #[cfg(all(any(feature = "glsl", feature = "hlsl"), not(target_arch = "wasm32")))]
+    pub fn compile_into(&mut self, shader: &mut String) -> Result<(), ErrorCode> {
+        unsafe {
+            let mut compiled = ptr::null_mut();
+            let mut length = 0;
+            check!(br::sc_internal_compiler_compile_retained(
+                self.sc_compiler,
+                &mut compiled,
+                &mut length,
+            ));
+            read_compiled_shader(compiled, length, shader)
+        }
+    }
+
+    #[cfg(all(any(feature = "glsl", feature = "hlsl"), target_arch = "wasm32"))]
+    pub fn compile_into(&mut self, shader: &mut String) -> Result<(), ErrorCode> {
         unsafe {
             let mut shader_ptr = ptr::null();
             check!(br::sc_internal_compiler_compile(
                 self.sc_compiler,
                 &mut shader_ptr,
             ));
-            let shader = read_string_from_ptr(shader_ptr)?;
+            let result = crate::ptr_util::read_string_into_from_ptr(shader_ptr, shader);
             check!(br::sc_internal_free_pointer(shader_ptr as *mut c_void));
-            Ok(shader)
+            result
+        }
+    }
+
+    pub fn add_header_line(&self, line: &str) -> Result<(), ErrorCode> {
+        let line = CString::new(line).map_err(|_| ErrorCode::Unhandled)?;
+        unsafe {
+            check!(br::sc_internal_compiler_glsl_add_header_line(
+                self.sc_compiler,
+                line.as_ptr(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn set_header_macros(
+        &mut self,
+        preprocessor_defines: &[(String, Option<String>)],
+        specialization_constant_macros: &BTreeMap<u32, String>,
+    ) -> Result<(), ErrorCode> {
+        if self.header_macros_added
+            && (self.preprocessor_defines != preprocessor_defines
+                || self.specialization_constant_macros != *specialization_constant_macros)
+        {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`preprocessor_defines` and `specialization_constant_macros` can't change once the module has been compiled",
+            )));
+        }
+        self.preprocessor_defines = preprocessor_defines.to_vec();
+        self.specialization_constant_macros = specialization_constant_macros.clone();
+        Ok(())
+    }
+
+    pub fn add_header_macros(&mut self) -> Result<(), ErrorCode> {
+        if self.header_macros_added {
+            return Ok(());
+        }
+
+        for (name, value) in &self.preprocessor_defines {
+            let line = match value {
+                Some(value) => format!("#define {} {}", name, value),
+                None => format!("#define {}", name),
+            };
+            self.add_header_line(&line)?;
+        }
+        let bool_constants = find_bool_specialization_constants(&self.module_words);
+        let constants = self.get_specialization_constants()?;
+        for (constant_id, name) in &self.specialization_constant_macros {
+            let constant_macro = format!("SPIRV_CROSS_CONSTANT_ID_{}", constant_id);
+            let is_bool = constants.iter().any(|constant| {
+                constant.constant_id == *constant_id && bool_constants.contains(&constant.id)
+            });
+            self.add_header_line(&format!("#ifdef {}", name))?;
+            if is_bool {
+                // GLSL doesn't convert integers to `bool`, so the value of the macro is tested.
+                self.add_header_line(&format!("#if {}", name))?;
+                self.add_header_line(&format!("#define {} true", constant_macro))?;
+                self.add_header_line("#else")?;
+                self.add_header_line(&format!("#define {} false", constant_macro))?;
+                self.add_header_line("#endif")?;
+            } else {
+                self.add_header_line(&format!("#define {} {}", constant_macro, name))?;
+            }
+            self.add_header_line("#endif")?;
         }
+        self.header_macros_added = true;
+
+        Ok(())
     }
 
     pub fn get_decoration(&self, id: u32, decoration: spirv::Decoration) -> Result<u32, ErrorCode> {
@@ -194,7 +966,61 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(result)
     }
 
-    pub fn get_name(&mut self, id: u32) -> Result<String, ErrorCode> {
+    pub fn get_decoration_string(
+        &self,
+        id: u32,
+        decoration: spirv::Decoration,
+    ) -> Result<String, ErrorCode> {
+        unsafe {
+            let mut argument_ptr = ptr::null();
+            check!(br::sc_internal_compiler_get_decoration_string(
+                self.sc_compiler,
+                id,
+                decoration.as_raw(),
+                &mut argument_ptr,
+            ));
+            let argument = read_string_from_ptr(argument_ptr)?;
+            check!(br::sc_internal_free_pointer(argument_ptr as *mut c_void));
+            Ok(argument)
+        }
+    }
+
+    pub fn set_decoration_string(
+        &mut self,
+        id: u32,
+        decoration: spirv::Decoration,
+        argument: &str,
+    ) -> Result<(), ErrorCode> {
+        let argument = CString::new(argument).map_err(|_| ErrorCode::Unhandled)?;
+        unsafe {
+            check!(br::sc_internal_compiler_set_decoration_string(
+                self.sc_compiler,
+                id,
+                decoration.as_raw(),
+                argument.as_ptr(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn has_decoration(
+        &self,
+        id: u32,
+        decoration: spirv::Decoration,
+    ) -> Result<bool, ErrorCode> {
+        let mut result = false;
+        unsafe {
+            check!(br::sc_internal_compiler_has_decoration(
+                self.sc_compiler,
+                &mut result,
+                id,
+                decoration.as_raw(),
+            ));
+        }
+        Ok(result)
+    }
+
+    pub fn get_name(&self, id: u32) -> Result<String, ErrorCode> {
         unsafe {
             let mut name_ptr = ptr::null();
             check!(br::sc_internal_compiler_get_name(
@@ -208,6 +1034,21 @@ impl<TTargetData> Compiler<TTargetData> {
         }
     }
 
+    pub fn get_name_interned(&self, id: u32) -> Result<Rc<str>, ErrorCode> {
+        let sc_compiler = self.sc_compiler;
+        self.name_cache.get_or_insert_with((id, None), || unsafe {
+            let mut name_ptr = ptr::null();
+            check!(br::sc_internal_compiler_get_name(
+                sc_compiler,
+                id,
+                &mut name_ptr,
+            ));
+            let name = read_string_from_ptr(name_ptr)?;
+            check!(br::sc_internal_free_pointer(name_ptr as *mut c_void));
+            Ok(name)
+        })
+    }
+
     pub fn set_name(&mut self, id: u32, name: &str) -> Result<(), ErrorCode> {
         let name = CString::new(name);
         unsafe {
@@ -409,6 +1250,10 @@ impl<TTargetData> Compiler<TTargetData> {
     }
 
     pub fn get_type(&self, id: u32) -> Result<spirv::Type, ErrorCode> {
+        Ok(self.get_type_details(id)?.ty)
+    }
+
+    pub fn get_type_details(&self, id: u32) -> Result<spirv::TypeDetails, ErrorCode> {
         unsafe {
             let mut type_ptr = std::mem::zeroed();
 
@@ -421,7 +1266,7 @@ impl<TTargetData> Compiler<TTargetData> {
             let raw = read_from_ptr::<br::ScType>(type_ptr);
             let member_types = read_into_vec_from_ptr(raw.member_types, raw.member_types_size);
             let array = read_into_vec_from_ptr(raw.array, raw.array_size);
-            let result = Type::from_raw(raw.type_, raw.vecsize, raw.columns, member_types, array);
+            let array_size_literal = read_into_vec_from_ptr(raw.array_size_literal, raw.array_size);
 
             if raw.member_types_size > 0 {
                 check!(br::sc_internal_free_pointer(
@@ -430,11 +1275,156 @@ impl<TTargetData> Compiler<TTargetData> {
             }
             if raw.array_size > 0 {
                 check!(br::sc_internal_free_pointer(raw.array as *mut c_void));
+                check!(br::sc_internal_free_pointer(
+                    raw.array_size_literal as *mut c_void
+                ));
             }
             check!(br::sc_internal_free_pointer(type_ptr as *mut c_void));
 
-            Ok(result)
+            let array_dimensions = array
+                .iter()
+                .zip(array_size_literal)
+                .map(|(&size, literal)| match (size, literal) {
+                    (0, true) => spirv::ArrayDimension::Runtime,
+                    (size, true) => spirv::ArrayDimension::Literal(size),
+                    (id, false) => spirv::ArrayDimension::SpecializationConstant(id),
+                })
+                .collect();
+            let pointer = if raw.pointer {
+                Some(spirv::PointerType {
+                    storage_class: spirv::StorageClass::from_raw(raw.storage),
+                    pointee_type_id: raw.parent_type,
+                })
+            } else {
+                None
+            };
+            let ty = Type::from_raw(raw.type_, raw.vecsize, raw.columns, member_types, array);
+
+            Ok(spirv::TypeDetails {
+                ty,
+                array_dimensions,
+                pointer,
+            })
+        }
+    }
+
+    pub fn get_type_string(&self, id: u32) -> Result<String, ErrorCode> {
+        let details = self.get_type_details(id)?;
+        let (scalar, vecsize, columns) = match &details.ty {
+            Type::Unknown => ("unknown", 1, 1),
+            Type::Void => ("void", 1, 1),
+            Type::Boolean {
+                vecsize, columns, ..
+            } => ("bool", *vecsize, *columns),
+            Type::Char { .. } => ("char", 1, 1),
+            Type::Int {
+                vecsize, columns, ..
+            } => ("i32", *vecsize, *columns),
+            Type::UInt {
+                vecsize, columns, ..
+            } => ("u32", *vecsize, *columns),
+            Type::Int64 { vecsize, .. } => ("i64", *vecsize, 1),
+            Type::UInt64 { vecsize, .. } => ("u64", *vecsize, 1),
+            Type::AtomicCounter { .. } => ("atomic<u32>", 1, 1),
+            Type::Half {
+                vecsize, columns, ..
+            } => ("f16", *vecsize, *columns),
+            Type::Float {
+                vecsize, columns, ..
+            } => ("f32", *vecsize, *columns),
+            Type::Double {
+                vecsize, columns, ..
+            } => ("f64", *vecsize, *columns),
+            Type::Struct { .. } => ("", 1, 1),
+            Type::Image { .. } | Type::SampledImage { .. } => ("", 1, 1),
+            Type::Sampler { .. } => ("sampler", 1, 1),
+            Type::SByte { vecsize, .. } => ("i8", *vecsize, 1),
+            Type::UByte { vecsize, .. } => ("u8", *vecsize, 1),
+            Type::Short { vecsize, .. } => ("i16", *vecsize, 1),
+            Type::UShort { vecsize, .. } => ("u16", *vecsize, 1),
+            Type::ControlPointArray => ("control_point_array", 1, 1),
+            Type::AccelerationStructure => ("acceleration_structure", 1, 1),
+            Type::RayQuery => ("ray_query", 1, 1),
+            Type::Interpolant => ("interpolant", 1, 1),
+        };
+
+        let mut type_string = if let Type::Struct { .. } = details.ty {
+            // Arrays of structs keep the struct as their `self`, which holds the name.
+            let struct_id = unsafe {
+                let mut struct_id = 0;
+                check!(br::sc_internal_compiler_get_type_self(
+                    self.sc_compiler,
+                    id,
+                    &mut struct_id,
+                ));
+                struct_id
+            };
+            match self.get_name(struct_id)? {
+                name if name.is_empty() => format!("_{}", struct_id),
+                name => name,
+            }
+        } else if let Type::Image { .. } | Type::SampledImage { .. } = details.ty {
+            let image_type = self.get_image_type_raw(id)?;
+            let dimension = match spirv::ImageDimension::from_raw(image_type.dim) {
+                spirv::ImageDimension::Dim1D => "1d".to_string(),
+                spirv::ImageDimension::Dim2D => "2d".to_string(),
+                spirv::ImageDimension::Dim3D => "3d".to_string(),
+                spirv::ImageDimension::Cube => "cube".to_string(),
+                spirv::ImageDimension::Rect => "rect".to_string(),
+                spirv::ImageDimension::Buffer => "buffer".to_string(),
+                spirv::ImageDimension::SubpassData => "subpass".to_string(),
+                spirv::ImageDimension::Unknown(raw) => format!("dim{}", raw),
+            };
+            let prefix = match details.ty {
+                Type::SampledImage { .. } => "sampled_texture",
+                _ => "texture",
+            };
+            let kind = if image_type.storage {
+                "storage_"
+            } else if image_type.depth {
+                "depth_"
+            } else {
+                ""
+            };
+            let multisampled = if image_type.multisampled {
+                "multisampled_"
+            } else {
+                ""
+            };
+            let mut type_string = format!("{}_{}{}{}", prefix, kind, multisampled, dimension);
+            if image_type.arrayed {
+                type_string.push_str("_array");
+            }
+            type_string
+        } else if columns > 1 {
+            format!("mat{}x{}<{}>", columns, vecsize, scalar)
+        } else if vecsize > 1 {
+            format!("vec{}<{}>", vecsize, scalar)
+        } else {
+            scalar.to_string()
+        };
+
+        // The innermost dimension comes first.
+        for dimension in &details.array_dimensions {
+            type_string = match *dimension {
+                spirv::ArrayDimension::Literal(length) => {
+                    format!("array<{}, {}>", type_string, length)
+                }
+                // Like WGSL overrides, arrays sized by specialization constants are given
+                // by the name of the constant.
+                spirv::ArrayDimension::SpecializationConstant(constant_id) => {
+                    match self.get_name(constant_id)? {
+                        name if name.is_empty() => {
+                            format!("array<{}, _{}>", type_string, constant_id)
+                        }
+                        name => format!("array<{}, {}>", type_string, name),
+                    }
+                }
+                spirv::ArrayDimension::Runtime => format!("array<{}>", type_string),
+            };
         }
+
+        Ok(type_string)
     }
 
     pub fn get_member_name(&self, id: u32, index: u32) -> Result<String, ErrorCode> {
@@ -452,6 +1442,55 @@ impl<TTargetData> Compiler<TTargetData> {
         }
     }
 
+    pub fn get_member_name_interned(&self, id: u32, index: u32) -> Result<Rc<str>, ErrorCode> {
+        let sc_compiler = self.sc_compiler;
+        self.name_cache
+            .get_or_insert_with((id, Some(index)), || unsafe {
+                let mut name_ptr = ptr::null();
+                check!(br::sc_internal_compiler_get_member_name(
+                    sc_compiler,
+                    id,
+                    index,
+                    &mut name_ptr,
+                ));
+                let name = read_string_from_ptr(name_ptr)?;
+                check!(br::sc_internal_free_pointer(name_ptr as *mut c_void));
+                Ok(name)
+            })
+    }
+
+    pub fn set_member_name(&mut self, id: u32, index: u32, name: &str) -> Result<(), ErrorCode> {
+        let name = CString::new(name).map_err(|_| ErrorCode::Unhandled)?;
+        unsafe {
+            check!(br::sc_internal_compiler_set_member_name(
+                self.sc_compiler,
+                id,
+                index,
+                name.as_ptr(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn has_member_decoration(
+        &self,
+        id: u32,
+        index: u32,
+        decoration: Decoration,
+    ) -> Result<bool, ErrorCode> {
+        let mut result = false;
+        unsafe {
+            check!(br::sc_internal_compiler_has_member_decoration(
+                self.sc_compiler,
+                id,
+                index,
+                decoration.as_raw(),
+                &mut result,
+            ));
+        }
+        Ok(result)
+    }
+
     pub fn get_member_decoration(
         &self,
         id: u32,
@@ -491,9 +1530,27 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(())
     }
 
-    pub fn get_declared_struct_size(&self, id: u32) -> Result<u32, ErrorCode> {
-        let mut result = 0;
-        unsafe {
+    pub fn unset_member_decoration(
+        &self,
+        id: u32,
+        index: u32,
+        decoration: Decoration,
+    ) -> Result<(), ErrorCode> {
+        unsafe {
+            check!(br::sc_internal_compiler_unset_member_decoration(
+                self.sc_compiler,
+                id,
+                index,
+                decoration.as_raw(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_declared_struct_size(&self, id: u32) -> Result<u32, ErrorCode> {
+        let mut result = 0;
+        unsafe {
             check!(br::sc_internal_compiler_get_declared_struct_size(
                 self.sc_compiler,
                 id,
@@ -516,6 +1573,84 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(result)
     }
 
+    pub fn get_struct_member_layout(
+        &self,
+        id: u32,
+        index: u32,
+    ) -> Result<spirv::StructMemberLayout, ErrorCode> {
+        let member_type = match self.get_type(id)? {
+            Type::Struct { member_types, .. } => {
+                member_types.get(index as usize).copied().ok_or_else(|| {
+                    ErrorCode::CompilationError(format!("the struct has no member {}", index))
+                })?
+            }
+            _ => {
+                return Err(ErrorCode::CompilationError(String::from(
+                    "the type is not a struct",
+                )))
+            }
+        };
+
+        let array_stride = if self.has_decoration(member_type, Decoration::ArrayStride)? {
+            Some(self.get_decoration(member_type, Decoration::ArrayStride)?)
+        } else {
+            None
+        };
+        let matrix_stride = if self.has_member_decoration(id, index, Decoration::MatrixStride)? {
+            Some(self.get_member_decoration(id, index, Decoration::MatrixStride)?)
+        } else {
+            None
+        };
+        Ok(spirv::StructMemberLayout {
+            offset: self.get_member_decoration(id, index, Decoration::Offset)?,
+            size: self.get_declared_struct_member_size(id, index)?,
+            array_stride,
+            matrix_stride,
+            row_major: self.has_member_decoration(id, index, Decoration::RowMajor)?,
+        })
+    }
+
+    pub fn get_padded_struct_layout(&self, id: u32) -> Result<Vec<spirv::StructField>, ErrorCode> {
+        let member_count = match self.get_type(id)? {
+            Type::Struct { member_types, .. } => member_types.len() as u32,
+            _ => {
+                return Err(ErrorCode::CompilationError(String::from(
+                    "the type is not a struct",
+                )))
+            }
+        };
+
+        let mut members = Vec::new();
+        for index in 0..member_count {
+            members.push(spirv::StructField {
+                name: self.get_member_name(id, index)?,
+                offset: self.get_member_decoration(id, index, Decoration::Offset)?,
+                size: self.get_declared_struct_member_size(id, index)?,
+                member_index: Some(index),
+            });
+        }
+        members.sort_by_key(|member| member.offset);
+
+        let mut fields = Vec::new();
+        let mut end = 0;
+        for member in members {
+            if member.offset > end {
+                fields.push(spirv::StructField::padding(
+                    fields.len(),
+                    end,
+                    member.offset,
+                ));
+            }
+            end = end.max(member.offset + member.size);
+            fields.push(member);
+        }
+        let size = self.get_declared_struct_size(id)?;
+        if size > end {
+            fields.push(spirv::StructField::padding(fields.len(), end, size));
+        }
+        Ok(fields)
+    }
+
     pub fn get_shader_resources(&self) -> Result<spirv::ShaderResources, ErrorCode> {
         unsafe {
             let mut shader_resources_raw = MaybeUninit::uninit();
@@ -523,57 +1658,419 @@ impl<TTargetData> Compiler<TTargetData> {
                 self.sc_compiler,
                 shader_resources_raw.as_mut_ptr(),
             ));
-            let shader_resources_raw = shader_resources_raw.assume_init();
-
-            let fill_resources = |array_raw: &br::ScResourceArray| {
-                let resources = (0..array_raw.num as usize)
-                    .map(|i| {
-                        let resource_raw = read_from_ptr::<br::ScResource>(array_raw.data.add(i));
-                        let name = read_string_from_ptr(resource_raw.name)?;
-                        check!(br::sc_internal_free_pointer(
-                            resource_raw.name as *mut c_void,
-                        ));
-
-                        Ok(spirv::Resource {
-                            id: resource_raw.id,
-                            type_id: resource_raw.type_id,
-                            base_type_id: resource_raw.base_type_id,
-                            name,
-                        })
-                    })
-                    .collect::<Result<Vec<_>, ErrorCode>>();
-
-                check!(br::sc_internal_free_pointer(array_raw.data as *mut c_void));
-
-                resources
-            };
+            read_shader_resources(shader_resources_raw.assume_init())
+        }
+    }
 
-            let uniform_buffers = fill_resources(&shader_resources_raw.uniform_buffers)?;
-            let storage_buffers = fill_resources(&shader_resources_raw.storage_buffers)?;
-            let stage_inputs = fill_resources(&shader_resources_raw.stage_inputs)?;
-            let stage_outputs = fill_resources(&shader_resources_raw.stage_outputs)?;
-            let subpass_inputs = fill_resources(&shader_resources_raw.subpass_inputs)?;
-            let storage_images = fill_resources(&shader_resources_raw.storage_images)?;
-            let sampled_images = fill_resources(&shader_resources_raw.sampled_images)?;
-            let atomic_counters = fill_resources(&shader_resources_raw.atomic_counters)?;
-            let push_constant_buffers =
-                fill_resources(&shader_resources_raw.push_constant_buffers)?;
-            let separate_images = fill_resources(&shader_resources_raw.separate_images)?;
-            let separate_samplers = fill_resources(&shader_resources_raw.separate_samplers)?;
-
-            Ok(spirv::ShaderResources {
-                uniform_buffers,
-                storage_buffers,
-                stage_inputs,
-                stage_outputs,
-                subpass_inputs,
-                storage_images,
-                sampled_images,
-                atomic_counters,
-                push_constant_buffers,
-                separate_images,
-                separate_samplers,
+    pub fn get_active_shader_resources(&self) -> Result<spirv::ShaderResources, ErrorCode> {
+        unsafe {
+            let mut shader_resources_raw = MaybeUninit::uninit();
+            check!(br::sc_internal_compiler_get_active_shader_resources(
+                self.sc_compiler,
+                shader_resources_raw.as_mut_ptr(),
+            ));
+            read_shader_resources(shader_resources_raw.assume_init())
+        }
+    }
+
+    pub fn get_active_interface_variables(&self) -> Result<Vec<u32>, ErrorCode> {
+        let mut ids_raw = ptr::null_mut();
+        let mut ids_raw_length = 0;
+
+        unsafe {
+            check!(br::sc_internal_compiler_get_active_interface_variables(
+                self.sc_compiler,
+                &mut ids_raw,
+                &mut ids_raw_length,
+            ));
+
+            let mut ids = read_into_vec_from_ptr(ids_raw, ids_raw_length);
+
+            check!(br::sc_internal_free_pointer(ids_raw as *mut c_void));
+
+            ids.sort_unstable();
+            Ok(ids)
+        }
+    }
+
+    pub fn set_enabled_interface_variables(&mut self, ids: &[u32]) -> Result<(), ErrorCode> {
+        unsafe {
+            check!(br::sc_internal_compiler_set_enabled_interface_variables(
+                self.sc_compiler,
+                ids.as_ptr(),
+                ids.len(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_resources_by_descriptor_set(
+        &self,
+    ) -> Result<BTreeMap<u32, Vec<spirv::Resource>>, ErrorCode> {
+        let resources = self.get_shader_resources()?;
+        let descriptor_resources = [
+            resources.uniform_buffers,
+            resources.storage_buffers,
+            resources.subpass_inputs,
+            resources.storage_images,
+            resources.sampled_images,
+            resources.atomic_counters,
+            resources.separate_images,
+            resources.separate_samplers,
+            resources.acceleration_structures,
+        ];
+
+        let mut sets = BTreeMap::new();
+        for resource in descriptor_resources.iter().flatten() {
+            let set = self.get_decoration(resource.id, Decoration::DescriptorSet)?;
+            sets.entry(set)
+                .or_insert_with(Vec::new)
+                .push(resource.clone());
+        }
+        Ok(sets)
+    }
+
+    fn get_image_type_raw(&self, type_id: u32) -> Result<br::ScImageType, ErrorCode> {
+        unsafe {
+            let mut image_type = MaybeUninit::uninit();
+            check!(br::sc_internal_compiler_get_image_type(
+                self.sc_compiler,
+                type_id,
+                image_type.as_mut_ptr(),
+            ));
+            Ok(image_type.assume_init())
+        }
+    }
+
+    pub fn get_image_resources(&self) -> Result<Vec<spirv::ImageResource>, ErrorCode> {
+        use crate::spirv::ImageResourceKind::*;
+        let resources = self.get_shader_resources()?;
+        let kinds = [
+            (resources.sampled_images, SampledImage),
+            (resources.separate_images, SeparateImage),
+            (resources.storage_images, StorageImage),
+            (resources.subpass_inputs, SubpassInput),
+        ];
+
+        let mut images = Vec::new();
+        for (resources, kind) in kinds.iter() {
+            for resource in resources {
+                images.push(spirv::ImageResource {
+                    resource: resource.clone(),
+                    kind: *kind,
+                    image_type: self.get_image_type(resource)?,
+                });
+            }
+        }
+        Ok(images)
+    }
+
+    pub fn get_image_type(
+        &self,
+        resource: &spirv::Resource,
+    ) -> Result<spirv::ImageType, ErrorCode> {
+        let image_type = self.get_image_type_raw(resource.base_type_id)?;
+        if image_type.dim == br::spv::Dim_DimMax {
+            return Err(ErrorCode::CompilationError(format!(
+                "`{}` is not an image",
+                resource.name
+            )));
+        }
+
+        let access = match image_type.access {
+            br::spv::AccessQualifier_AccessQualifierReadOnly => spirv::ImageAccess::ReadOnly,
+            br::spv::AccessQualifier_AccessQualifierWriteOnly => spirv::ImageAccess::WriteOnly,
+            br::spv::AccessQualifier_AccessQualifierReadWrite => spirv::ImageAccess::ReadWrite,
+            // Shaders declare the access of storage images by decorating the variable instead.
+            _ if image_type.storage => {
+                if self.has_decoration(resource.id, Decoration::NonWritable)? {
+                    spirv::ImageAccess::ReadOnly
+                } else if self.has_decoration(resource.id, Decoration::NonReadable)? {
+                    spirv::ImageAccess::WriteOnly
+                } else {
+                    spirv::ImageAccess::ReadWrite
+                }
+            }
+            _ => spirv::ImageAccess::ReadOnly,
+        };
+
+        Ok(spirv::ImageType {
+            dimension: spirv::ImageDimension::from_raw(image_type.dim),
+            arrayed: image_type.arrayed,
+            multisampled: image_type.multisampled,
+            depth: image_type.depth,
+            storage: image_type.storage,
+            format: spirv::ImageFormat::from_raw(image_type.format),
+            access,
+        })
+    }
+
+    pub fn get_multisampled_images(&self) -> Result<Vec<spirv::ImageResource>, ErrorCode> {
+        Ok(self
+            .get_image_resources()?
+            .into_iter()
+            .filter(|image| {
+                image.image_type.multisampled
+                    && image.kind != spirv::ImageResourceKind::SubpassInput
             })
+            .collect())
+    }
+
+    pub fn check_interface_compatibility<TNextTargetData>(
+        &self,
+        next: &Compiler<TNextTargetData>,
+        options: &spirv::InterfaceMatchOptions,
+    ) -> Result<spirv::InterfaceCompatibility, ErrorCode> {
+        use crate::spirv::ExecutionModel::*;
+        // Tessellation control outputs and tessellation and geometry inputs have an outer
+        // array dimension with an element per vertex.
+        let outputs_per_vertex = self.get_execution_model()? == TessellationControl;
+        let inputs_per_vertex = matches!(
+            next.get_execution_model()?,
+            TessellationControl | TessellationEvaluation | Geometry
+        );
+
+        let mut mismatches = Vec::new();
+        let outputs = self.get_located_variables(
+            self.get_shader_resources()?.stage_outputs,
+            outputs_per_vertex,
+            &mut mismatches,
+        )?;
+        let inputs = next.get_located_variables(
+            next.get_shader_resources()?.stage_inputs,
+            inputs_per_vertex,
+            &mut mismatches,
+        )?;
+
+        for input in &inputs {
+            let output = outputs.iter().find(|output| {
+                output.component == input.component
+                    && output.location <= input.location
+                    && input.location < output.location + output.slots
+            });
+            match output {
+                None => mismatches.push(spirv::InterfaceMismatch::MissingOutput {
+                    location: input.location,
+                    component: input.component,
+                    input: input.resource.clone(),
+                }),
+                Some(output) => {
+                    // An input starting inside a matrix or array output can't match it.
+                    let types_match = output.location == input.location
+                        && self.interface_types_match(
+                            (output.resource.type_id, output.per_vertex),
+                            next,
+                            (input.resource.type_id, input.per_vertex),
+                            options,
+                        )?;
+                    if !types_match {
+                        mismatches.push(spirv::InterfaceMismatch::TypeMismatch {
+                            location: input.location,
+                            component: input.component,
+                            output: output.resource.clone(),
+                            input: input.resource.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        if !options.allow_unused_outputs {
+            for output in &outputs {
+                let is_read = inputs.iter().any(|input| {
+                    input.component == output.component
+                        && input.location < output.location + output.slots
+                        && output.location < input.location + input.slots
+                });
+                if !is_read {
+                    mismatches.push(spirv::InterfaceMismatch::UnusedOutput {
+                        location: output.location,
+                        component: output.component,
+                        output: output.resource.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(spirv::InterfaceCompatibility { mismatches })
+    }
+
+    /// Gets the locations of stage variables, ordered by `Location` and `Component`. Variables
+    /// without a `Location` are reported as mismatches instead.
+    fn get_located_variables(
+        &self,
+        resources: Vec<spirv::Resource>,
+        per_vertex: bool,
+        mismatches: &mut Vec<spirv::InterfaceMismatch>,
+    ) -> Result<Vec<LocatedVariable>, ErrorCode> {
+        let mut variables = Vec::new();
+        for resource in resources {
+            if !self.has_decoration(resource.id, Decoration::Location)? {
+                mismatches.push(spirv::InterfaceMismatch::MissingLocation { resource });
+                continue;
+            }
+            let per_vertex = per_vertex && !self.has_decoration(resource.id, Decoration::Patch)?;
+            variables.push(LocatedVariable {
+                location: self.get_decoration(resource.id, Decoration::Location)?,
+                component: self.get_decoration(resource.id, Decoration::Component)?,
+                slots: self.get_location_slots(resource.type_id, per_vertex)?,
+                per_vertex,
+                resource,
+            });
+        }
+        variables.sort_by_key(|variable| (variable.location, variable.component));
+        Ok(variables)
+    }
+
+    /// Gets the number of locations a stage variable of a type takes. Matrices take a location
+    /// per column, arrays one per element and 64-bit vectors with more than two components two.
+    /// `per_vertex` leaves out the outer array dimension of per-vertex variables.
+    pub(crate) fn get_location_slots(
+        &self,
+        type_id: u32,
+        per_vertex: bool,
+    ) -> Result<u32, ErrorCode> {
+        let ty = self.get_type(type_id)?;
+        let (slots, array) = match &ty {
+            Type::Struct {
+                member_types,
+                array,
+            } => {
+                let mut slots = 0;
+                for &member_type in member_types {
+                    slots += self.get_location_slots(member_type, false)?;
+                }
+                (slots, &array[..])
+            }
+            ty => match Self::numeric_type_shape(ty) {
+                Some((class, vecsize, columns, array)) => {
+                    let is_64_bit = class == 2 || class == 4;
+                    let column_slots = if is_64_bit && vecsize > 2 { 2 } else { 1 };
+                    (columns * column_slots, array)
+                }
+                None => (1, &[][..]),
+            },
+        };
+        Ok(Self::strip_per_vertex_array(array, per_vertex)
+            .iter()
+            .fold(slots, |slots, &len| slots * len.max(1)))
+    }
+
+    /// Removes the outer dimension of a per-vertex array, which is the last in `array`.
+    fn strip_per_vertex_array(array: &[u32], per_vertex: bool) -> &[u32] {
+        match array.split_last() {
+            Some((_, inner)) if per_vertex => inner,
+            _ => array,
+        }
+    }
+
+    fn interface_types_match<TNextTargetData>(
+        &self,
+        (output_type_id, output_per_vertex): (u32, bool),
+        next: &Compiler<TNextTargetData>,
+        (input_type_id, input_per_vertex): (u32, bool),
+        options: &spirv::InterfaceMatchOptions,
+    ) -> Result<bool, ErrorCode> {
+        let output_type = self.get_type(output_type_id)?;
+        let input_type = next.get_type(input_type_id)?;
+
+        if let (
+            Type::Struct {
+                member_types: output_members,
+                array: output_array,
+            },
+            Type::Struct {
+                member_types: input_members,
+                array: input_array,
+            },
+        ) = (&output_type, &input_type)
+        {
+            if Self::strip_per_vertex_array(output_array, output_per_vertex)
+                != Self::strip_per_vertex_array(input_array, input_per_vertex)
+                || output_members.len() != input_members.len()
+            {
+                return Ok(false);
+            }
+            for (&output_member, &input_member) in output_members.iter().zip(input_members) {
+                if !self.interface_types_match(
+                    (output_member, false),
+                    next,
+                    (input_member, false),
+                    options,
+                )? {
+                    return Ok(false);
+                }
+            }
+            return Ok(true);
+        }
+
+        let (output_class, output_vecsize, output_columns, output_array) =
+            match Self::numeric_type_shape(&output_type) {
+                Some(shape) => shape,
+                None => return Ok(false),
+            };
+        let (input_class, input_vecsize, input_columns, input_array) =
+            match Self::numeric_type_shape(&input_type) {
+                Some(shape) => shape,
+                None => return Ok(false),
+            };
+
+        let scalars_match = if options.allow_scalar_type_mismatch {
+            output_class == input_class
+        } else {
+            std::mem::discriminant(&output_type) == std::mem::discriminant(&input_type)
+        };
+        let vecsizes_match = output_vecsize == input_vecsize
+            || (options.allow_component_truncation && output_vecsize > input_vecsize);
+        Ok(scalars_match
+            && vecsizes_match
+            && output_columns == input_columns
+            && Self::strip_per_vertex_array(output_array, output_per_vertex)
+                == Self::strip_per_vertex_array(input_array, input_per_vertex))
+    }
+
+    /// Gets the scalar class, vector size, column count and array dimensions of a numeric type.
+    /// Types of the same class differ only in signedness or width.
+    fn numeric_type_shape(ty: &Type) -> Option<(u32, u32, u32, &[u32])> {
+        match ty {
+            Type::Boolean {
+                vecsize,
+                columns,
+                array,
+            } => Some((0, *vecsize, *columns, array)),
+            Type::Half {
+                vecsize,
+                columns,
+                array,
+            }
+            | Type::Float {
+                vecsize,
+                columns,
+                array,
+            } => Some((1, *vecsize, *columns, array)),
+            Type::Double {
+                vecsize,
+                columns,
+                array,
+            } => Some((2, *vecsize, *columns, array)),
+            Type::Int {
+                vecsize,
+                columns,
+                array,
+            }
+            | Type::UInt {
+                vecsize,
+                columns,
+                array,
+            } => Some((3, *vecsize, *columns, array)),
+            Type::SByte { vecsize, array }
+            | Type::UByte { vecsize, array }
+            | Type::Short { vecsize, array }
+            | Type::UShort { vecsize, array } => Some((3, *vecsize, 1, array)),
+            Type::Int64 { vecsize, array } | Type::UInt64 { vecsize, array } => {
+                Some((4, *vecsize, 1, array))
+            }
+            _ => None,
         }
     }
 
@@ -618,6 +2115,469 @@ impl<TTargetData> Compiler<TTargetData> {
         }
     }
 
+    pub fn get_buffer_block_flags(&self, id: u32) -> Result<spirv::BufferBlockFlags, ErrorCode> {
+        let mut decorations_raw = ptr::null_mut();
+        let mut decorations_raw_length = 0;
+
+        let decorations: Vec<u32> = unsafe {
+            check!(br::sc_internal_compiler_get_buffer_block_flags(
+                self.sc_compiler,
+                id,
+                &mut decorations_raw,
+                &mut decorations_raw_length,
+            ));
+
+            let decorations = read_into_vec_from_ptr(decorations_raw, decorations_raw_length);
+
+            check!(br::sc_internal_free_pointer(decorations_raw as *mut c_void));
+
+            decorations
+        };
+
+        let has = |decoration: Decoration| decorations.contains(&(decoration.as_raw() as u32));
+        Ok(spirv::BufferBlockFlags {
+            non_writable: has(Decoration::NonWritable),
+            non_readable: has(Decoration::NonReadable),
+            restrict: has(Decoration::Restrict),
+            coherent: has(Decoration::Coherent),
+            volatile: has(Decoration::Volatile),
+        })
+    }
+
+    pub fn get_declared_capabilities(&self) -> Result<Vec<spirv::Capability>, ErrorCode> {
+        let mut capabilities_raw = ptr::null_mut();
+        let mut capabilities_raw_length = 0;
+
+        unsafe {
+            check!(br::sc_internal_compiler_get_declared_capabilities(
+                self.sc_compiler,
+                &mut capabilities_raw,
+                &mut capabilities_raw_length,
+            ));
+
+            let capabilities = read_into_vec_from_ptr(capabilities_raw, capabilities_raw_length);
+
+            check!(br::sc_internal_free_pointer(
+                capabilities_raw as *mut c_void
+            ));
+
+            Ok(capabilities
+                .into_iter()
+                .map(spirv::Capability::from_raw)
+                .collect())
+        }
+    }
+
+    pub fn get_declared_extensions(&self) -> Result<Vec<String>, ErrorCode> {
+        let mut extensions_raw = ptr::null_mut();
+        let mut extensions_raw_length = 0;
+
+        unsafe {
+            check!(br::sc_internal_compiler_get_declared_extensions(
+                self.sc_compiler,
+                &mut extensions_raw,
+                &mut extensions_raw_length,
+            ));
+
+            // Every string is freed before an error reading any of them is returned.
+            let extension_ptrs = read_into_vec_from_ptr(extensions_raw, extensions_raw_length);
+            let extensions = extension_ptrs
+                .iter()
+                .map(|&extension_ptr| read_string_from_ptr(extension_ptr))
+                .collect::<Result<Vec<_>, _>>();
+
+            for extension_ptr in extension_ptrs {
+                check!(br::sc_internal_free_pointer(extension_ptr as *mut c_void));
+            }
+            check!(br::sc_internal_free_pointer(extensions_raw as *mut c_void));
+
+            extensions
+        }
+    }
+
+    pub fn get_geometry_execution_modes(&self) -> Result<spirv::GeometryExecutionModes, ErrorCode> {
+        let mut modes_raw = MaybeUninit::uninit();
+        unsafe {
+            check!(br::sc_internal_compiler_get_geometry_execution_modes(
+                self.sc_compiler,
+                modes_raw.as_mut_ptr(),
+            ));
+            let modes_raw: br::ScGeometryExecutionModes = modes_raw.assume_init();
+
+            Ok(spirv::GeometryExecutionModes {
+                invocations: modes_raw.invocations,
+                input_primitive: spirv::GeometryInputPrimitive::from_raw(modes_raw.input_primitive),
+                output_primitive: spirv::GeometryOutputPrimitive::from_raw(
+                    modes_raw.output_primitive,
+                ),
+                max_vertices: modes_raw.max_vertices,
+            })
+        }
+    }
+
+    pub fn get_fragment_depth_modes(&self) -> Result<spirv::FragmentDepthModes, ErrorCode> {
+        if self.get_execution_model()? != spirv::ExecutionModel::Fragment {
+            return Ok(Default::default());
+        }
+
+        let mut modes_raw = MaybeUninit::uninit();
+        unsafe {
+            check!(br::sc_internal_compiler_get_depth_execution_modes(
+                self.sc_compiler,
+                modes_raw.as_mut_ptr(),
+            ));
+            let modes_raw: br::ScDepthExecutionModes = modes_raw.assume_init();
+
+            Ok(spirv::FragmentDepthModes {
+                writes_depth: self.is_builtin_output_active(spirv::BuiltIn::FragDepth)?,
+                conservative_depth: spirv::ConservativeDepth::from_raw(
+                    modes_raw.conservative_depth,
+                ),
+                early_fragment_tests: modes_raw.early_fragment_tests,
+            })
+        }
+    }
+
+    pub fn get_execution_modes(&self) -> Result<Vec<spirv::ExecutionModeInfo>, ErrorCode> {
+        let mut modes_raw = ptr::null_mut();
+        let mut modes_raw_length = 0;
+
+        let mut modes_raw = unsafe {
+            check!(br::sc_internal_compiler_get_execution_modes(
+                self.sc_compiler,
+                &mut modes_raw,
+                &mut modes_raw_length,
+            ));
+
+            let modes = read_into_vec_from_ptr(modes_raw, modes_raw_length);
+
+            check!(br::sc_internal_free_pointer(modes_raw as *mut c_void));
+
+            modes
+        };
+        modes_raw.sort_unstable();
+
+        let mut modes = Vec::with_capacity(modes_raw.len());
+        for mode_raw in modes_raw {
+            let mode = spirv::ExecutionMode::from_raw(mode_raw);
+            let arguments = (0..mode.argument_count())
+                .map(|index| self.get_execution_mode_argument(mode, index))
+                .collect::<Result<_, _>>()?;
+            modes.push(spirv::ExecutionModeInfo { mode, arguments });
+        }
+        Ok(modes)
+    }
+
+    pub fn get_execution_mode_argument(
+        &self,
+        mode: spirv::ExecutionMode,
+        index: u32,
+    ) -> Result<u32, ErrorCode> {
+        let mut argument = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_execution_mode_argument(
+                self.sc_compiler,
+                mode.as_raw(),
+                index,
+                &mut argument,
+            ));
+        }
+        Ok(argument)
+    }
+
+    pub fn set_execution_mode(
+        &mut self,
+        mode: spirv::ExecutionMode,
+        arguments: &[u32],
+    ) -> Result<(), ErrorCode> {
+        if arguments.len() > 3 {
+            return Err(ErrorCode::CompilationError(format!(
+                "{:?} was given {} arguments, but at most 3 are supported",
+                mode,
+                arguments.len()
+            )));
+        }
+        let argument = |index| arguments.get(index).copied().unwrap_or(0);
+        unsafe {
+            check!(br::sc_internal_compiler_set_execution_mode(
+                self.sc_compiler,
+                mode.as_raw(),
+                argument(0),
+                argument(1),
+                argument(2),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn unset_execution_mode(&mut self, mode: spirv::ExecutionMode) -> Result<(), ErrorCode> {
+        unsafe {
+            check!(br::sc_internal_compiler_unset_execution_mode(
+                self.sc_compiler,
+                mode.as_raw(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn get_mesh_outputs(&self) -> Result<spirv::MeshOutputs, ErrorCode> {
+        let mut outputs_raw = ptr::null_mut();
+        let mut outputs_raw_length = 0;
+
+        unsafe {
+            check!(br::sc_internal_compiler_get_mesh_outputs(
+                self.sc_compiler,
+                &mut outputs_raw,
+                &mut outputs_raw_length,
+            ));
+
+            let mut outputs = spirv::MeshOutputs {
+                per_vertex: Vec::new(),
+                per_primitive: Vec::new(),
+            };
+            for output_raw in read_into_vec_from_ptr(outputs_raw, outputs_raw_length) {
+                let output = spirv::MeshOutput {
+                    id: output_raw.id,
+                    location: if output_raw.location == !0 {
+                        None
+                    } else {
+                        Some(output_raw.location)
+                    },
+                    built_in: spirv::built_in_from_raw(output_raw.built_in),
+                };
+                if output_raw.per_primitive {
+                    outputs.per_primitive.push(output);
+                } else {
+                    outputs.per_vertex.push(output);
+                }
+            }
+
+            check!(br::sc_internal_free_pointer(outputs_raw as *mut c_void));
+
+            Ok(outputs)
+        }
+    }
+
+    pub fn get_current_id_bound(&self) -> Result<u32, ErrorCode> {
+        let mut bound = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_current_id_bound(
+                self.sc_compiler,
+                &mut bound,
+            ));
+        }
+        Ok(bound)
+    }
+
+    pub fn get_execution_model(&self) -> Result<spirv::ExecutionModel, ErrorCode> {
+        unsafe {
+            let mut execution_model = MaybeUninit::uninit();
+            check!(br::sc_internal_compiler_get_execution_model(
+                self.sc_compiler,
+                execution_model.as_mut_ptr(),
+            ));
+            spirv::ExecutionModel::from_raw(execution_model.assume_init())
+        }
+    }
+
+    pub fn is_builtin_output_active(&self, builtin: spirv::BuiltIn) -> Result<bool, ErrorCode> {
+        let mut is_active = false;
+        unsafe {
+            check!(br::sc_internal_compiler_is_builtin_output_active(
+                self.sc_compiler,
+                spirv::built_in_as_raw(Some(builtin)),
+                &mut is_active,
+            ));
+        }
+        Ok(is_active)
+    }
+
+    pub fn get_active_builtins(&self) -> Result<spirv::BuiltInSet, ErrorCode> {
+        let mut builtins_raw = ptr::null_mut();
+        let mut builtins_raw_length = 0;
+
+        unsafe {
+            check!(br::sc_internal_compiler_get_active_builtins(
+                self.sc_compiler,
+                &mut builtins_raw,
+                &mut builtins_raw_length,
+            ));
+
+            let builtins = read_into_vec_from_ptr(builtins_raw, builtins_raw_length);
+
+            check!(br::sc_internal_free_pointer(builtins_raw as *mut c_void));
+
+            // Builtins newer than `spirv::BuiltIn` are left out.
+            Ok(builtins
+                .into_iter()
+                .filter_map(spirv::built_in_from_u32)
+                .collect())
+        }
+    }
+
+    pub fn get_layered_rendering_outputs(
+        &self,
+    ) -> Result<spirv::LayeredRenderingOutputs, ErrorCode> {
+        use crate::spirv::ExecutionModel::*;
+        let layer = self.is_builtin_output_active(spirv::BuiltIn::Layer)?;
+        let viewport_index = self.is_builtin_output_active(spirv::BuiltIn::ViewportIndex)?;
+        let from_vertex_processing = (layer || viewport_index)
+            && matches!(self.get_execution_model()?, Vertex | TessellationEvaluation);
+        Ok(spirv::LayeredRenderingOutputs {
+            layer,
+            viewport_index,
+            from_vertex_processing,
+        })
+    }
+
+    pub fn get_transform_feedback_outputs(
+        &self,
+    ) -> Result<Vec<spirv::TransformFeedbackOutput>, ErrorCode> {
+        let mut outputs = Vec::new();
+        for output in self.get_shader_resources()?.stage_outputs {
+            if !self.has_decoration(output.id, Decoration::XfbBuffer)? {
+                continue;
+            }
+            let buffer = self.get_decoration(output.id, Decoration::XfbBuffer)?;
+            let stride = self.get_decoration(output.id, Decoration::XfbStride)?;
+
+            if self.has_decoration(output.id, Decoration::Offset)? {
+                outputs.push(spirv::TransformFeedbackOutput {
+                    id: output.id,
+                    member_index: None,
+                    name: output.name.clone(),
+                    buffer,
+                    stride,
+                    offset: self.get_decoration(output.id, Decoration::Offset)?,
+                });
+            } else if let Type::Struct { member_types, .. } = self.get_type(output.base_type_id)? {
+                // Output blocks are captured member by member, named `Block.member` in GL.
+                let block_name = self.get_name(output.base_type_id)?;
+                for index in 0..member_types.len() as u32 {
+                    if !self.has_member_decoration(
+                        output.base_type_id,
+                        index,
+                        Decoration::Offset,
+                    )? {
+                        continue;
+                    }
+                    outputs.push(spirv::TransformFeedbackOutput {
+                        id: output.id,
+                        member_index: Some(index),
+                        name: format!(
+                            "{}.{}",
+                            block_name,
+                            self.get_member_name(output.base_type_id, index)?
+                        ),
+                        buffer,
+                        stride,
+                        offset: self.get_member_decoration(
+                            output.base_type_id,
+                            index,
+                            Decoration::Offset,
+                        )?,
+                    });
+                }
+            }
+        }
+        outputs.sort_by_key(|output| (output.buffer, output.offset));
+        Ok(outputs)
+    }
+
+    pub fn get_clip_space_fixups(&self) -> Result<spirv::ClipSpaceFixups, ErrorCode> {
+        use crate::spirv::ExecutionModel::*;
+        match self.get_execution_model()? {
+            Vertex | TessellationEvaluation | Geometry => Ok(self.clip_space_fixups),
+            _ => Ok(Default::default()),
+        }
+    }
+
+    pub fn limit_identifier_length(
+        &mut self,
+        max_len: usize,
+    ) -> Result<Vec<spirv::IdentifierRename>, ErrorCode> {
+        if max_len < SHORTENED_IDENTIFIER_SUFFIX_LEN + 1 {
+            return Err(ErrorCode::CompilationError(format!(
+                "identifier length limit must be at least {}",
+                SHORTENED_IDENTIFIER_SUFFIX_LEN + 1
+            )));
+        }
+
+        // Shortened names must not clash with any other name, including those yet to be
+        // shortened, so every name is taken from the start.
+        let id_bound = self.get_current_id_bound()?;
+        let mut taken = (1..id_bound)
+            .map(|id| self.get_name(id))
+            .collect::<Result<HashSet<_>, _>>()?;
+        let mut renames = Vec::new();
+        for id in 1..id_bound {
+            let name = self.get_name(id)?;
+            if name.len() > max_len
+                && !is_reserved_identifier(&name)
+                && !self.has_decoration(id, Decoration::BuiltIn)?
+            {
+                let renamed = shorten_identifier(&name, max_len, &taken);
+                self.set_name(id, &renamed)?;
+                taken.insert(renamed.clone());
+                renames.push(spirv::IdentifierRename {
+                    id,
+                    member_index: None,
+                    original: name,
+                    renamed,
+                });
+            }
+        }
+
+        // Member names are only reachable through the struct types, so walk those used by
+        // the shader's resources.
+        let resources = self.get_shader_resources()?;
+        let mut pending = [
+            resources.uniform_buffers,
+            resources.storage_buffers,
+            resources.stage_inputs,
+            resources.stage_outputs,
+            resources.push_constant_buffers,
+        ]
+        .iter()
+        .flatten()
+        .map(|resource| resource.base_type_id)
+        .collect::<Vec<_>>();
+        let mut visited = HashSet::new();
+        while let Some(type_id) = pending.pop() {
+            if !visited.insert(type_id) {
+                continue;
+            }
+            if let Type::Struct { member_types, .. } = self.get_type(type_id)? {
+                // Members only need names unique within their struct.
+                let mut taken = (0..member_types.len() as u32)
+                    .map(|index| self.get_member_name(type_id, index))
+                    .collect::<Result<HashSet<_>, _>>()?;
+                for (index, &member_type) in member_types.iter().enumerate() {
+                    let index = index as u32;
+                    let name = self.get_member_name(type_id, index)?;
+                    if name.len() > max_len
+                        && !is_reserved_identifier(&name)
+                        && !self.has_member_decoration(type_id, index, Decoration::BuiltIn)?
+                    {
+                        let renamed = shorten_identifier(&name, max_len, &taken);
+                        self.set_member_name(type_id, index, &renamed)?;
+                        taken.insert(renamed.clone());
+                        renames.push(spirv::IdentifierRename {
+                            id: type_id,
+                            member_index: Some(index),
+                            original: name,
+                            renamed,
+                        });
+                    }
+                    pending.push(member_type);
+                }
+            }
+        }
+
+        self.name_cache.clear();
+        Ok(renames)
+    }
+
     pub fn get_work_group_size_specialization_constants(
         &self,
     ) -> Result<spirv::WorkGroupSizeSpecializationConstants, ErrorCode> {
@@ -664,3 +2624,157 @@ impl<TTargetData> Drop for Compiler<TTargetData> {
         }
     }
 }
+
+/// Finds the clocks read by `OpReadClockKHR` instructions in the module.
+pub(crate) fn find_shader_clock_usage(words: &[u32]) -> spirv::ShaderClockUsage {
+    const HEADER_LEN: usize = 5;
+    const OP_CONSTANT: u32 = 43;
+    const OP_READ_CLOCK_KHR: u32 = 5056;
+    const SCOPE_DEVICE: u32 = 1;
+    const SCOPE_SUBGROUP: u32 = 3;
+
+    let mut constants = HashMap::new();
+    let mut usage = spirv::ShaderClockUsage::default();
+    let mut offset = HEADER_LEN;
+    while offset < words.len() {
+        let word_count = (words[offset] >> 16) as usize;
+        let opcode = words[offset] & 0xffff;
+        if word_count == 0 || offset + word_count > words.len() {
+            break;
+        }
+        let operands = &words[offset + 1..offset + word_count];
+        match opcode {
+            OP_CONSTANT if operands.len() > 2 => {
+                constants.insert(operands[1], operands[2]);
+            }
+            // Scopes are constants, which are declared before any function.
+            OP_READ_CLOCK_KHR if operands.len() > 2 => match constants.get(&operands[2]) {
+                Some(&SCOPE_DEVICE) => usage.device = true,
+                Some(&SCOPE_SUBGROUP) => usage.subgroup = true,
+                _ => {}
+            },
+            _ => {}
+        }
+        offset += word_count;
+    }
+    usage
+}
+
+/// Finds the `bool` specialization constants, declared by `OpSpecConstantTrue` and
+/// `OpSpecConstantFalse`, in the module.
+fn find_bool_specialization_constants(words: &[u32]) -> HashSet<u32> {
+    const HEADER_LEN: usize = 5;
+    const OP_SPEC_CONSTANT_TRUE: u32 = 48;
+    const OP_SPEC_CONSTANT_FALSE: u32 = 49;
+
+    let mut constants = HashSet::new();
+    let mut offset = HEADER_LEN;
+    while offset < words.len() {
+        let word_count = (words[offset] >> 16) as usize;
+        let opcode = words[offset] & 0xffff;
+        if word_count == 0 || offset + word_count > words.len() {
+            break;
+        }
+        let operands = &words[offset + 1..offset + word_count];
+        match opcode {
+            OP_SPEC_CONSTANT_TRUE | OP_SPEC_CONSTANT_FALSE if operands.len() > 1 => {
+                constants.insert(operands[1]);
+            }
+            _ => {}
+        }
+        offset += word_count;
+    }
+    constants
+}
+
+/// Length of the `_xxxxxxxx` suffix appended to shortened identifiers.
+const SHORTENED_IDENTIFIER_SUFFIX_LEN: usize = 9;
+
+/// Whether `name` is reserved for builtins, such as `gl_Position`, so must be kept as is.
+fn is_reserved_identifier(name: &str) -> bool {
+    name.starts_with("gl_")
+}
+
+/// Truncates `name` to `max_len` bytes, replacing the tail with a hash of the full name so that
+/// shortened identifiers are stable between runs. Names in `taken` are avoided by hashing again
+/// with a counter, in the unlikely case of a collision.
+fn shorten_identifier(name: &str, max_len: usize, taken: &HashSet<String>) -> String {
+    let mut prefix_len = max_len - SHORTENED_IDENTIFIER_SUFFIX_LEN;
+    while !name.is_char_boundary(prefix_len) {
+        prefix_len -= 1;
+    }
+    // Identifiers containing `__` are reserved, so don't put the separator after another `_`.
+    let prefix = name[..prefix_len].trim_end_matches('_');
+
+    // 32-bit FNV-1a
+    let fnv1a = |hash, bytes: &[u8]| {
+        bytes.iter().fold(hash, |hash: u32, &byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        })
+    };
+    let hash = fnv1a(0x811c_9dc5, name.as_bytes());
+    let mut renamed = format!("{}_{:08x}", prefix, hash);
+    let mut attempt = 1_u32;
+    while taken.contains(&renamed) {
+        renamed = format!("{}_{:08x}", prefix, fnv1a(hash, &attempt.to_le_bytes()));
+        attempt += 1;
+    }
+    renamed
+}
+
+unsafe fn read_shader_resources(
+    shader_resources_raw: br::ScShaderResources,
+) -> Result<spirv::ShaderResources, ErrorCode> {
+    let fill_resources = |array_raw: &br::ScResourceArray| {
+        let resources = (0..array_raw.num as usize)
+            .map(|i| {
+                let resource_raw = read_from_ptr::<br::ScResource>(array_raw.data.add(i));
+                let name = read_string_from_ptr(resource_raw.name)?;
+                check!(br::sc_internal_free_pointer(
+                    resource_raw.name as *mut c_void,
+                ));
+
+                Ok(spirv::Resource {
+                    id: resource_raw.id,
+                    type_id: resource_raw.type_id,
+                    base_type_id: resource_raw.base_type_id,
+                    name,
+                })
+            })
+            .collect::<Result<Vec<_>, ErrorCode>>();
+
+        check!(br::sc_internal_free_pointer(array_raw.data as *mut c_void));
+
+        resources
+    };
+
+    let uniform_buffers = fill_resources(&shader_resources_raw.uniform_buffers)?;
+    let storage_buffers = fill_resources(&shader_resources_raw.storage_buffers)?;
+    let stage_inputs = fill_resources(&shader_resources_raw.stage_inputs)?;
+    let stage_outputs = fill_resources(&shader_resources_raw.stage_outputs)?;
+    let subpass_inputs = fill_resources(&shader_resources_raw.subpass_inputs)?;
+    let storage_images = fill_resources(&shader_resources_raw.storage_images)?;
+    let sampled_images = fill_resources(&shader_resources_raw.sampled_images)?;
+    let atomic_counters = fill_resources(&shader_resources_raw.atomic_counters)?;
+    let push_constant_buffers = fill_resources(&shader_resources_raw.push_constant_buffers)?;
+    let separate_images = fill_resources(&shader_resources_raw.separate_images)?;
+    let separate_samplers = fill_resources(&shader_resources_raw.separate_samplers)?;
+    let acceleration_structures = fill_resources(&shader_resources_raw.acceleration_structures)?;
+    let shader_record_buffers = fill_resources(&shader_resources_raw.shader_record_buffers)?;
+
+    Ok(spirv::ShaderResources {
+        uniform_buffers,
+        storage_buffers,
+        stage_inputs,
+        stage_outputs,
+        subpass_inputs,
+        storage_images,
+        sampled_images,
+        atomic_counters,
+        push_constant_buffers,
+        separate_images,
+        separate_samplers,
+        acceleration_structures,
+        shader_record_buffers,
+    })
+}
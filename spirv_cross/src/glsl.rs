@@ -1,6 +1,7 @@
 use crate::bindings as br;
 use crate::ptr_util::read_into_vec_from_ptr;
 use crate::{compiler, spirv, ErrorCode};
+use std::collections::{BTreeMap, HashSet};
 use std::ffi::CString;
 use std::marker::PhantomData;
 use std::ptr;
@@ -11,6 +12,18 @@ pub enum Target {}
 
 pub struct TargetData {
     combined_image_samplers_built: bool,
+    dummy_sampler_id: Option<Option<u32>>,
+    version: Version,
+    reject_unsupported_multisampled_images: bool,
+    vertex_attribute_locations: Vec<(u32, u32)>,
+    base_instance_uniform_enabled: bool,
+    vulkan_semantics: bool,
+    extension_directives: Vec<ExtensionDirective>,
+    ext_framebuffer_fetch_color_locations: BTreeMap<u32, u32>,
+    ext_framebuffer_fetch_noncoherent: bool,
+    ext_framebuffer_fetch_remapped: bool,
+    float16_to_mediump: bool,
+    float16_demotions: Vec<DemotedOperation>,
 }
 
 impl spirv::Target for Target {
@@ -38,12 +51,88 @@ pub enum Version {
     V3_00Es,
     V3_10Es,
     V3_20Es,
+    /// A version without its own variant, such as `Custom { version: 460, es: false }` for
+    /// `#version 460`.
+    Custom {
+        version: u32,
+        es: bool,
+    },
 }
 
+impl Version {
+    pub(crate) fn as_raw(self) -> (u32, bool) {
+        use self::Version::*;
+        match self {
+            V1_10 => (1_10, false),
+            V1_20 => (1_20, false),
+            V1_30 => (1_30, false),
+            V1_40 => (1_40, false),
+            V1_50 => (1_50, false),
+            V3_30 => (3_30, false),
+            V4_00 => (4_00, false),
+            V4_10 => (4_10, false),
+            V4_20 => (4_20, false),
+            V4_30 => (4_30, false),
+            V4_40 => (4_40, false),
+            V4_50 => (4_50, false),
+            V4_60 => (4_60, false),
+            V1_00Es => (1_00, true),
+            V3_00Es => (3_00, true),
+            V3_10Es => (3_10, true),
+            V3_20Es => (3_20, true),
+            Custom { version, es } => (version, es),
+        }
+    }
+
+    /// Gets the variant for a `#version` number and profile, or `Custom` if it has none.
+    pub(crate) fn from_raw(version: u32, es: bool) -> Self {
+        use self::Version::*;
+        match (version, es) {
+            (1_10, false) => V1_10,
+            (1_20, false) => V1_20,
+            (1_30, false) => V1_30,
+            (1_40, false) => V1_40,
+            (1_50, false) => V1_50,
+            (3_30, false) => V3_30,
+            (4_00, false) => V4_00,
+            (4_10, false) => V4_10,
+            (4_20, false) => V4_20,
+            (4_30, false) => V4_30,
+            (4_40, false) => V4_40,
+            (4_50, false) => V4_50,
+            (4_60, false) => V4_60,
+            (1_00, true) => V1_00Es,
+            (3_00, true) => V3_00Es,
+            (3_10, true) => V3_10Es,
+            (3_20, true) => V3_20Es,
+            (version, es) => Custom { version, es },
+        }
+    }
+
+    fn is_es(self) -> bool {
+        self.as_raw().1
+    }
+
+    /// Whether multisampled images can be declared, possibly by enabling an extension.
+    fn supports_multisampled_images(self, storage: bool) -> bool {
+        match self.as_raw() {
+            (version, true) => version >= 3_10 && !storage,
+            (version, false) => version >= 1_30,
+        }
+    }
+}
+
+/// Fixups of the vertex position output, to run SPIR-V written for Vulkan conventions on GL.
 #[derive(Debug, Clone)]
 pub struct CompilerVertexOptions {
+    /// Whether to invert the Y coordinate of the position output.
     pub invert_y: bool,
+    /// Whether to convert the depth of the position output from the `[0, 1]` range used by
+    /// Vulkan to the `[-1, 1]` range used by GL.
     pub transform_clip_space: bool,
+    /// Whether `gl_InstanceIndex` includes the base instance of the draw without Vulkan
+    /// semantics. `gl_BaseInstanceARB` is used where `ARB_shader_draw_parameters` is
+    /// available, and otherwise a uniform named by `get_base_instance_uniform_name`.
     pub support_nonzero_base_instance: bool,
 }
 
@@ -57,6 +146,7 @@ impl Default for CompilerVertexOptions {
     }
 }
 
+/// A GLSL ES precision qualifier.
 // Note: These values should match with `CompilerGLSL::Options::Precision`.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 #[repr(u8)]
@@ -67,9 +157,16 @@ pub enum Precision {
     High = 3,
 }
 
+/// Options for fragment shaders.
+///
+/// The default precisions are emitted as `precision <qualifier> float;` and
+/// `precision <qualifier> int;` when targeting ES. Other stages always default to `highp`, as
+/// their precision is `highp` unless qualified otherwise in GLSL ES.
 #[derive(Debug, Clone)]
 pub struct CompilerFragmentOptions {
+    /// The default precision of floating-point types. `Precision::Medium` by default.
     pub default_float_precision: Precision,
+    /// The default precision of integer types. `Precision::High` by default.
     pub default_int_precision: Precision,
 }
 
@@ -88,18 +185,92 @@ impl Default for CompilerFragmentOptions {
 pub struct CompilerOptions {
     pub version: Version,
     pub force_temporary: bool,
+    /// Whether to emit GLSL for Vulkan, to be compiled by glslang, rather than for OpenGL.
+    /// Descriptor `set` and `binding` qualifiers, push constant blocks, subpass inputs and
+    /// separate images and samplers are kept as they are.
     pub vulkan_semantics: bool,
+    /// Whether to emit GLSL compatible with `ARB_separate_shader_objects` program pipelines, by
+    /// redeclaring the `gl_PerVertex` blocks of non-fragment stages.
     pub separate_shader_objects: bool,
     pub flatten_multidimensional_arrays: bool,
+    /// Whether to emit `layout(binding = N)` qualifiers before GLSL 4.20 by enabling
+    /// `GL_ARB_shading_language_420pack`. When unset, bindings must be assigned at runtime,
+    /// i.e. with `glUniformBlockBinding`.
     pub enable_420_pack_extension: bool,
+    /// Whether to emit push constant blocks as uniform buffers rather than plain uniform structs
+    /// when not targeting Vulkan, so they can be backed by a buffer in GL.
     pub emit_push_constant_as_uniform_buffer: bool,
+    /// Whether to emit uniform buffers as plain uniform structs, for targets without uniform
+    /// buffer support such as GLES 2.0 and WebGL 1. Reflection still reports the original
+    /// blocks, so members can be set with their block and member names.
     pub emit_uniform_buffer_as_plain_uniforms: bool,
+    /// Whether to emit `#line` directives from the `OpLine` debug info of the module, so driver
+    /// compilation errors point at the original source lines.
     pub emit_line_directives: bool,
     pub enable_storage_image_qualifier_deduction: bool,
-    /// Whether to force all uninitialized variables to be initialized to zero.
+    /// Whether to force all uninitialized variables to be initialized to zero. This avoids
+    /// undefined values on drivers which miscompile reads of uninitialized variables, as seen
+    /// on some mobile GLES drivers.
     pub force_zero_initialized_variables: bool,
+    /// Whether to emit `NMin`, `NMax`, `NClamp` and ordered or unordered comparisons as plain
+    /// operations, without the checks needed for exact NaN semantics, for speed.
+    pub relax_nan_checks: bool,
+    /// The number of views of multiview vertex shaders, emitted as `layout(num_views = N) in;`
+    /// with `GL_OVR_multiview2` when targeting GL without Vulkan semantics. Required when the
+    /// shader reads `gl_ViewIndex`.
+    pub ovr_multiview_view_count: u32,
+    /// Whether to flatten stage input and output blocks into plain varyings named
+    /// `<block instance>_<member>`, for ES targets without `GL_EXT_shader_io_blocks`. Both stages
+    /// of a pipeline must be compiled with the option to link.
+    pub force_flattened_io_blocks: bool,
+    /// The color attachment locations read with `GL_EXT_shader_framebuffer_fetch` by subpass
+    /// inputs, keyed by their `input_attachment_index`. Each remapped subpass input reads the
+    /// `inout` fragment output at its location, rather than a texture the attachment must be
+    /// copied to between subpasses. Only applies to ES targets without Vulkan semantics. The
+    /// mapping is applied the first time the module is compiled, and can't be changed afterwards.
+    pub ext_framebuffer_fetch_color_locations: BTreeMap<u32, u32>,
+    /// Whether to read the remapped attachments with
+    /// `GL_EXT_shader_framebuffer_fetch_non_coherent` instead. Like the mapping, this can't be
+    /// changed once the module has been compiled.
+    pub ext_framebuffer_fetch_noncoherent: bool,
+    /// Whether to emit 16-bit float arithmetic as `mediump` 32-bit floats on ES targets, which
+    /// have no 16-bit float types without `GL_EXT_shader_explicit_arithmetic_types_float16`.
+    /// Modules with 16-bit floats in buffers or push constants are rejected, as demoting them
+    /// would change their layout, as are bitcasts of 16-bit floats. The module is parsed again
+    /// when the option changes, so changing it fails once the `Ast` has been modified or
+    /// compiled. See `get_float16_demotions`.
+    pub float16_to_mediump: bool,
     pub vertex: CompilerVertexOptions,
     pub fragment: CompilerFragmentOptions,
+    /// Whether to omit `layout(location = N)` from vertex shader inputs, for GL 3.3 drivers
+    /// without `ARB_explicit_attrib_location`. The locations are reported by
+    /// `get_vertex_attribute_locations` instead, to be bound with `glBindAttribLocation`.
+    pub omit_vertex_attribute_locations: bool,
+    /// Whether to flatten every uniform buffer into a `vec4` array, as `flatten_buffer_block`
+    /// does for a single block.
+    pub flatten_uniform_buffers: bool,
+    /// Whether `compile` fails with an error naming the affected resources when the module uses
+    /// multisampled images that `version` can't declare, rather than emitting invalid GLSL.
+    /// See `get_unsupported_multisampled_images`.
+    pub reject_unsupported_multisampled_images: bool,
+    /// Preprocessor macros which override specialization constants, keyed by `constant_id`.
+    ///
+    /// Without Vulkan semantics every specialization constant is emitted as a
+    /// `SPIRV_CROSS_CONSTANT_ID_<constant_id>` macro with its default value. Each entry here
+    /// adds a header line forwarding the named macro to that constant when it is defined, so
+    /// the generated source can be specialized by the preprocessor (e.g. `#define USE_SHADOWS 1`).
+    /// Macros for `bool` constants must be integers, which select `true` unless they are 0.
+    /// The mapping is applied the first time the module is compiled, and can't be changed
+    /// afterwards.
+    pub specialization_constant_macros: BTreeMap<u32, String>,
+    /// Names for uniform, storage and push constant blocks whose type is unnamed, keyed by the
+    /// resource ID. Without one, such blocks get generated names like `_13`, which can't be
+    /// relied on when binding blocks by name. Blocks that already have a name are left as is.
+    pub anonymous_block_names: BTreeMap<u32, String>,
+    /// Macros emitted as `#define NAME` or `#define NAME VALUE` lines at the top of the
+    /// generated source, so shader variants can be selected by the preprocessor. The defines
+    /// are applied the first time the module is compiled, and can't be changed afterwards.
+    pub preprocessor_defines: Vec<(String, Option<String>)>,
     /// The name and execution model of the entry point to use. If no entry
     /// point is specified, then the first entry point found will be used.
     pub entry_point: Option<(String, spirv::ExecutionModel)>,
@@ -119,13 +290,251 @@ impl Default for CompilerOptions {
             emit_line_directives: false,
             enable_storage_image_qualifier_deduction: true,
             force_zero_initialized_variables: false,
+            relax_nan_checks: false,
+            ovr_multiview_view_count: 0,
+            force_flattened_io_blocks: false,
+            ext_framebuffer_fetch_color_locations: Default::default(),
+            ext_framebuffer_fetch_noncoherent: false,
+            float16_to_mediump: false,
             vertex: CompilerVertexOptions::default(),
             fragment: CompilerFragmentOptions::default(),
+            omit_vertex_attribute_locations: false,
+            flatten_uniform_buffers: false,
+            reject_unsupported_multisampled_images: false,
+            specialization_constant_macros: Default::default(),
+            anonymous_block_names: Default::default(),
+            preprocessor_defines: Vec::new(),
             entry_point: None,
         }
     }
 }
 
+/// The binding and offset of an atomic counter, emitted as `layout(binding = N, offset = M)`
+/// when targeting GLSL 4.20+ or ESSL 3.10+.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct AtomicCounterBinding {
+    pub id: u32,
+    pub binding: u32,
+    pub offset: u32,
+}
+
+/// An instruction whose 16-bit float result was demoted to a `mediump` 32-bit float, from
+/// `CompilerOptions::float16_to_mediump`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct DemotedOperation {
+    /// The result ID of the instruction.
+    pub id: u32,
+    /// The SPIR-V opcode of the instruction, such as 133 for `OpFMul`.
+    pub opcode: u32,
+}
+
+/// How an `#extension` directive asks for the extension.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ExtensionBehavior {
+    Require,
+    Enable,
+    Warn,
+    Disable,
+}
+
+/// An `#extension` directive in the compiled shader.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ExtensionDirective {
+    pub name: String,
+    pub behavior: ExtensionBehavior,
+    /// Whether the directive is inside a preprocessor conditional, such as the
+    /// `#ifdef GL_ARB_shading_language_420pack` guard of an optional extension or one of several
+    /// alternative extensions. Such extensions may be missing without the shader failing.
+    pub conditional: bool,
+}
+
+fn parse_extension_directives(source: &str) -> Vec<ExtensionDirective> {
+    let mut directives = Vec::new();
+    let mut conditional_depth = 0usize;
+    for line in source.lines() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("#if") | Some("#ifdef") | Some("#ifndef") => conditional_depth += 1,
+            Some("#endif") => conditional_depth = conditional_depth.saturating_sub(1),
+            Some("#extension") => {
+                let name = words.next();
+                let behavior = match (words.next(), words.next()) {
+                    (Some(":"), Some("require")) => ExtensionBehavior::Require,
+                    (Some(":"), Some("enable")) => ExtensionBehavior::Enable,
+                    (Some(":"), Some("warn")) => ExtensionBehavior::Warn,
+                    (Some(":"), Some("disable")) => ExtensionBehavior::Disable,
+                    _ => continue,
+                };
+                if let Some(name) = name {
+                    directives.push(ExtensionDirective {
+                        name: name.to_string(),
+                        behavior,
+                        conditional: conditional_depth > 0,
+                    });
+                }
+            }
+            _ => (),
+        }
+    }
+    directives
+}
+
+/// Rewrites the 16-bit floats of a module as 32-bit floats, decorating the instructions which
+/// produce them with `RelaxedPrecision`. Modules without 16-bit floats are returned unchanged.
+fn demote_float16(words: &[u32]) -> Result<(Vec<u32>, Vec<DemotedOperation>), ErrorCode> {
+    const HEADER_LEN: usize = 5;
+    const OP_CAPABILITY: u32 = 17;
+    const OP_TYPE_FLOAT: u32 = 22;
+    const OP_TYPE_VECTOR: u32 = 23;
+    const OP_TYPE_MATRIX: u32 = 24;
+    const OP_TYPE_ARRAY: u32 = 28;
+    const OP_TYPE_RUNTIME_ARRAY: u32 = 29;
+    const OP_TYPE_STRUCT: u32 = 30;
+    const OP_TYPE_POINTER: u32 = 32;
+    const OP_CONSTANT: u32 = 43;
+    const OP_SPEC_CONSTANT: u32 = 50;
+    const OP_FUNCTION: u32 = 54;
+    const OP_FUNCTION_END: u32 = 56;
+    const OP_VARIABLE: u32 = 59;
+    const OP_BITCAST: u32 = 124;
+    const OP_DECORATE: u32 = 71;
+    const CAPABILITY_FLOAT16: u32 = 9;
+    const DECORATION_RELAXED_PRECISION: u32 = 0;
+    // Input, Output, Workgroup, Private and Function, whose layout isn't visible to the API.
+    const DEMOTABLE_STORAGE_CLASSES: [u32; 5] = [1, 3, 4, 6, 7];
+    // The debug and annotation instructions which precede the types of a module.
+    const PREAMBLE_OPCODES: [u32; 24] = [
+        2, 3, 4, 5, 6, 7, 8, 10, 11, 14, 15, 16, 17, 71, 72, 73, 74, 75, 317, 330, 331, 332, 5632,
+        5633,
+    ];
+
+    let mut instructions = Vec::new();
+    let mut offset = HEADER_LEN;
+    while offset < words.len() {
+        let word_count = (words[offset] >> 16) as usize;
+        if word_count == 0 || offset + word_count > words.len() {
+            return Err(ErrorCode::CompilationError(String::from(
+                "SPIR-V module is truncated",
+            )));
+        }
+        instructions.push(&words[offset..offset + word_count]);
+        offset += word_count;
+    }
+
+    let mut half_scalars = HashSet::new();
+    let mut half_types = HashSet::new();
+    for instruction in &instructions {
+        let operands = &instruction[1..];
+        let contains_half = match instruction[0] & 0xffff {
+            OP_TYPE_FLOAT if operands.get(1) == Some(&16) => {
+                half_scalars.insert(operands[0]);
+                true
+            }
+            OP_TYPE_VECTOR | OP_TYPE_MATRIX | OP_TYPE_ARRAY | OP_TYPE_RUNTIME_ARRAY => {
+                operands.len() > 1 && half_types.contains(&operands[1])
+            }
+            OP_TYPE_STRUCT => operands[1..]
+                .iter()
+                .any(|member| half_types.contains(member)),
+            OP_TYPE_POINTER if operands.len() > 2 && half_types.contains(&operands[2]) => {
+                if !DEMOTABLE_STORAGE_CLASSES.contains(&operands[1]) {
+                    return Err(ErrorCode::CompilationError(String::from(
+                        "16-bit floats in buffers can't be demoted to mediump",
+                    )));
+                }
+                true
+            }
+            _ => false,
+        };
+        if contains_half {
+            half_types.insert(operands[0]);
+        }
+    }
+    if half_scalars.is_empty() {
+        return Ok((words.to_vec(), Vec::new()));
+    }
+
+    let mut demoted_words = words[..HEADER_LEN].to_vec();
+    let mut demotions = Vec::new();
+    let mut half_values = HashSet::new();
+    let mut annotations_end = None;
+    let mut in_function = false;
+    for instruction in instructions {
+        let opcode = instruction[0] & 0xffff;
+        if opcode == OP_CAPABILITY && instruction.get(1) == Some(&CAPABILITY_FLOAT16) {
+            continue;
+        }
+        if annotations_end.is_none() && !PREAMBLE_OPCODES.contains(&opcode) {
+            annotations_end = Some(demoted_words.len());
+        }
+
+        let start = demoted_words.len() + 1;
+        demoted_words.extend_from_slice(instruction);
+        let operands = &mut demoted_words[start..];
+        match opcode {
+            OP_TYPE_FLOAT if half_scalars.contains(&operands[0]) => operands[1] = 32,
+            OP_CONSTANT | OP_SPEC_CONSTANT
+                if operands.len() > 2 && half_scalars.contains(&operands[0]) =>
+            {
+                operands[2] = f16_bits_to_f32_bits(operands[2] as u16);
+                half_values.insert(operands[1]);
+            }
+            // The bits of a demoted value no longer match its 16-bit type.
+            OP_BITCAST
+                if operands.len() > 2
+                    && (half_types.contains(&operands[0])
+                        || half_values.contains(&operands[2])) =>
+            {
+                return Err(ErrorCode::CompilationError(String::from(
+                    "bitcasts of 16-bit floats can't be demoted to mediump",
+                )));
+            }
+            OP_FUNCTION => in_function = true,
+            OP_FUNCTION_END => in_function = false,
+            // Result IDs follow their type, which is never the ID of another instruction.
+            _ if (in_function || opcode == OP_VARIABLE)
+                && operands.len() > 1
+                && half_types.contains(&operands[0]) =>
+            {
+                half_values.insert(operands[1]);
+                demotions.push(DemotedOperation {
+                    id: operands[1],
+                    opcode,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let decorations = demotions.iter().flat_map(|demotion| {
+        vec![
+            3 << 16 | OP_DECORATE,
+            demotion.id,
+            DECORATION_RELAXED_PRECISION,
+        ]
+    });
+    let annotations_end = annotations_end.unwrap_or(demoted_words.len());
+    demoted_words.splice(annotations_end..annotations_end, decorations);
+
+    Ok((demoted_words, demotions))
+}
+
+fn f16_bits_to_f32_bits(half: u16) -> u32 {
+    let sign = u32::from(half & 0x8000) << 16;
+    let exponent = u32::from(half >> 10 & 0x1f);
+    let mantissa = u32::from(half & 0x3ff);
+    match exponent {
+        0 if mantissa == 0 => sign,
+        // Subnormal halves are normal floats.
+        0 => {
+            let shift = mantissa.leading_zeros() - 21;
+            sign | (113 - shift) << 23 | (mantissa << shift & 0x3ff) << 13
+        }
+        0x1f => sign | 0x7f80_0000 | mantissa << 13,
+        _ => sign | (exponent + 112) << 23 | mantissa << 13,
+    }
+}
+
 impl spirv::Parse<Target> for spirv::Ast<Target> {
     fn parse(module: &spirv::Module) -> Result<Self, ErrorCode> {
         let compiler = {
@@ -142,8 +551,30 @@ impl spirv::Parse<Target> for spirv::Ast<Target> {
                 sc_compiler: compiler,
                 target_data: TargetData {
                     combined_image_samplers_built: false,
+                    dummy_sampler_id: None,
+                    version: CompilerOptions::default().version,
+                    reject_unsupported_multisampled_images: false,
+                    vertex_attribute_locations: Vec::new(),
+                    base_instance_uniform_enabled: false,
+                    vulkan_semantics: false,
+                    extension_directives: Vec::new(),
+                    ext_framebuffer_fetch_color_locations: BTreeMap::new(),
+                    ext_framebuffer_fetch_noncoherent: false,
+                    ext_framebuffer_fetch_remapped: false,
+                    float16_to_mediump: false,
+                    float16_demotions: Vec::new(),
                 },
                 has_been_compiled: false,
+                has_compiler_options: false,
+                has_been_modified: false,
+                module_words: module.words.to_vec(),
+                name_cache: Default::default(),
+                clip_space_fixups: Default::default(),
+                ast_tag: Default::default(),
+                shader_clock_usage: compiler::find_shader_clock_usage(module.words),
+                preprocessor_defines: Vec::new(),
+                specialization_constant_macros: BTreeMap::new(),
+                header_macros_added: false,
             }
         };
 
@@ -159,6 +590,22 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
 
     /// Set GLSL compiler specific compilation settings.
     fn set_compiler_options(&mut self, options: &CompilerOptions) -> Result<(), ErrorCode> {
+        let (version, es) = options.version.as_raw();
+        // Applied first, as it may parse the module again.
+        self.set_float16_to_mediump(options.float16_to_mediump && es)?;
+
+        let target_data = &self.compiler.target_data;
+        if target_data.ext_framebuffer_fetch_remapped
+            && (target_data.ext_framebuffer_fetch_color_locations
+                != options.ext_framebuffer_fetch_color_locations
+                || target_data.ext_framebuffer_fetch_noncoherent
+                    != options.ext_framebuffer_fetch_noncoherent)
+        {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`ext_framebuffer_fetch_color_locations` can't change once the module has been compiled",
+            )));
+        }
+
         if let Some((name, model)) = &options.entry_point {
             let name_raw = CString::new(name.as_str()).map_err(|_| ErrorCode::Unhandled)?;
             let model = model.as_raw();
@@ -171,25 +618,13 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             }
         };
 
-        use self::Version::*;
-        let (version, es) = match options.version {
-            V1_10 => (1_10, false),
-            V1_20 => (1_20, false),
-            V1_30 => (1_30, false),
-            V1_40 => (1_40, false),
-            V1_50 => (1_50, false),
-            V3_30 => (3_30, false),
-            V4_00 => (4_00, false),
-            V4_10 => (4_10, false),
-            V4_20 => (4_20, false),
-            V4_30 => (4_30, false),
-            V4_40 => (4_40, false),
-            V4_50 => (4_50, false),
-            V4_60 => (4_60, false),
-            V1_00Es => (1_00, true),
-            V3_00Es => (3_00, true),
-            V3_10Es => (3_10, true),
-            V3_20Es => (3_20, true),
+        self.compiler.set_header_macros(
+            &options.preprocessor_defines,
+            &options.specialization_constant_macros,
+        )?;
+        self.compiler.clip_space_fixups = spirv::ClipSpaceFixups {
+            transform_clip_space: options.vertex.transform_clip_space,
+            invert_y: options.vertex.invert_y,
         };
         let raw_options = br::ScGlslCompilerOptions {
             vertex_invert_y: options.vertex.invert_y,
@@ -210,6 +645,9 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             enable_storage_image_qualifier_deduction: options
                 .enable_storage_image_qualifier_deduction,
             force_zero_initialized_variables: options.force_zero_initialized_variables,
+            relax_nan_checks: options.relax_nan_checks,
+            ovr_multiview_view_count: options.ovr_multiview_view_count,
+            force_flattened_io_blocks: options.force_flattened_io_blocks,
         };
         unsafe {
             check!(br::sc_internal_compiler_glsl_set_options(
@@ -218,20 +656,299 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             ));
         }
 
+        self.compiler
+            .target_data
+            .ext_framebuffer_fetch_color_locations =
+            options.ext_framebuffer_fetch_color_locations.clone();
+        self.compiler.target_data.ext_framebuffer_fetch_noncoherent =
+            options.ext_framebuffer_fetch_noncoherent;
+        self.compiler.target_data.version = options.version;
+        self.compiler.target_data.vulkan_semantics = options.vulkan_semantics;
+        self.compiler.target_data.base_instance_uniform_enabled =
+            options.vertex.support_nonzero_base_instance && !options.vulkan_semantics;
+        self.compiler
+            .target_data
+            .reject_unsupported_multisampled_images =
+            options.reject_unsupported_multisampled_images;
+        self.name_anonymous_blocks(&options.anonymous_block_names)?;
+        self.omit_vertex_attribute_locations(options.omit_vertex_attribute_locations)?;
+        if options.flatten_uniform_buffers {
+            for uniform_buffer in self.get_shader_resources()?.uniform_buffers {
+                self.flatten_buffer_block(uniform_buffer.id)?;
+            }
+        }
+
         Ok(())
     }
 
     /// Generate GLSL shader from the AST.
     fn compile(&mut self) -> Result<String, ErrorCode> {
-        self.build_combined_image_samplers()?;
-        self.compiler.compile()
+        let mut shader = String::new();
+        self.compile_into(&mut shader)?;
+        Ok(shader)
+    }
+
+    fn compile_into(&mut self, shader: &mut String) -> Result<(), ErrorCode> {
+        if self
+            .compiler
+            .target_data
+            .reject_unsupported_multisampled_images
+        {
+            let unsupported = self.get_unsupported_multisampled_images()?;
+            if !unsupported.is_empty() {
+                let names = unsupported
+                    .iter()
+                    .map(|image| image.resource.name.as_str())
+                    .collect::<Vec<_>>();
+                return Err(ErrorCode::CompilationError(format!(
+                    "multisampled images are not supported by {:?}: {}",
+                    self.compiler.target_data.version,
+                    names.join(", ")
+                )));
+            }
+        }
+        // Vulkan GLSL can sample with separate images and samplers.
+        if !self.compiler.target_data.vulkan_semantics {
+            self.build_combined_image_samplers()?;
+        }
+        self.remap_ext_framebuffer_fetch()?;
+        self.compiler.compile_into(shader)?;
+        self.compiler.target_data.extension_directives = parse_extension_directives(shader);
+        Ok(())
     }
 }
 
 impl spirv::Ast<Target> {
+    fn set_float16_to_mediump(&mut self, enabled: bool) -> Result<(), ErrorCode> {
+        if enabled == self.compiler.target_data.float16_to_mediump {
+            return Ok(());
+        }
+
+        let (words, demotions) = if enabled {
+            demote_float16(&self.compiler.module_words)?
+        } else {
+            (self.compiler.module_words.clone(), Vec::new())
+        };
+        // Modules without 16-bit float operations are unchanged, so needn't be parsed again.
+        if !demotions.is_empty() || !self.compiler.target_data.float16_demotions.is_empty() {
+            if self.compiler.has_been_modified || self.compiler.has_been_compiled {
+                return Err(ErrorCode::CompilationError(String::from(
+                    "`float16_to_mediump` can't change once the `Ast` has been modified or compiled",
+                )));
+            }
+            let mut ast = Self::parse(&spirv::Module::from_words(&words))?;
+            std::mem::swap(
+                &mut ast.compiler.module_words,
+                &mut self.compiler.module_words,
+            );
+            std::mem::swap(&mut ast.compiler.ast_tag, &mut self.compiler.ast_tag);
+            *self = ast;
+        }
+        self.compiler.target_data.float16_to_mediump = enabled;
+        self.compiler.target_data.float16_demotions = demotions;
+
+        Ok(())
+    }
+
+    fn remap_ext_framebuffer_fetch(&mut self) -> Result<(), ErrorCode> {
+        let target_data = &self.compiler.target_data;
+        if target_data.ext_framebuffer_fetch_remapped {
+            return Ok(());
+        }
+
+        let coherent = !target_data.ext_framebuffer_fetch_noncoherent;
+        for (&input_attachment_index, &color_location) in
+            &target_data.ext_framebuffer_fetch_color_locations
+        {
+            unsafe {
+                check!(br::sc_internal_compiler_glsl_remap_ext_framebuffer_fetch(
+                    self.compiler.sc_compiler,
+                    input_attachment_index,
+                    color_location,
+                    coherent,
+                ));
+            }
+        }
+        self.compiler.target_data.ext_framebuffer_fetch_remapped = true;
+
+        Ok(())
+    }
+
+    fn omit_vertex_attribute_locations(&mut self, omit: bool) -> Result<(), ErrorCode> {
+        if !omit {
+            // Restore the locations omitted by an earlier call.
+            let attributes =
+                std::mem::take(&mut self.compiler.target_data.vertex_attribute_locations);
+            for (id, location) in attributes {
+                self.set_decoration(id, spirv::Decoration::Location, location)?;
+            }
+            return Ok(());
+        }
+        if self.compiler.get_execution_model()? != spirv::ExecutionModel::Vertex {
+            return Ok(());
+        }
+
+        // Attributes omitted by an earlier call no longer have a location and are kept as
+        // recorded.
+        for input in self.get_shader_resources()?.stage_inputs {
+            if !self.has_decoration(input.id, spirv::Decoration::Location)? {
+                continue;
+            }
+            let location = self.get_decoration(input.id, spirv::Decoration::Location)?;
+            self.unset_decoration(input.id, spirv::Decoration::Location)?;
+            self.compiler
+                .target_data
+                .vertex_attribute_locations
+                .push((input.id, location));
+        }
+
+        Ok(())
+    }
+
+    fn name_anonymous_blocks(&mut self, names: &BTreeMap<u32, String>) -> Result<(), ErrorCode> {
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        let resources = self.get_shader_resources()?;
+        for (&id, name) in names {
+            let block = resources
+                .uniform_buffers
+                .iter()
+                .chain(&resources.storage_buffers)
+                .chain(&resources.push_constant_buffers)
+                .find(|resource| resource.id == id)
+                .ok_or_else(|| {
+                    ErrorCode::CompilationError(format!("{} is not the ID of a block", id))
+                })?;
+            if self.get_name(block.base_type_id)?.is_empty() {
+                self.set_name(block.base_type_id, name)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the AST to both ES and desktop GLSL, reusing the parsed module.
+    ///
+    /// Returns `(es_source, desktop_source)`. Both option sets must select the same entry point
+    /// and preprocessor macros, and `es_options` must target an ES version while
+    /// `desktop_options` must not. The options which change the module itself, and so would
+    /// carry over from the ES variant to the desktop one, must match too: `float16_to_mediump`,
+    /// `ext_framebuffer_fetch_color_locations`, `ext_framebuffer_fetch_noncoherent`,
+    /// `flatten_uniform_buffers`, `anonymous_block_names` and `vulkan_semantics`. Fails if the
+    /// variants' stage inputs and outputs differ in their locations or types. The AST is left
+    /// configured with `desktop_options`.
+    pub fn compile_dual(
+        &mut self,
+        es_options: &CompilerOptions,
+        desktop_options: &CompilerOptions,
+    ) -> Result<(String, String), ErrorCode> {
+        if !es_options.version.is_es() || desktop_options.version.is_es() {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile_dual` requires an ES version and a desktop version",
+            )));
+        }
+        if es_options.entry_point != desktop_options.entry_point {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile_dual` requires both variants to use the same entry point",
+            )));
+        }
+        if es_options.preprocessor_defines != desktop_options.preprocessor_defines
+            || es_options.specialization_constant_macros
+                != desktop_options.specialization_constant_macros
+        {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile_dual` requires both variants to use the same preprocessor macros",
+            )));
+        }
+        if es_options.float16_to_mediump != desktop_options.float16_to_mediump
+            || es_options.ext_framebuffer_fetch_color_locations
+                != desktop_options.ext_framebuffer_fetch_color_locations
+            || es_options.ext_framebuffer_fetch_noncoherent
+                != desktop_options.ext_framebuffer_fetch_noncoherent
+            || es_options.flatten_uniform_buffers != desktop_options.flatten_uniform_buffers
+            || es_options.anonymous_block_names != desktop_options.anonymous_block_names
+            || es_options.vulkan_semantics != desktop_options.vulkan_semantics
+        {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile_dual` requires both variants to use the same options for changes to the module",
+            )));
+        }
+
+        self.set_compiler_options(es_options)?;
+        let es_source = self.compile()?;
+        let es_interface = self.get_stage_interface()?;
+        self.set_compiler_options(desktop_options)?;
+        let desktop_source = self.compile()?;
+        if self.get_stage_interface()? != es_interface {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile_dual` produced variants with different stage inputs or outputs",
+            )));
+        }
+
+        Ok((es_source, desktop_source))
+    }
+
+    /// Gets the ID, type and location of each stage input and output, to compare the interfaces
+    /// of compiled variants.
+    fn get_stage_interface(&self) -> Result<Vec<(u32, u32, Option<u32>)>, ErrorCode> {
+        let resources = self.get_shader_resources()?;
+        let mut interface = Vec::new();
+        for resource in resources
+            .stage_inputs
+            .iter()
+            .chain(&resources.stage_outputs)
+        {
+            let location = if self.has_decoration(resource.id, spirv::Decoration::Location)? {
+                Some(self.get_decoration(resource.id, spirv::Decoration::Location)?)
+            } else {
+                None
+            };
+            interface.push((resource.id, resource.type_id, location));
+        }
+        Ok(interface)
+    }
+
+    /// Creates a sampler for images which are only read with `texelFetch` (`OpImageFetch`) and
+    /// so have no sampler of their own, returning its identifier, or `None` if there are no such
+    /// images. Legacy GLSL still needs such an image combined with a sampler, so this is done by
+    /// `build_combined_image_samplers`, and only needs to be called to set the sampler's name or
+    /// decorations.
+    pub fn build_dummy_sampler_for_combined_images(&mut self) -> Result<Option<u32>, ErrorCode> {
+        self.compiler.has_been_modified = true;
+        if let Some(sampler_id) = self.compiler.target_data.dummy_sampler_id {
+            return Ok(sampler_id);
+        }
+
+        let sampler_id = unsafe {
+            let mut sampler_id = 0;
+            check!(
+                br::sc_internal_compiler_glsl_build_dummy_sampler_for_combined_images(
+                    self.compiler.sc_compiler,
+                    &mut sampler_id,
+                )
+            );
+            sampler_id
+        };
+        let sampler_id = if sampler_id == 0 {
+            None
+        } else {
+            Some(sampler_id)
+        };
+        self.compiler.target_data.dummy_sampler_id = Some(sampler_id);
+        Ok(sampler_id)
+    }
+
+    /// Creates a combined image sampler for each pair of separate image and sampler used
+    /// together, as legacy GLSL can't sample with separate images and samplers. This is done
+    /// by `compile` when not targeting Vulkan, so only needs to be called to reflect or rename
+    /// the combined image samplers beforehand.
     pub fn build_combined_image_samplers(&mut self) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
         unsafe {
             if !self.compiler.target_data.combined_image_samplers_built {
+                self.build_dummy_sampler_for_combined_images()?;
                 check!(br::sc_internal_compiler_glsl_build_combined_image_samplers(
                     self.compiler.sc_compiler
                 ));
@@ -242,6 +959,8 @@ impl spirv::Ast<Target> {
         Ok(())
     }
 
+    /// Gets the combined image samplers, building them first if needed. Their names can be set
+    /// with `set_name` on `combined_id` before compiling.
     pub fn get_combined_image_samplers(
         &mut self,
     ) -> Result<Vec<spirv::CombinedImageSampler>, ErrorCode> {
@@ -269,24 +988,210 @@ impl spirv::Ast<Target> {
         }
     }
 
+    /// Names every combined image sampler with `name(image_name, sampler_name)`, so the names
+    /// in the compiled shader don't depend on the IDs SPIRV-Cross assigns.
+    pub fn name_combined_image_samplers<F>(&mut self, mut name: F) -> Result<(), ErrorCode>
+    where
+        F: FnMut(&str, &str) -> String,
+    {
+        self.compiler.has_been_modified = true;
+        for sampler in self.get_combined_image_samplers()? {
+            let image_name = self.compiler.get_name(sampler.image_id)?;
+            let sampler_name = self.compiler.get_name(sampler.sampler_id)?;
+            let combined_name = name(&image_name, &sampler_name);
+            self.set_name(sampler.combined_id, &combined_name)?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the type names of variables in the compiled shader, as with SPIRV-Cross's
+    /// variable type remap callback. `remap` is called with the name and type of each stage
+    /// input and output, image and sampler, and returns the type name to emit in its place, or
+    /// `None` to keep the type, i.e. to declare a camera texture as `samplerExternalOES` with
+    /// `GL_OES_EGL_image_external` from `require_extension`.
+    ///
+    /// Combined image samplers built from separate images and samplers aren't passed to `remap`.
+    /// Each call replaces the remaps of previous calls.
+    pub fn set_variable_type_remap<F>(&mut self, mut remap: F) -> Result<(), ErrorCode>
+    where
+        F: FnMut(&str, &spirv::Type) -> Option<String>,
+    {
+        self.compiler.has_been_modified = true;
+        let resources = self.get_shader_resources()?;
+        let mut variable_names = Vec::new();
+        let mut type_names = Vec::new();
+        for resource in resources
+            .stage_inputs
+            .iter()
+            .chain(&resources.stage_outputs)
+            .chain(&resources.sampled_images)
+            .chain(&resources.storage_images)
+            .chain(&resources.separate_images)
+            .chain(&resources.separate_samplers)
+        {
+            let ty = self.get_type(resource.type_id)?;
+            if let Some(type_name) = remap(&resource.name, &ty) {
+                variable_names
+                    .push(CString::new(resource.name.as_str()).map_err(|_| ErrorCode::Unhandled)?);
+                type_names.push(CString::new(type_name).map_err(|_| ErrorCode::Unhandled)?);
+            }
+        }
+
+        let variable_name_ptrs = variable_names
+            .iter()
+            .map(|name| name.as_ptr())
+            .collect::<Vec<_>>();
+        let type_name_ptrs = type_names
+            .iter()
+            .map(|name| name.as_ptr())
+            .collect::<Vec<_>>();
+        unsafe {
+            check!(br::sc_internal_compiler_glsl_set_variable_type_remaps(
+                self.compiler.sc_compiler,
+                variable_name_ptrs.as_ptr(),
+                type_name_ptrs.as_ptr(),
+                variable_name_ptrs.len(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Adds a line, such as a `#define`, after the `#version` and `#extension` directives of
+    /// the compiled shader.
     pub fn add_header_line(&mut self, line: &str) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.add_header_line(line)
+    }
+
+    /// Gets the multisampled images that can't be declared in the GLSL version set by
+    /// `set_compiler_options`: all of them before GLSL 1.30 and ESSL 3.10, and storage images
+    /// on ES.
+    pub fn get_unsupported_multisampled_images(
+        &self,
+    ) -> Result<Vec<spirv::ImageResource>, ErrorCode> {
+        let version = self.compiler.target_data.version;
+        Ok(self
+            .get_multisampled_images()?
+            .into_iter()
+            .filter(|image| !version.supports_multisampled_images(image.image_type.storage))
+            .collect())
+    }
+
+    /// Gets the locations of the vertex attributes whose `layout(location = N)` was omitted by
+    /// `omit_vertex_attribute_locations`, keyed by their name in the compiled shader. `compile`
+    /// must be called first.
+    pub fn get_vertex_attribute_locations(&self) -> Result<BTreeMap<String, u32>, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        self.compiler
+            .target_data
+            .vertex_attribute_locations
+            .iter()
+            .map(|&(id, location)| Ok((self.compiler.get_name(id)?, location)))
+            .collect()
+    }
+
+    /// Adds an `#extension NAME : require` directive to the compiled shader, unless it is
+    /// already required.
+    pub fn require_extension(&mut self, extension: &str) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        let extension = CString::new(extension).map_err(|_| ErrorCode::Unhandled)?;
         unsafe {
-            let line = CString::new(line);
-            match line {
-                Ok(line) => {
-                    check!(br::sc_internal_compiler_glsl_add_header_line(
-                        self.compiler.sc_compiler,
-                        line.as_ptr(),
-                    ));
-                }
-                _ => return Err(ErrorCode::Unhandled),
-            }
+            check!(br::sc_internal_compiler_glsl_require_extension(
+                self.compiler.sc_compiler,
+                extension.as_ptr(),
+            ));
+        }
+        Ok(())
+    }
 
-            Ok(())
+    /// Gets the `#extension` directives of the compiled shader, so the application can check
+    /// for the extensions before creating the shader, or explain why it failed to compile.
+    /// SPIRV-Cross doesn't record what made it require an extension, so the directives don't
+    /// say which instruction or resource of the shader needs them. `compile` must be called
+    /// first.
+    pub fn get_extension_directives(&self) -> Result<Vec<ExtensionDirective>, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        Ok(self.compiler.target_data.extension_directives.clone())
+    }
+
+    /// Gets the instructions demoted to `mediump` by `CompilerOptions::float16_to_mediump`, so the
+    /// loss of the 16-bit performance intent can be reviewed. Empty unless the option is set
+    /// for an ES target.
+    pub fn get_float16_demotions(&self) -> Result<Vec<DemotedOperation>, ErrorCode> {
+        Ok(self.compiler.target_data.float16_demotions.clone())
+    }
+
+    /// Gets the name of the `uniform int` that the compiled shader reads the base instance from
+    /// when `ARB_shader_draw_parameters` is unavailable, or `None` if it doesn't declare one.
+    /// The application sets the uniform to the base instance of each draw. `compile` must be
+    /// called first.
+    pub fn get_base_instance_uniform_name(&self) -> Result<Option<String>, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
         }
+        if !self.compiler.target_data.base_instance_uniform_enabled {
+            return Ok(None);
+        }
+
+        let mut is_used = false;
+        unsafe {
+            check!(br::sc_internal_compiler_glsl_is_base_instance_used(
+                self.compiler.sc_compiler,
+                &mut is_used,
+            ));
+        }
+        Ok(if is_used {
+            Some(String::from("SPIRV_Cross_BaseInstance"))
+        } else {
+            None
+        })
+    }
+
+    /// Gets the binding and byte offset of each atomic counter, so counter buffers can be
+    /// allocated and bound by the application.
+    pub fn get_atomic_counter_bindings(&self) -> Result<Vec<AtomicCounterBinding>, ErrorCode> {
+        self.get_shader_resources()?
+            .atomic_counters
+            .iter()
+            .map(|counter| {
+                Ok(AtomicCounterBinding {
+                    id: counter.id,
+                    binding: self.get_decoration(counter.id, spirv::Decoration::Binding)?,
+                    offset: self.get_decoration(counter.id, spirv::Decoration::Offset)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Assigns the binding and byte offset emitted for the atomic counter `id`.
+    pub fn set_atomic_counter_binding(
+        &mut self,
+        id: u32,
+        binding: u32,
+        offset: u32,
+    ) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.set_decoration(id, spirv::Decoration::Binding, binding)?;
+        self.set_decoration(id, spirv::Decoration::Offset, offset)
     }
 
+    /// Flattens the uniform buffer `id` into a `uniform vec4 name[N];` array, where `N` is the
+    /// declared size of the block in bytes divided by 16 and rounded up. The block must be
+    /// emitted as plain uniforms, i.e. with `emit_uniform_buffer_as_plain_uniforms` set.
     pub fn flatten_buffer_block(&mut self, id: u32) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
         unsafe {
             check!(br::sc_internal_compiler_glsl_flatten_buffer_block(
                 self.compiler.sc_compiler,
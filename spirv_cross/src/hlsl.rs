@@ -1,5 +1,6 @@
 use crate::bindings as br;
 use crate::{compiler, spirv, ErrorCode};
+use std::collections::BTreeMap;
 use std::ffi::CString;
 use std::marker::PhantomData;
 use std::ptr;
@@ -10,8 +11,72 @@ pub use crate::bindings::root::ScHlslRootConstant as RootConstant;
 #[derive(Debug, Clone)]
 pub enum Target {}
 
+/// The state an HLSL `Ast` keeps alongside the SPIRV-Cross compiler: the resource binding
+/// overrides, root constant layouts and options which decide the registers of resources.
+pub struct TargetData {
+    resource_binding_overrides: BTreeMap<ResourceBindingLocation, ResourceBinding>,
+    root_constant_layouts: Vec<RootConstant>,
+    shader_model: ShaderModel,
+    force_storage_buffer_as_uav: bool,
+    nonwritable_uav_texture_as_srv: bool,
+}
+
 impl spirv::Target for Target {
-    type Data = ();
+    type Data = TargetData;
+}
+
+/// Location of a resource binding to override
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ResourceBindingLocation {
+    pub stage: spirv::ExecutionModel,
+    pub desc_set: u32,
+    pub binding: u32,
+}
+
+/// A register index and space, i.e. `register(t1, space2)`.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct Register {
+    pub register: u32,
+    pub space: u32,
+}
+
+/// Registers for overriding a resource binding. Only the register class used by the resource is
+/// consulted, i.e. `srv` for a sampled texture, and both `srv` and `sampler` for a combined
+/// image sampler.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct ResourceBinding {
+    /// The `b` register of a constant buffer.
+    pub cbv: Register,
+    /// The `u` register of a read-write resource.
+    pub uav: Register,
+    /// The `t` register of a read-only resource.
+    pub srv: Register,
+    /// The `s` register of a sampler.
+    pub sampler: Register,
+}
+
+/// The class of a HLSL register, i.e. the `t` in `register(t1, space2)`.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum RegisterType {
+    /// A `b` register, used by constant buffers.
+    ConstantBuffer,
+    /// A `t` register, used by read-only resources.
+    ShaderResource,
+    /// A `u` register, used by read-write resources.
+    UnorderedAccess,
+    /// A `s` register, used by samplers.
+    Sampler,
+}
+
+/// The register a resource is declared with in the compiled shader.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct ResourceRegister {
+    /// The ID of the resource variable.
+    pub id: u32,
+    pub desc_set: u32,
+    pub binding: u32,
+    pub register_type: RegisterType,
+    pub register: Register,
 }
 
 /// A HLSL shader model version.
@@ -28,6 +93,12 @@ pub enum ShaderModel {
     V5_0,
     V5_1,
     V6_0,
+    V6_1,
+    V6_2,
+    V6_3,
+    V6_4,
+    V6_5,
+    V6_6,
 }
 
 #[allow(non_snake_case, non_camel_case_types)]
@@ -44,13 +115,56 @@ impl ShaderModel {
             V5_0 => 50,
             V5_1 => 51,
             V6_0 => 60,
+            V6_1 => 61,
+            V6_2 => 62,
+            V6_3 => 63,
+            V6_4 => 64,
+            V6_5 => 65,
+            V6_6 => 66,
         }
     }
+
+    /// A name for the shader model which, unlike its raw value, tells the `V4_0L9_*` feature
+    /// levels apart, as stored in packs.
+    pub(crate) fn as_str(self) -> &'static str {
+        use self::ShaderModel::*;
+        match self {
+            V3_0 => "30",
+            V4_0 => "40",
+            V4_0L9_0 => "40_level_9_0",
+            V4_0L9_1 => "40_level_9_1",
+            V4_0L9_3 => "40_level_9_3",
+            V4_1 => "41",
+            V5_0 => "50",
+            V5_1 => "51",
+            V6_0 => "60",
+            V6_1 => "61",
+            V6_2 => "62",
+            V6_3 => "63",
+            V6_4 => "64",
+            V6_5 => "65",
+            V6_6 => "66",
+        }
+    }
+
+    pub(crate) fn from_str(shader_model: &str) -> Option<Self> {
+        use self::ShaderModel::*;
+        [
+            V3_0, V4_0, V4_0L9_0, V4_0L9_1, V4_0L9_3, V4_1, V5_0, V5_1, V6_0, V6_1, V6_2, V6_3,
+            V6_4, V6_5, V6_6,
+        ]
+        .iter()
+        .copied()
+        .find(|model| model.as_str() == shader_model)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct CompilerVertexOptions {
+    /// Whether to invert the Y coordinate of the position output.
     pub invert_y: bool,
+    /// Whether to convert the depth of the position output from the `[-1, 1]` range used by
+    /// GL to the `[0, 1]` range used by the target.
     pub transform_clip_space: bool,
 }
 
@@ -73,10 +187,50 @@ pub struct CompilerOptions {
     /// Support point coordinate builtin but ignore the value.
     pub point_coord_compat: bool,
     pub vertex: CompilerVertexOptions,
+    /// Whether to declare read-only storage buffers as UAVs (`RWByteAddressBuffer`) instead
+    /// of SRVs (`ByteAddressBuffer`).
     pub force_storage_buffer_as_uav: bool,
+    /// Whether to declare storage images decorated `NonWritable` as SRVs (`Texture*`) instead
+    /// of UAVs (`RWTexture*`).
     pub nonwritable_uav_texture_as_srv: bool,
     /// Whether to force all uninitialized variables to be initialized to zero.
     pub force_zero_initialized_variables: bool,
+    /// Whether to emit `NMin`, `NMax`, `NClamp` and ordered or unordered comparisons as plain
+    /// operations, without the checks needed for exact NaN semantics, for speed.
+    pub relax_nan_checks: bool,
+    /// Whether to emit `#line` directives from the `OpLine` debug info of the module, so driver
+    /// compilation errors point at the original source lines.
+    pub emit_line_directives: bool,
+    /// Whether to deduce readonly/writeonly qualifiers of storage images from their usage,
+    /// rather than only from their decorations.
+    pub enable_storage_image_qualifier_deduction: bool,
+    /// Whether to add `SPIRV_Cross_BaseVertex` and `SPIRV_Cross_BaseInstance` to a
+    /// `SPIRV_Cross_VertexInfo` cbuffer, so `gl_BaseVertex` and `gl_BaseInstance` are supported.
+    /// See `set_base_vertex_instance_binding` to choose its register.
+    pub support_nonzero_base_vertex_base_instance: bool,
+    /// Whether to emit native 16-bit types (`half`, `int16_t`) instead of min-precision types such
+    /// as `min16float`. Requires shader model 6.2 or newer.
+    pub enable_16bit_types: bool,
+    /// Whether to split matrix vertex inputs into one vector per column with consecutive semantics
+    /// (`TEXCOORD0`, `TEXCOORD1`, ...), so they can be described by a D3D input layout.
+    pub flatten_matrix_vertex_input_semantics: bool,
+    /// Whether to name the HLSL entry point after the SPIR-V entry point instead of `main`.
+    pub use_entry_point_name: bool,
+    /// Whether to declare storage buffers as `StructuredBuffer`/`RWStructuredBuffer` when the
+    /// module carries the HLSL user type decorations emitted by DXC, instead of
+    /// `ByteAddressBuffer`.
+    pub preserve_structured_buffers: bool,
+    /// Explicit registers for resources, keyed by stage, descriptor set and binding. Overrides
+    /// are added to those of earlier calls to `set_compiler_options`.
+    pub resource_binding_overrides: BTreeMap<ResourceBindingLocation, ResourceBinding>,
+    /// Byte ranges of the push constant block to emit as D3D12 root constants, each as its own
+    /// `cbuffer` with an explicit register and space. Left untouched when empty, so layouts
+    /// set with `set_root_constant_layout` are kept.
+    pub root_constant_layouts: Vec<RootConstant>,
+    /// Macros emitted as `#define NAME` or `#define NAME VALUE` lines at the top of the
+    /// generated source, so shader variants can be selected by the preprocessor. The defines
+    /// are applied the first time the module is compiled, and can't be changed afterwards.
+    pub preprocessor_defines: Vec<(String, Option<String>)>,
     /// The name and execution model of the entry point to use. If no entry
     /// point is specified, then the first entry point found will be used.
     pub entry_point: Option<(String, spirv::ExecutionModel)>,
@@ -92,6 +246,17 @@ impl Default for CompilerOptions {
             force_storage_buffer_as_uav: false,
             nonwritable_uav_texture_as_srv: false,
             force_zero_initialized_variables: false,
+            relax_nan_checks: false,
+            emit_line_directives: false,
+            enable_storage_image_qualifier_deduction: true,
+            support_nonzero_base_vertex_base_instance: false,
+            enable_16bit_types: false,
+            flatten_matrix_vertex_input_semantics: false,
+            use_entry_point_name: false,
+            preserve_structured_buffers: false,
+            resource_binding_overrides: Default::default(),
+            root_constant_layouts: Vec::new(),
+            preprocessor_defines: Vec::new(),
             entry_point: None,
         }
     }
@@ -111,8 +276,24 @@ impl spirv::Parse<Target> for spirv::Ast<Target> {
 
             compiler::Compiler {
                 sc_compiler: compiler,
-                target_data: (),
+                target_data: TargetData {
+                    resource_binding_overrides: BTreeMap::new(),
+                    root_constant_layouts: Vec::new(),
+                    shader_model: ShaderModel::V3_0,
+                    force_storage_buffer_as_uav: false,
+                    nonwritable_uav_texture_as_srv: false,
+                },
                 has_been_compiled: false,
+                has_compiler_options: false,
+                has_been_modified: false,
+                module_words: module.words.to_vec(),
+                name_cache: Default::default(),
+                clip_space_fixups: Default::default(),
+                ast_tag: Default::default(),
+                shader_clock_usage: compiler::find_shader_clock_usage(module.words),
+                preprocessor_defines: Vec::new(),
+                specialization_constant_macros: BTreeMap::new(),
+                header_macros_added: false,
             }
         };
 
@@ -139,6 +320,12 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
                 ));
             }
         };
+        self.compiler
+            .set_header_macros(&options.preprocessor_defines, &BTreeMap::new())?;
+        self.compiler.clip_space_fixups = spirv::ClipSpaceFixups {
+            transform_clip_space: options.vertex.transform_clip_space,
+            invert_y: options.vertex.invert_y,
+        };
         let raw_options = br::ScHlslCompilerOptions {
             shader_model: options.shader_model.as_raw(),
             point_size_compat: options.point_size_compat,
@@ -148,6 +335,16 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             force_storage_buffer_as_uav: options.force_storage_buffer_as_uav,
             nonwritable_uav_texture_as_srv: options.nonwritable_uav_texture_as_srv,
             force_zero_initialized_variables: options.force_zero_initialized_variables,
+            relax_nan_checks: options.relax_nan_checks,
+            emit_line_directives: options.emit_line_directives,
+            enable_storage_image_qualifier_deduction: options
+                .enable_storage_image_qualifier_deduction,
+            support_nonzero_base_vertex_base_instance: options
+                .support_nonzero_base_vertex_base_instance,
+            enable_16bit_types: options.enable_16bit_types,
+            flatten_matrix_vertex_input_semantics: options.flatten_matrix_vertex_input_semantics,
+            use_entry_point_name: options.use_entry_point_name,
+            preserve_structured_buffers: options.preserve_structured_buffers,
         };
         unsafe {
             check!(br::sc_internal_compiler_hlsl_set_options(
@@ -156,17 +353,67 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             ));
         }
 
+        let target_data = &mut self.compiler.target_data;
+        target_data.shader_model = options.shader_model;
+        target_data.force_storage_buffer_as_uav = options.force_storage_buffer_as_uav;
+        target_data.nonwritable_uav_texture_as_srv = options.nonwritable_uav_texture_as_srv;
+        target_data.resource_binding_overrides.extend(
+            options
+                .resource_binding_overrides
+                .iter()
+                .map(|(loc, res)| (loc.clone(), *res)),
+        );
+        let resource_bindings = options
+            .resource_binding_overrides
+            .iter()
+            .map(|(loc, res)| br::ScHlslResourceBinding {
+                stage: loc.stage.as_raw(),
+                desc_set: loc.desc_set,
+                binding: loc.binding,
+                cbv_register: res.cbv.register,
+                cbv_space: res.cbv.space,
+                uav_register: res.uav.register,
+                uav_space: res.uav.space,
+                srv_register: res.srv.register,
+                srv_space: res.srv.space,
+                sampler_register: res.sampler.register,
+                sampler_space: res.sampler.space,
+            })
+            .collect::<Vec<_>>();
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_add_resource_bindings(
+                self.compiler.sc_compiler,
+                resource_bindings.as_ptr(),
+                resource_bindings.len(),
+            ));
+        }
+
+        if !options.root_constant_layouts.is_empty() {
+            self.set_root_constant_layout(options.root_constant_layouts.clone())?;
+        }
+
         Ok(())
     }
 
     /// Generate HLSL shader from the AST.
     fn compile(&mut self) -> Result<String, ErrorCode> {
-        self.compiler.compile()
+        let mut shader = String::new();
+        self.compile_into(&mut shader)?;
+        Ok(shader)
+    }
+
+    fn compile_into(&mut self, shader: &mut String) -> Result<(), ErrorCode> {
+        if self.compiler.shader_clock_usage != Default::default() {
+            return Err(ErrorCode::CompilationError(String::from(
+                "OpReadClockKHR is not supported in HLSL",
+            )));
+        }
+        self.compiler.compile_into(shader)
     }
 }
 
 impl spirv::Ast<Target> {
-    ///
+    /// Sets the byte ranges of the push constant block to emit as D3D12 root constants.
     pub fn set_root_constant_layout(&mut self, layout: Vec<RootConstant>) -> Result<(), ErrorCode> {
         unsafe {
             check!(br::sc_internal_compiler_hlsl_set_root_constant_layout(
@@ -176,6 +423,279 @@ impl spirv::Ast<Target> {
             ));
         }
 
+        self.compiler.target_data.root_constant_layouts = layout;
+        Ok(())
+    }
+
+    /// Maps the vertex input at `location` to a custom HLSL semantic such as `POSITION` or `COLOR0`
+    /// instead of the default `TEXCOORD#`.
+    pub fn add_vertex_attribute_remap(
+        &mut self,
+        location: u32,
+        semantic: &str,
+    ) -> Result<(), ErrorCode> {
+        let semantic = CString::new(semantic).map_err(|_| ErrorCode::Unhandled)?;
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_add_vertex_attribute_remap(
+                self.compiler.sc_compiler,
+                location,
+                semantic.as_ptr(),
+            ));
+        }
+
         Ok(())
     }
+
+    /// Sets the register and space of the `SPIRV_Cross_VertexInfo` cbuffer emitted when
+    /// `support_nonzero_base_vertex_base_instance` is enabled. Without a binding the cbuffer is
+    /// declared without a register.
+    pub fn set_base_vertex_instance_binding(
+        &mut self,
+        register_index: u32,
+        register_space: u32,
+    ) -> Result<(), ErrorCode> {
+        unsafe {
+            check!(
+                br::sc_internal_compiler_hlsl_set_base_vertex_instance_binding(
+                    self.compiler.sc_compiler,
+                    register_index,
+                    register_space,
+                )
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Replaces reads of the `NumWorkgroups` builtin, which HLSL lacks, with a constant buffer
+    /// holding a `uint3 count`. Returns the ID of the new buffer variable, or `None` if the
+    /// shader doesn't use the builtin.
+    ///
+    /// Assign the buffer a register by setting its `DescriptorSet` and `Binding` decorations
+    /// before compiling; the application fills it with the dispatch size.
+    pub fn remap_num_workgroups_builtin(&mut self) -> Result<Option<u32>, ErrorCode> {
+        let mut id = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_remap_num_workgroups_builtin(
+                self.compiler.sc_compiler,
+                &mut id,
+            ));
+        }
+        Ok(if id == 0 { None } else { Some(id) })
+    }
+
+    /// Returns whether the buffer variable `id` is the atomic counter of an HLSL append,
+    /// consume or counted structured buffer, as recorded by the `HlslCounterBufferGOOGLE`
+    /// decoration.
+    pub fn buffer_is_hlsl_counter_buffer(&self, id: u32) -> Result<bool, ErrorCode> {
+        let mut is_counter_buffer = false;
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_buffer_is_counter_buffer(
+                self.compiler.sc_compiler,
+                id,
+                &mut is_counter_buffer,
+            ));
+        }
+        Ok(is_counter_buffer)
+    }
+
+    /// Returns the ID of the atomic counter buffer paired with the buffer variable `id`, or
+    /// `None` if it has no counter.
+    pub fn buffer_get_hlsl_counter_buffer(&self, id: u32) -> Result<Option<u32>, ErrorCode> {
+        let mut has_counter_buffer = false;
+        let mut counter_id = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_buffer_get_counter_buffer(
+                self.compiler.sc_compiler,
+                id,
+                &mut has_counter_buffer,
+                &mut counter_id,
+            ));
+        }
+        Ok(if has_counter_buffer {
+            Some(counter_id)
+        } else {
+            None
+        })
+    }
+
+    /// Returns whether the resource binding at `location` is used by the compiled shader.
+    /// Only locations present in `resource_binding_overrides` are tracked. `compile` must be
+    /// called first.
+    pub fn is_resource_binding_used(
+        &self,
+        location: &ResourceBindingLocation,
+    ) -> Result<bool, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        unsafe {
+            let mut is_used = false;
+            check!(br::sc_internal_compiler_hlsl_is_resource_binding_used(
+                self.compiler.sc_compiler,
+                location.stage.as_raw(),
+                location.desc_set,
+                location.binding,
+                &mut is_used
+            ));
+            Ok(is_used)
+        }
+    }
+
+    /// Returns the registers that resources are declared with in the compiled shader, so that
+    /// root signatures can be generated for them.
+    ///
+    /// The registers follow the `DescriptorSet` and `Binding` decorations of resources, or
+    /// their `resource_binding_overrides`, with the register class chosen by the options as
+    /// SPIRV-Cross does. Resources without a `Binding` decoration are declared without a
+    /// register and omitted. Combined image samplers report a sampler register, and from shader
+    /// model 4.0 a texture register too. A push constant block reports a constant buffer for
+    /// each root constant layout, and 0 for its descriptor set and binding, which it doesn't
+    /// have. Spaces are reported as 0 before shader model 5.1. `compile` must be called first.
+    pub fn get_resource_registers(&self) -> Result<Vec<ResourceRegister>, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        let target_data = &self.compiler.target_data;
+        let stage = self.compiler.get_execution_model()?;
+        let resources = self.get_shader_resources()?;
+        let mut classes = Vec::new();
+        for resource in &resources.uniform_buffers {
+            classes.push((resource, RegisterType::ConstantBuffer));
+        }
+        for resource in &resources.storage_buffers {
+            let is_read_only = self.get_buffer_block_flags(resource.id)?.non_writable
+                && !target_data.force_storage_buffer_as_uav;
+            classes.push((resource, read_only_register_type(is_read_only)));
+        }
+        for resource in &resources.storage_images {
+            let is_read_only = target_data.nonwritable_uav_texture_as_srv
+                && self.has_decoration(resource.id, spirv::Decoration::NonWritable)?;
+            classes.push((resource, read_only_register_type(is_read_only)));
+        }
+        for resource in &resources.sampled_images {
+            if target_data.shader_model.as_raw() >= 40 {
+                classes.push((resource, RegisterType::ShaderResource));
+            }
+            classes.push((resource, RegisterType::Sampler));
+        }
+        for resource in resources
+            .separate_images
+            .iter()
+            .chain(&resources.subpass_inputs)
+            .chain(&resources.acceleration_structures)
+        {
+            classes.push((resource, RegisterType::ShaderResource));
+        }
+        for resource in &resources.separate_samplers {
+            classes.push((resource, RegisterType::Sampler));
+        }
+
+        let mut registers = Vec::new();
+        for (resource, register_type) in classes {
+            if !self.has_decoration(resource.id, spirv::Decoration::Binding)? {
+                continue;
+            }
+            let desc_set = self.get_decoration(resource.id, spirv::Decoration::DescriptorSet)?;
+            let binding = self.get_decoration(resource.id, spirv::Decoration::Binding)?;
+            registers.push(ResourceRegister {
+                id: resource.id,
+                desc_set,
+                binding,
+                register_type,
+                register: self.get_register(stage, register_type, desc_set, binding),
+            });
+        }
+        for resource in &resources.push_constant_buffers {
+            for layout in &target_data.root_constant_layouts {
+                registers.push(ResourceRegister {
+                    id: resource.id,
+                    desc_set: 0,
+                    binding: 0,
+                    register_type: RegisterType::ConstantBuffer,
+                    register: self.get_register(
+                        stage,
+                        RegisterType::ConstantBuffer,
+                        layout.space,
+                        layout.binding,
+                    ),
+                });
+            }
+        }
+        Ok(registers)
+    }
+
+    /// Gets the register of a resource at `desc_set` and `binding`, which may be overridden by
+    /// `resource_binding_overrides`.
+    fn get_register(
+        &self,
+        stage: spirv::ExecutionModel,
+        register_type: RegisterType,
+        desc_set: u32,
+        binding: u32,
+    ) -> Register {
+        let target_data = &self.compiler.target_data;
+        let location = ResourceBindingLocation {
+            stage,
+            desc_set,
+            binding,
+        };
+        let register = match target_data.resource_binding_overrides.get(&location) {
+            Some(overrides) => match register_type {
+                RegisterType::ConstantBuffer => overrides.cbv,
+                RegisterType::ShaderResource => overrides.srv,
+                RegisterType::UnorderedAccess => overrides.uav,
+                RegisterType::Sampler => overrides.sampler,
+            },
+            None => Register {
+                register: binding,
+                space: desc_set,
+            },
+        };
+        if target_data.shader_model.as_raw() >= 51 {
+            register
+        } else {
+            Register {
+                space: 0,
+                ..register
+            }
+        }
+    }
+
+    /// Returns whether the compiled shader reads the `SPIRV_Cross_VertexInfo` cbuffer, and so
+    /// whether the base vertex and base instance need to be uploaded. `compile` must be called
+    /// first.
+    pub fn is_base_vertex_instance_binding_used(&self) -> Result<bool, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        unsafe {
+            let mut is_used = false;
+            check!(
+                br::sc_internal_compiler_hlsl_is_base_vertex_instance_binding_used(
+                    self.compiler.sc_compiler,
+                    &mut is_used,
+                )
+            );
+            Ok(is_used)
+        }
+    }
+}
+
+/// The class of a buffer or storage image, which is declared as an SRV if it is read-only.
+fn read_only_register_type(is_read_only: bool) -> RegisterType {
+    if is_read_only {
+        RegisterType::ShaderResource
+    } else {
+        RegisterType::UnorderedAccess
+    }
 }
@@ -52,6 +52,25 @@ pub mod msl;
 
 pub mod spirv;
 
+#[cfg(any(
+    feature = "glsl",
+    all(any(feature = "hlsl", feature = "msl"), not(target_arch = "wasm32"))
+))]
+mod translate;
+#[cfg(any(
+    feature = "glsl",
+    all(any(feature = "hlsl", feature = "msl"), not(target_arch = "wasm32"))
+))]
+pub use crate::translate::{translate, DescriptorBinding, TargetIndex, TargetSpec, Translation};
+#[cfg(any(
+    feature = "glsl",
+    all(any(feature = "hlsl", feature = "msl"), not(target_arch = "wasm32"))
+))]
+pub mod pack;
+
+mod lint;
+pub use crate::lint::{lint, LintFinding, LintRule, LintRules};
+
 #[cfg(target_arch = "wasm32")]
 pub(crate) mod emscripten;
 pub(crate) mod ptr_util;
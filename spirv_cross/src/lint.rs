@@ -0,0 +1,179 @@
+use crate::spirv::{self, ExecutionModel, Type};
+use crate::ErrorCode;
+use std::collections::{HashMap, HashSet};
+
+/// The limits checked by `lint`. A rule is skipped when its limit is `None` or `false`.
+#[non_exhaustive]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct LintRules {
+    /// The maximum number of `vec4` varying slots passed between stages, i.e.
+    /// `GL_MAX_VARYING_VECTORS`. Matrices take a slot per column and arrays a slot per element.
+    pub max_varyings: Option<u32>,
+    /// The maximum size in bytes of a uniform buffer, i.e. `GL_MAX_UNIFORM_BLOCK_SIZE`.
+    pub max_uniform_buffer_size: Option<u32>,
+    /// Whether arrays of sampled images may only be indexed by constants, as in GLSL ES. Only
+    /// checked for GLSL ES sources, as other targets allow dynamic indices.
+    pub constant_sampler_array_indices: bool,
+}
+
+impl Default for LintRules {
+    fn default() -> Self {
+        LintRules {
+            max_varyings: Some(16),
+            max_uniform_buffer_size: Some(65536),
+            constant_sampler_array_indices: true,
+        }
+    }
+}
+
+/// The rule broken by a `LintFinding`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum LintRule {
+    TooManyVaryings,
+    UniformBufferTooLarge,
+    DynamicSamplerArrayIndex,
+}
+
+/// A rule broken by a compiled shader, from `lint`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct LintFinding {
+    pub rule: LintRule,
+    /// The ID of the offending resource, or `None` for findings about the whole shader.
+    pub id: Option<u32>,
+    pub message: String,
+}
+
+/// Checks the `source` compiled from `ast` against `rules`, so limits of the target API can
+/// be caught before the source reaches a driver. The rules are checked with reflection of
+/// `ast`, while `source` decides which rules apply to its language. Findings are ordered by
+/// rule.
+pub fn lint<TTarget>(
+    ast: &spirv::Ast<TTarget>,
+    source: &str,
+    rules: &LintRules,
+) -> Result<Vec<LintFinding>, ErrorCode>
+where
+    TTarget: spirv::Target,
+    spirv::Ast<TTarget>: spirv::Parse<TTarget> + spirv::Compile<TTarget>,
+{
+    let resources = ast.get_shader_resources()?;
+    let mut findings = Vec::new();
+
+    if let Some(max_varyings) = rules.max_varyings {
+        let varyings: &[spirv::Resource] = match ast.compiler.get_execution_model()? {
+            ExecutionModel::Fragment => &resources.stage_inputs,
+            ExecutionModel::Vertex
+            | ExecutionModel::TessellationControl
+            | ExecutionModel::TessellationEvaluation
+            | ExecutionModel::Geometry => &resources.stage_outputs,
+            _ => &[],
+        };
+        let mut slots = 0;
+        for varying in varyings {
+            slots += ast.compiler.get_location_slots(varying.type_id, false)?;
+        }
+        if slots > max_varyings {
+            findings.push(LintFinding {
+                rule: LintRule::TooManyVaryings,
+                id: None,
+                message: format!(
+                    "{} varying slots are used, but at most {} are allowed",
+                    slots, max_varyings
+                ),
+            });
+        }
+    }
+
+    if let Some(max_size) = rules.max_uniform_buffer_size {
+        for buffer in &resources.uniform_buffers {
+            let size = ast.get_declared_struct_size(buffer.base_type_id)?;
+            if size > max_size {
+                findings.push(LintFinding {
+                    rule: LintRule::UniformBufferTooLarge,
+                    id: Some(buffer.id),
+                    message: format!(
+                        "uniform buffer `{}` is {} bytes, but at most {} are allowed",
+                        buffer.name, size, max_size
+                    ),
+                });
+            }
+        }
+    }
+
+    if rules.constant_sampler_array_indices && is_glsl_es(source) {
+        let dynamically_indexed = get_dynamically_indexed_variables(&ast.compiler.module_words);
+        for image in &resources.sampled_images {
+            let is_array = match ast.get_type(image.type_id)? {
+                Type::SampledImage { array } => !array.is_empty(),
+                _ => false,
+            };
+            if is_array && dynamically_indexed.contains(&image.id) {
+                findings.push(LintFinding {
+                    rule: LintRule::DynamicSamplerArrayIndex,
+                    id: Some(image.id),
+                    message: format!(
+                        "sampler array `{}` is indexed by a non-constant expression",
+                        image.name
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+/// Returns whether `source` is GLSL ES, from its `#version` directive. ESSL 1.00 has no `es`
+/// profile.
+fn is_glsl_es(source: &str) -> bool {
+    let version = source
+        .lines()
+        .find_map(|line| line.trim_start().strip_prefix("#version"));
+    match version.map(|version| version.split_whitespace().collect::<Vec<_>>()) {
+        Some(words) => matches!(words[..], ["100"] | [_, "es"]),
+        None => false,
+    }
+}
+
+/// Returns the variables indexed by a non-constant in an access chain, i.e. a sampler array
+/// indexed by a varying.
+fn get_dynamically_indexed_variables(words: &[u32]) -> HashSet<u32> {
+    const HEADER_LEN: usize = 5;
+    const OP_CONSTANT: u32 = 43;
+    const OP_CONSTANT_NULL: u32 = 46;
+    const OP_SPEC_CONSTANT: u32 = 50;
+    const OP_SPEC_CONSTANT_OP: u32 = 52;
+    const OP_ACCESS_CHAIN: u32 = 65;
+    const OP_IN_BOUNDS_ACCESS_CHAIN: u32 = 66;
+
+    let mut constants = HashSet::new();
+    // The variable each access chain result points into, so chains of chains are followed.
+    let mut chain_bases = HashMap::new();
+    let mut variables = HashSet::new();
+    let mut offset = HEADER_LEN;
+    while offset < words.len() {
+        let word_count = (words[offset] >> 16) as usize;
+        let opcode = words[offset] & 0xffff;
+        if word_count == 0 || offset + word_count > words.len() {
+            break;
+        }
+        let operands = &words[offset + 1..offset + word_count];
+        match opcode {
+            OP_CONSTANT | OP_CONSTANT_NULL | OP_SPEC_CONSTANT | OP_SPEC_CONSTANT_OP
+                if operands.len() > 1 =>
+            {
+                constants.insert(operands[1]);
+            }
+            OP_ACCESS_CHAIN | OP_IN_BOUNDS_ACCESS_CHAIN if operands.len() > 2 => {
+                let variable = *chain_bases.get(&operands[2]).unwrap_or(&operands[2]);
+                chain_bases.insert(operands[1], variable);
+                if operands[3..].iter().any(|index| !constants.contains(index)) {
+                    variables.insert(variable);
+                }
+            }
+            _ => {}
+        }
+        offset += word_count;
+    }
+    variables
+}
@@ -1,8 +1,9 @@
 use crate::bindings as br;
+use crate::ptr_util::read_string_from_ptr;
 use crate::{compiler, spirv, ErrorCode};
 
-use std::collections::BTreeMap;
-use std::ffi::{CStr, CString};
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::CString;
 use std::marker::PhantomData;
 use std::ptr;
 use std::u8;
@@ -15,6 +16,8 @@ pub struct TargetData {
     vertex_attribute_overrides: Vec<br::spirv_cross::MSLShaderInput>,
     resource_binding_overrides: Vec<br::spirv_cross::MSLResourceBinding>,
     const_samplers: Vec<br::ScMslConstSamplerMapping>,
+    framebuffer_fetch_subpasses: bool,
+    input_attachment_color_indices: BTreeMap<u32, u32>,
 }
 
 impl spirv::Target for Target {
@@ -65,11 +68,69 @@ pub struct ResourceBindingLocation {
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct ResourceBinding {
     pub buffer_id: u32,
+    /// The texture index. Multi-planar images sampled through a Y'CbCr conversion sampler
+    /// occupy consecutive slots, with plane `n` bound at `texture_id + n`.
     pub texture_id: u32,
     pub sampler_id: u32,
     pub count: u32,
 }
 
+/// The indices a resource is declared with in the compiled shader, from `get_resource_indices`.
+/// Combined image samplers have both a texture and a sampler index.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct ResourceIndices {
+    /// The `[[buffer(n)]]` index of a buffer or acceleration structure.
+    pub buffer: Option<u32>,
+    /// The `[[texture(n)]]` index of an image.
+    pub texture: Option<u32>,
+    /// The `[[sampler(n)]]` index of a sampler.
+    pub sampler: Option<u32>,
+}
+
+/// Which buffer index options were consulted when compiling the shader.
+///
+/// A `false` field means the corresponding `CompilerOptions` index had no effect on the
+/// generated source for this module.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct CompilerOptionsUsage {
+    pub swizzle_buffer_index: bool,
+    pub buffer_size_buffer_index: bool,
+    pub output_buffer_index: bool,
+    pub patch_output_buffer_index: bool,
+}
+
+/// Metal features that a compiled shader relies on, for gating pipeline creation on device
+/// support. These are derived from the options, resources, execution model and declared
+/// capabilities of the shader; the argument buffer tier, texture types and minimum MSL version
+/// or GPU family are not reported.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct FeatureRequirements {
+    /// `enable_argument_buffers` is set and the shader has buffers, images or samplers, so they
+    /// are bound through argument buffers, which need MSL 2.0.
+    pub argument_buffers: bool,
+    /// The module has specialization constants, emitted as function constants, which need
+    /// MSL 1.2.
+    pub function_constants: bool,
+    /// The shader has subpass inputs read with framebuffer fetch, as on iOS or with
+    /// `use_framebuffer_fetch_subpasses`, which needs an Apple GPU family.
+    pub framebuffer_fetch: bool,
+    /// The shader is a tessellation stage, which needs MSL 1.2 and tessellation support.
+    pub tessellation: bool,
+    /// The module declares the `RayQueryKHR` capability, so it may use ray queries, which need
+    /// MSL 2.4 and ray tracing support.
+    pub ray_queries: bool,
+}
+
+/// A subpass input read with framebuffer fetch.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct FramebufferFetchInput {
+    pub resource: spirv::Resource,
+    /// The `input_attachment_index` declared by the shader.
+    pub input_attachment_index: u32,
+    /// The color attachment read, as `[[color(m)]]`.
+    pub color_index: u32,
+}
+
 /// Location of a sampler binding to override
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct SamplerLocation {
@@ -228,6 +289,36 @@ pub enum SamplerYCbCrRange {
     ItuNarrow = 1,
 }
 
+/// Samplers with identical states sharing MSL sampler indices, from `coalesce_samplers`.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq)]
+pub struct SamplerCoalescing {
+    /// The MSL sampler index of each sampler.
+    pub sampler_ids: BTreeMap<SamplerLocation, u32>,
+    /// The state of the sampler to bind at each MSL sampler index.
+    pub states: BTreeMap<u32, SamplerData>,
+}
+
+/// Gives samplers with identical states the same MSL sampler index, counting up from
+/// `first_sampler_id`, as Metal only allows 16 samplers per stage. The states are typically
+/// those of `const_samplers` which are instead created at runtime, with the result set as
+/// `sampler_coalescing`.
+pub fn coalesce_samplers(
+    samplers: &BTreeMap<SamplerLocation, SamplerData>,
+    first_sampler_id: u32,
+) -> SamplerCoalescing {
+    let mut coalescing = SamplerCoalescing::default();
+    let mut ids_by_state = HashMap::new();
+    for (location, state) in samplers {
+        let next_id = first_sampler_id + ids_by_state.len() as u32;
+        let id = *ids_by_state.entry(state).or_insert_with(|| {
+            coalescing.states.insert(next_id, state.clone());
+            next_id
+        });
+        coalescing.sampler_ids.insert(location.clone(), id);
+    }
+    coalescing
+}
+
 /// A MSL shader platform.
 #[repr(u8)]
 #[allow(non_snake_case, non_camel_case_types)]
@@ -248,10 +339,11 @@ pub enum Version {
     V2_1,
     V2_2,
     V2_3,
+    V2_4,
 }
 
 impl Version {
-    fn as_raw(self) -> u32 {
+    pub(crate) fn as_raw(self) -> u32 {
         use self::Version::*;
         match self {
             V1_0 => 10000,
@@ -261,13 +353,25 @@ impl Version {
             V2_1 => 20100,
             V2_2 => 20200,
             V2_3 => 20300,
+            V2_4 => 20400,
         }
     }
+
+    pub(crate) fn from_raw(raw: u32) -> Option<Self> {
+        use self::Version::*;
+        [V1_0, V1_1, V1_2, V2_0, V2_1, V2_2, V2_3, V2_4]
+            .iter()
+            .copied()
+            .find(|version| version.as_raw() == raw)
+    }
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct CompilerVertexOptions {
+    /// Whether to invert the Y coordinate of the position output.
     pub invert_y: bool,
+    /// Whether to convert the depth of the position output from the `[-1, 1]` range used by
+    /// GL to the `[0, 1]` range used by the target.
     pub transform_clip_space: bool,
 }
 
@@ -326,6 +430,51 @@ pub struct CompilerOptions {
     pub force_native_arrays: bool,
     /// Whether to force all uninitialized variables to be initialized to zero.
     pub force_zero_initialized_variables: bool,
+    /// Whether to emit `NMin`, `NMax`, `NClamp` and ordered or unordered comparisons as plain
+    /// operations, without the checks needed for exact NaN semantics, for speed.
+    pub relax_nan_checks: bool,
+    /// Whether to emit `#line` directives from the `OpLine` debug info of the module, so driver
+    /// compilation errors point at the original source lines.
+    pub emit_line_directives: bool,
+    /// Whether fragment shaders skip storage resource writes after a discard.
+    pub check_discarded_frag_stores: bool,
+    /// Whether to emit fences between writes and reads of read-write textures.
+    pub readwrite_texture_fences: bool,
+    /// Whether to deduce readonly/writeonly qualifiers of storage images from their usage,
+    /// rather than only from their decorations.
+    pub enable_storage_image_qualifier_deduction: bool,
+    /// The width of the 2D texture used to emulate texel buffers when native texture buffers
+    /// are unavailable.
+    pub texel_buffer_texture_width: u32,
+    /// Whether to pad argument buffers so that resources keep the same `[[id(n)]]` across pipeline
+    /// variants that use different subsets of a descriptor set. Requires `enable_argument_buffers`.
+    pub pad_argument_buffer_resources: bool,
+    /// Whether to take resource indices from the SPIR-V `Binding` decoration rather than assigning
+    /// them automatically. `resource_binding_overrides` still take precedence.
+    pub enable_decoration_binding: bool,
+    /// Whether subpass inputs are arrayed by layer, as required for multiview render passes.
+    pub arrayed_subpass_input: bool,
+    /// Whether to use SIMD-group functions on iOS, which requires Apple A11 or newer GPUs.
+    pub ios_use_simdgroup_functions: bool,
+    /// Whether to read subpass inputs with framebuffer fetch, as `[[color(m)]]` fragment inputs,
+    /// on macOS, which requires MSL 2.3. Framebuffer fetch is always used on iOS.
+    pub use_framebuffer_fetch_subpasses: bool,
+    /// Whether runtime-sized storage buffer arrays read their length from a rich descriptor, an
+    /// address and size pair bound in place of the buffer, rather than from the buffer size buffer
+    /// at `buffer_size_buffer_index`.
+    pub runtime_array_rich_descriptor: bool,
+    /// The color attachment `m` read by framebuffer fetch for subpass inputs, keyed by their
+    /// `input_attachment_index`. Without an entry, `m` is the `input_attachment_index` itself.
+    pub input_attachment_color_indices: BTreeMap<u32, u32>,
+    /// Samplers bound at shared MSL sampler indices, in every stage. This replaces the sampler
+    /// index of `resource_binding_overrides` at the same locations, and other overrides are
+    /// added for samplers without one. Combined image samplers still need an override to place
+    /// their texture.
+    pub sampler_coalescing: Option<SamplerCoalescing>,
+    /// Macros emitted as `#define NAME` or `#define NAME VALUE` lines at the top of the
+    /// generated source, so shader variants can be selected by the preprocessor. The defines
+    /// are applied the first time the module is compiled, and can't be changed afterwards.
+    pub preprocessor_defines: Vec<(String, Option<String>)>,
     /// The name and execution model of the entry point to use. If no entry
     /// point is specified, then the first entry point found will be used.
     pub entry_point: Option<(String, spirv::ExecutionModel)>,
@@ -355,6 +504,21 @@ impl Default for CompilerOptions {
             const_samplers: Default::default(),
             force_native_arrays: false,
             force_zero_initialized_variables: false,
+            relax_nan_checks: false,
+            emit_line_directives: false,
+            check_discarded_frag_stores: false,
+            readwrite_texture_fences: true,
+            enable_storage_image_qualifier_deduction: true,
+            texel_buffer_texture_width: 4096,
+            pad_argument_buffer_resources: false,
+            enable_decoration_binding: false,
+            arrayed_subpass_input: false,
+            ios_use_simdgroup_functions: false,
+            use_framebuffer_fetch_subpasses: false,
+            runtime_array_rich_descriptor: false,
+            input_attachment_color_indices: Default::default(),
+            sampler_coalescing: None,
+            preprocessor_defines: Vec::new(),
             entry_point: None,
         }
     }
@@ -378,8 +542,20 @@ impl<'a> spirv::Parse<Target> for spirv::Ast<Target> {
                     resource_binding_overrides: Vec::new(),
                     vertex_attribute_overrides: Vec::new(),
                     const_samplers: Vec::new(),
+                    framebuffer_fetch_subpasses: false,
+                    input_attachment_color_indices: BTreeMap::new(),
                 },
                 has_been_compiled: false,
+                has_compiler_options: false,
+                has_been_modified: false,
+                module_words: module.words.to_vec(),
+                name_cache: Default::default(),
+                clip_space_fixups: Default::default(),
+                ast_tag: Default::default(),
+                shader_clock_usage: compiler::find_shader_clock_usage(module.words),
+                preprocessor_defines: Vec::new(),
+                specialization_constant_macros: BTreeMap::new(),
+                header_macros_added: false,
             },
             target_type: PhantomData,
         })
@@ -402,6 +578,12 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
                 ));
             }
         };
+        self.compiler
+            .set_header_macros(&options.preprocessor_defines, &BTreeMap::new())?;
+        self.compiler.clip_space_fixups = spirv::ClipSpaceFixups {
+            transform_clip_space: options.vertex.transform_clip_space,
+            invert_y: options.vertex.invert_y,
+        };
         let raw_options = br::ScMslCompilerOptions {
             vertex_invert_y: options.vertex.invert_y,
             vertex_transform_clip_space: options.vertex.transform_clip_space,
@@ -422,6 +604,19 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             pad_fragment_output_components: options.pad_fragment_output_components,
             force_native_arrays: options.force_native_arrays,
             force_zero_initialized_variables: options.force_zero_initialized_variables,
+            relax_nan_checks: options.relax_nan_checks,
+            emit_line_directives: options.emit_line_directives,
+            check_discarded_frag_stores: options.check_discarded_frag_stores,
+            readwrite_texture_fences: options.readwrite_texture_fences,
+            enable_storage_image_qualifier_deduction: options
+                .enable_storage_image_qualifier_deduction,
+            texel_buffer_texture_width: options.texel_buffer_texture_width,
+            pad_argument_buffer_resources: options.pad_argument_buffer_resources,
+            enable_decoration_binding: options.enable_decoration_binding,
+            arrayed_subpass_input: options.arrayed_subpass_input,
+            ios_use_simdgroup_functions: options.ios_use_simdgroup_functions,
+            use_framebuffer_fetch_subpasses: options.use_framebuffer_fetch_subpasses,
+            runtime_array_rich_descriptor: options.runtime_array_rich_descriptor,
         };
         unsafe {
             check!(br::sc_internal_compiler_msl_set_options(
@@ -445,6 +640,43 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             }),
         );
 
+        if let Some(coalescing) = &options.sampler_coalescing {
+            let overrides = &mut self.compiler.target_data.resource_binding_overrides;
+            for (location, &sampler_id) in &coalescing.sampler_ids {
+                for &stage in &[
+                    spirv::ExecutionModel::Vertex,
+                    spirv::ExecutionModel::TessellationControl,
+                    spirv::ExecutionModel::TessellationEvaluation,
+                    spirv::ExecutionModel::Fragment,
+                    spirv::ExecutionModel::GlCompute,
+                    spirv::ExecutionModel::Kernel,
+                ] {
+                    let stage = stage.as_raw();
+                    match overrides.iter_mut().find(|binding| {
+                        binding.stage == stage
+                            && binding.desc_set == location.desc_set
+                            && binding.binding == location.binding
+                    }) {
+                        Some(binding) => binding.msl_sampler = sampler_id,
+                        None => overrides.push(br::spirv_cross::MSLResourceBinding {
+                            stage,
+                            desc_set: location.desc_set,
+                            binding: location.binding,
+                            msl_buffer: 0,
+                            msl_texture: 0,
+                            msl_sampler: sampler_id,
+                            count: 0,
+                        }),
+                    }
+                }
+            }
+        }
+
+        self.compiler.target_data.framebuffer_fetch_subpasses =
+            options.platform == Platform::iOS || options.use_framebuffer_fetch_subpasses;
+        self.compiler.target_data.input_attachment_color_indices =
+            options.input_attachment_color_indices.clone();
+
         self.compiler.target_data.vertex_attribute_overrides.clear();
         self.compiler.target_data.vertex_attribute_overrides.extend(
             options.vertex_attribute_overrides.iter().map(|(loc, vat)| {
@@ -502,20 +734,39 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
 
     /// Generate MSL shader from the AST.
     fn compile(&mut self) -> Result<String, ErrorCode> {
-        self.compile_internal()
+        let mut shader = String::new();
+        self.compile_into(&mut shader)?;
+        Ok(shader)
+    }
+
+    fn compile_into(&mut self, shader: &mut String) -> Result<(), ErrorCode> {
+        if self.compiler.shader_clock_usage != Default::default() {
+            return Err(ErrorCode::CompilationError(String::from(
+                "OpReadClockKHR is not supported in MSL",
+            )));
+        }
+        let declared_indices = self.remap_input_attachments()?;
+        let result = self.compile_internal(shader);
+        for (id, index) in declared_indices {
+            self.compiler
+                .set_decoration(id, spirv::Decoration::InputAttachmentIndex, index)?;
+        }
+        result
     }
 }
 
 impl spirv::Ast<Target> {
-    fn compile_internal(&self) -> Result<String, ErrorCode> {
+    fn compile_internal(&self, shader: &mut String) -> Result<(), ErrorCode> {
         let vat_overrides = &self.compiler.target_data.vertex_attribute_overrides;
         let res_overrides = &self.compiler.target_data.resource_binding_overrides;
         let const_samplers = &self.compiler.target_data.const_samplers;
         unsafe {
-            let mut shader_ptr = ptr::null();
+            let mut compiled = ptr::null_mut();
+            let mut length = 0;
             check!(br::sc_internal_compiler_msl_compile(
                 self.compiler.sc_compiler,
-                &mut shader_ptr,
+                &mut compiled,
+                &mut length,
                 vat_overrides.as_ptr(),
                 vat_overrides.len(),
                 res_overrides.as_ptr(),
@@ -523,15 +774,40 @@ impl spirv::Ast<Target> {
                 const_samplers.as_ptr(),
                 const_samplers.len(),
             ));
-            let shader = match CStr::from_ptr(shader_ptr).to_str() {
-                Ok(v) => v.to_owned(),
-                Err(_) => return Err(ErrorCode::Unhandled),
-            };
-            check!(br::sc_internal_free_pointer(
-                shader_ptr as *mut std::os::raw::c_void
-            ));
-            Ok(shader)
+            compiler::read_compiled_shader(compiled, length, shader)
+        }
+    }
+
+    /// Decorates subpass inputs read with framebuffer fetch with the color attachment they read,
+    /// returning their declared `input_attachment_index` to restore after compiling, so
+    /// reflection keeps reporting what the shader declares.
+    fn remap_input_attachments(&mut self) -> Result<Vec<(u32, u32)>, ErrorCode> {
+        let mut declared_indices = Vec::new();
+        if !self.compiler.target_data.framebuffer_fetch_subpasses {
+            return Ok(declared_indices);
+        }
+        for input in self.get_shader_resources()?.subpass_inputs {
+            let index = self.get_decoration(input.id, spirv::Decoration::InputAttachmentIndex)?;
+            let color_index = self.get_input_attachment_color_index(index);
+            if color_index != index {
+                self.compiler.set_decoration(
+                    input.id,
+                    spirv::Decoration::InputAttachmentIndex,
+                    color_index,
+                )?;
+                declared_indices.push((input.id, index));
+            }
         }
+        Ok(declared_indices)
+    }
+
+    fn get_input_attachment_color_index(&self, input_attachment_index: u32) -> u32 {
+        self.compiler
+            .target_data
+            .input_attachment_color_indices
+            .get(&input_attachment_index)
+            .copied()
+            .unwrap_or(input_attachment_index)
     }
 
     pub fn is_rasterization_enabled(&self) -> Result<bool, ErrorCode> {
@@ -544,6 +820,248 @@ impl spirv::Ast<Target> {
             Ok(!is_disabled)
         }
     }
+
+    /// Sets the suffix appended to the names of samplers in combined image samplers.
+    pub fn set_combined_sampler_suffix(&mut self, suffix: &str) -> Result<(), ErrorCode> {
+        let suffix = CString::new(suffix).map_err(|_| ErrorCode::Unhandled)?;
+        unsafe {
+            check!(br::sc_internal_compiler_msl_set_combined_sampler_suffix(
+                self.compiler.sc_compiler,
+                suffix.as_ptr(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Gets the suffix appended to the names of samplers in combined image samplers.
+    pub fn get_combined_sampler_suffix(&self) -> Result<String, ErrorCode> {
+        unsafe {
+            let mut suffix_ptr = ptr::null();
+            check!(br::sc_internal_compiler_msl_get_combined_sampler_suffix(
+                self.compiler.sc_compiler,
+                &mut suffix_ptr,
+            ));
+            let suffix = read_string_from_ptr(suffix_ptr)?;
+            check!(br::sc_internal_free_pointer(
+                suffix_ptr as *mut std::os::raw::c_void
+            ));
+            Ok(suffix)
+        }
+    }
+
+    /// Returns whether the vertex attribute at `location` is used by the compiled shader.
+    /// Only locations present in `vertex_attribute_overrides` are tracked. `compile` must be
+    /// called first.
+    pub fn is_shader_input_used(
+        &self,
+        location: &VertexAttributeLocation,
+    ) -> Result<bool, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        unsafe {
+            let mut is_used = false;
+            check!(br::sc_internal_compiler_msl_is_shader_input_used(
+                self.compiler.sc_compiler,
+                location.0,
+                &mut is_used
+            ));
+            Ok(is_used)
+        }
+    }
+
+    /// Returns whether the resource binding at `location` is used by the compiled shader.
+    /// Only locations present in `resource_binding_overrides` are tracked. `compile` must be
+    /// called first.
+    pub fn is_resource_binding_used(
+        &self,
+        location: &ResourceBindingLocation,
+    ) -> Result<bool, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        unsafe {
+            let mut is_used = false;
+            check!(br::sc_internal_compiler_msl_is_resource_binding_used(
+                self.compiler.sc_compiler,
+                location.stage.as_raw(),
+                location.desc_set,
+                location.binding,
+                &mut is_used
+            ));
+            Ok(is_used)
+        }
+    }
+
+    /// Returns the storage buffers whose runtime-sized array length is read by the compiled
+    /// shader. Their sizes must be provided in the buffer size buffer, indexed by their MSL
+    /// buffer index, or by their rich descriptor with `runtime_array_rich_descriptor`. `compile`
+    /// must be called first.
+    pub fn get_buffers_requiring_array_length(&self) -> Result<Vec<spirv::Resource>, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        let resources = self.compiler.get_shader_resources()?;
+        let mut buffers = Vec::new();
+        for resource in resources.storage_buffers {
+            let mut requires_array_length = false;
+            unsafe {
+                check!(br::sc_internal_compiler_msl_buffer_requires_array_length(
+                    self.compiler.sc_compiler,
+                    resource.id,
+                    &mut requires_array_length
+                ));
+            }
+            if requires_array_length {
+                buffers.push(resource);
+            }
+        }
+        Ok(buffers)
+    }
+
+    /// Returns the indices `resource` is declared with in the compiled shader, whether they were
+    /// set by `resource_binding_overrides` or assigned automatically. Indices of resources the
+    /// shader doesn't use are `None`. `compile` must be called first.
+    pub fn get_resource_indices(
+        &self,
+        resource: &spirv::Resource,
+    ) -> Result<ResourceIndices, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        let (primary, secondary) = unsafe {
+            let mut primary = 0;
+            let mut secondary = 0;
+            check!(br::sc_internal_compiler_msl_get_resource_indices(
+                self.compiler.sc_compiler,
+                resource.id,
+                &mut primary,
+                &mut secondary,
+            ));
+            (primary, secondary)
+        };
+        // SPIRV-Cross reports indices that weren't assigned as `u32::MAX`.
+        let index = |index| Some(index).filter(|&index| index != u32::MAX);
+        let indices = match self.compiler.get_type(resource.base_type_id)? {
+            spirv::Type::SampledImage { .. } => ResourceIndices {
+                texture: index(primary),
+                sampler: index(secondary),
+                ..Default::default()
+            },
+            spirv::Type::Image { .. } => ResourceIndices {
+                texture: index(primary),
+                ..Default::default()
+            },
+            spirv::Type::Sampler { .. } => ResourceIndices {
+                sampler: index(primary),
+                ..Default::default()
+            },
+            _ => ResourceIndices {
+                buffer: index(primary),
+                ..Default::default()
+            },
+        };
+        Ok(indices)
+    }
+
+    /// Reports which buffer index options influenced the compiled shader. `compile` must be
+    /// called first.
+    pub fn get_options_usage(&self) -> Result<CompilerOptionsUsage, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        unsafe {
+            let mut usage = br::ScMslCompilerOptionsUsage {
+                swizzle_buffer_index: false,
+                buffer_size_buffer_index: false,
+                output_buffer_index: false,
+                patch_output_buffer_index: false,
+            };
+            check!(br::sc_internal_compiler_msl_get_options_usage(
+                self.compiler.sc_compiler,
+                &mut usage
+            ));
+            Ok(CompilerOptionsUsage {
+                swizzle_buffer_index: usage.swizzle_buffer_index,
+                buffer_size_buffer_index: usage.buffer_size_buffer_index,
+                output_buffer_index: usage.output_buffer_index,
+                patch_output_buffer_index: usage.patch_output_buffer_index,
+            })
+        }
+    }
+
+    /// Gets the subpass inputs the compiled shader reads with framebuffer fetch, and the color
+    /// attachment each reads. `compile` must be called first.
+    pub fn get_framebuffer_fetch_inputs(&self) -> Result<Vec<FramebufferFetchInput>, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+        if !self.compiler.target_data.framebuffer_fetch_subpasses {
+            return Ok(Vec::new());
+        }
+
+        let mut inputs = Vec::new();
+        for resource in self.get_shader_resources()?.subpass_inputs {
+            let input_attachment_index =
+                self.get_decoration(resource.id, spirv::Decoration::InputAttachmentIndex)?;
+            let color_index = self.get_input_attachment_color_index(input_attachment_index);
+            inputs.push(FramebufferFetchInput {
+                resource,
+                input_attachment_index,
+                color_index,
+            });
+        }
+        Ok(inputs)
+    }
+
+    /// Reports the Metal features the compiled shader relies on, as far as `FeatureRequirements`
+    /// derives them. `compile` must be called first.
+    pub fn get_feature_requirements(&self) -> Result<FeatureRequirements, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        unsafe {
+            let mut requirements = br::ScMslFeatureRequirements {
+                argument_buffers: false,
+                function_constants: false,
+                framebuffer_fetch: false,
+                tessellation: false,
+                ray_queries: false,
+            };
+            check!(br::sc_internal_compiler_msl_get_feature_requirements(
+                self.compiler.sc_compiler,
+                &mut requirements
+            ));
+            Ok(FeatureRequirements {
+                argument_buffers: requirements.argument_buffers,
+                function_constants: requirements.function_constants,
+                framebuffer_fetch: requirements.framebuffer_fetch,
+                tessellation: requirements.tessellation,
+                ray_queries: requirements.ray_queries,
+            })
+        }
+    }
 }
 
 // TODO: Generate with bindgen
@@ -0,0 +1,415 @@
+#[cfg(feature = "glsl")]
+use crate::glsl;
+#[cfg(all(feature = "hlsl", not(target_arch = "wasm32")))]
+use crate::hlsl;
+#[cfg(all(feature = "msl", not(target_arch = "wasm32")))]
+use crate::msl;
+use crate::spirv;
+use crate::translate::{DescriptorBinding, TargetIndex, TargetSpec, Translation};
+use crate::ErrorCode;
+use std::fmt::Write;
+
+/// The version written by `Pack::write`. `Pack::read` rejects packs from newer versions.
+pub const PACK_VERSION: u32 = 1;
+
+const MAGIC: &str = "SPIRV-CROSS-PACK";
+
+/// The language a `PackedShader` was translated to.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum TargetKind {
+    Glsl,
+    Hlsl,
+    Msl,
+}
+
+impl TargetKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            TargetKind::Glsl => "glsl",
+            TargetKind::Hlsl => "hlsl",
+            TargetKind::Msl => "msl",
+        }
+    }
+
+    fn from_str(kind: &str) -> Result<Self, ErrorCode> {
+        match kind {
+            "glsl" => Ok(TargetKind::Glsl),
+            "hlsl" => Ok(TargetKind::Hlsl),
+            "msl" => Ok(TargetKind::Msl),
+            _ => Err(malformed(&format!("unknown target `{}`", kind))),
+        }
+    }
+}
+
+/// A translated shader stored in a `Pack`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct PackedShader {
+    pub name: String,
+    /// The hash of the original SPIR-V, from `spirv_hash`.
+    pub spirv_hash: u64,
+    pub target: TargetKind,
+    /// The target version, as parsed by `target_spec`. GLSL versions are their `#version`
+    /// number and profile, such as `450` or `300 es`. HLSL shader models are their number,
+    /// such as `51`, with a suffix for feature levels, as in `40_level_9_3`. MSL versions are
+    /// the `major * 10000 + minor * 100` number SPIRV-Cross uses and the platform, such as
+    /// `20300 ios`.
+    pub target_version: String,
+    pub source: String,
+    /// A line-based summary of the entry points and resources of the shader.
+    pub report: String,
+    pub bindings: Vec<DescriptorBinding>,
+}
+
+impl PackedShader {
+    /// Packs a `translation` of `spirv_bytes`, as returned by `translate` for `target`.
+    pub fn new(
+        name: &str,
+        spirv_bytes: &[u8],
+        target: TargetSpec,
+        translation: &Translation,
+    ) -> PackedShader {
+        let (target, target_version) = match target {
+            #[cfg(feature = "glsl")]
+            TargetSpec::Glsl { version } => match version.as_raw() {
+                (version, false) => (TargetKind::Glsl, version.to_string()),
+                (version, true) => (TargetKind::Glsl, format!("{} es", version)),
+            },
+            #[cfg(all(feature = "hlsl", not(target_arch = "wasm32")))]
+            TargetSpec::Hlsl { shader_model } => {
+                (TargetKind::Hlsl, shader_model.as_str().to_owned())
+            }
+            #[cfg(all(feature = "msl", not(target_arch = "wasm32")))]
+            TargetSpec::Msl { version, platform } => {
+                let platform = match platform {
+                    msl::Platform::iOS => "ios",
+                    msl::Platform::macOS => "macos",
+                };
+                (
+                    TargetKind::Msl,
+                    format!("{} {}", version.as_raw(), platform),
+                )
+            }
+        };
+
+        PackedShader {
+            name: name.to_owned(),
+            spirv_hash: spirv_hash(spirv_bytes),
+            target,
+            target_version,
+            source: translation.source.clone(),
+            report: report(translation),
+            bindings: translation.bindings.clone(),
+        }
+    }
+
+    /// Parses `target` and `target_version` back into the `TargetSpec` the shader was
+    /// translated for, so it can be translated again once the SPIR-V changes. Fails if the
+    /// target's feature is disabled.
+    pub fn target_spec(&self) -> Result<TargetSpec, ErrorCode> {
+        let fields = self.target_version.split(' ').collect::<Vec<_>>();
+        match (self.target, &fields[..]) {
+            #[cfg(feature = "glsl")]
+            (TargetKind::Glsl, [version]) => Ok(TargetSpec::Glsl {
+                version: glsl::Version::from_raw(parse(version)?, false),
+            }),
+            #[cfg(feature = "glsl")]
+            (TargetKind::Glsl, [version, "es"]) => Ok(TargetSpec::Glsl {
+                version: glsl::Version::from_raw(parse(version)?, true),
+            }),
+            #[cfg(all(feature = "hlsl", not(target_arch = "wasm32")))]
+            (TargetKind::Hlsl, [shader_model]) => Ok(TargetSpec::Hlsl {
+                shader_model: hlsl::ShaderModel::from_str(shader_model).ok_or_else(|| {
+                    malformed(&format!("unknown shader model `{}`", shader_model))
+                })?,
+            }),
+            #[cfg(all(feature = "msl", not(target_arch = "wasm32")))]
+            (TargetKind::Msl, [version, platform]) => Ok(TargetSpec::Msl {
+                version: msl::Version::from_raw(parse(version)?)
+                    .ok_or_else(|| malformed(&format!("unknown MSL version `{}`", version)))?,
+                platform: match *platform {
+                    "ios" => msl::Platform::iOS,
+                    "macos" => msl::Platform::macOS,
+                    _ => return Err(malformed(&format!("unknown platform `{}`", platform))),
+                },
+            }),
+            _ => Err(ErrorCode::CompilationError(format!(
+                "unsupported target `{} {}`",
+                self.target.as_str(),
+                self.target_version
+            ))),
+        }
+    }
+}
+
+/// A collection of translated shaders with a versioned serialization. Each shader is a block
+/// of text lines, with its source and report stored as length-prefixed bytes.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Pack {
+    pub shaders: Vec<PackedShader>,
+}
+
+impl Pack {
+    pub fn new() -> Self {
+        Pack::default()
+    }
+
+    /// Serializes the pack.
+    pub fn write(&self) -> Vec<u8> {
+        let mut out = format!("{} {}\n", MAGIC, PACK_VERSION);
+        for shader in &self.shaders {
+            out.push_str("shader\n");
+            write_blob(&mut out, "name", &shader.name);
+            writeln!(out, "hash {:016x}", shader.spirv_hash).unwrap();
+            writeln!(
+                out,
+                "target {} {}",
+                shader.target.as_str(),
+                shader.target_version
+            )
+            .unwrap();
+            write_blob(&mut out, "source", &shader.source);
+            write_blob(&mut out, "report", &shader.report);
+            for binding in &shader.bindings {
+                writeln!(
+                    out,
+                    "binding {} {} {}",
+                    binding.desc_set, binding.binding, binding.id
+                )
+                .unwrap();
+                write_blob(&mut out, "name", &binding.name);
+                for index in &binding.target_indices {
+                    match index {
+                        TargetIndex::MslBuffer(index) => {
+                            writeln!(out, "index msl_buffer {}", index)
+                        }
+                        TargetIndex::MslTexture(index) => {
+                            writeln!(out, "index msl_texture {}", index)
+                        }
+                        TargetIndex::MslSampler(index) => {
+                            writeln!(out, "index msl_sampler {}", index)
+                        }
+                        TargetIndex::HlslRegister {
+                            class,
+                            register,
+                            space,
+                        } => writeln!(out, "index hlsl {} {} {}", class, register, space),
+                    }
+                    .unwrap();
+                }
+            }
+            out.push_str("end\n");
+        }
+        out.into_bytes()
+    }
+
+    /// Deserializes a pack written by `write`.
+    pub fn read(bytes: &[u8]) -> Result<Pack, ErrorCode> {
+        let mut reader = Reader { bytes, position: 0 };
+
+        let header = reader.line()?;
+        let version = match header.strip_prefix(MAGIC) {
+            Some(version) => parse::<u32>(version.trim())?,
+            None => return Err(malformed("missing header")),
+        };
+        if version > PACK_VERSION {
+            return Err(malformed(&format!(
+                "version {} is newer than {}",
+                version, PACK_VERSION
+            )));
+        }
+
+        let mut shaders = Vec::new();
+        while !reader.is_empty() {
+            if reader.line()? != "shader" {
+                return Err(malformed("expected `shader`"));
+            }
+            let name = reader.blob("name")?;
+            let spirv_hash = u64::from_str_radix(reader.field("hash")?, 16)
+                .map_err(|_| malformed("invalid hash"))?;
+            let mut target = reader.field("target")?.splitn(2, ' ');
+            let target_kind = TargetKind::from_str(target.next().unwrap_or(""))?;
+            let target_version = target.next().unwrap_or("").to_owned();
+            let source = reader.blob("source")?;
+            let report = reader.blob("report")?;
+
+            let mut bindings: Vec<DescriptorBinding> = Vec::new();
+            loop {
+                let line = reader.line()?;
+                if line == "end" {
+                    break;
+                }
+                // Each binding is followed by its name and target indices.
+                if let Some(index) = line.strip_prefix("index ") {
+                    let binding = bindings
+                        .last_mut()
+                        .ok_or_else(|| malformed("`index` before `binding`"))?;
+                    binding.target_indices.push(parse_target_index(index)?);
+                    continue;
+                }
+                let binding = match line.strip_prefix("binding ") {
+                    Some(binding) => binding,
+                    None => return Err(malformed("expected `binding` or `end`")),
+                };
+                let fields = binding.split(' ').collect::<Vec<_>>();
+                let (desc_set, binding, id) = match fields[..] {
+                    [desc_set, binding, id] => (parse(desc_set)?, parse(binding)?, parse(id)?),
+                    _ => return Err(malformed(&format!("invalid binding `{}`", binding))),
+                };
+                bindings.push(DescriptorBinding {
+                    desc_set,
+                    binding,
+                    id,
+                    name: reader.blob("name")?,
+                    target_indices: Vec::new(),
+                });
+            }
+
+            shaders.push(PackedShader {
+                name,
+                spirv_hash,
+                target: target_kind,
+                target_version,
+                source,
+                report,
+                bindings,
+            });
+        }
+
+        Ok(Pack { shaders })
+    }
+}
+
+/// Hashes SPIR-V bytes with 64-bit FNV-1a, to detect when a packed shader is out of date.
+pub fn spirv_hash(spirv_bytes: &[u8]) -> u64 {
+    spirv_bytes
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+fn report(translation: &Translation) -> String {
+    let mut report = String::new();
+    for entry_point in &translation.entry_points {
+        writeln!(
+            report,
+            "entry_point {} {:?}",
+            entry_point.name, entry_point.execution_model
+        )
+        .unwrap();
+    }
+    let resources = &translation.resources;
+    let kinds: [(&str, &[spirv::Resource]); 13] = [
+        ("uniform_buffer", &resources.uniform_buffers),
+        ("storage_buffer", &resources.storage_buffers),
+        ("stage_input", &resources.stage_inputs),
+        ("stage_output", &resources.stage_outputs),
+        ("subpass_input", &resources.subpass_inputs),
+        ("storage_image", &resources.storage_images),
+        ("sampled_image", &resources.sampled_images),
+        ("atomic_counter", &resources.atomic_counters),
+        ("push_constant_buffer", &resources.push_constant_buffers),
+        ("separate_image", &resources.separate_images),
+        ("separate_sampler", &resources.separate_samplers),
+        ("acceleration_structure", &resources.acceleration_structures),
+        ("shader_record_buffer", &resources.shader_record_buffers),
+    ];
+    for (kind, resources) in kinds.iter() {
+        for resource in resources.iter() {
+            writeln!(
+                report,
+                "{} {} id {} type {}",
+                kind, resource.name, resource.id, resource.type_id
+            )
+            .unwrap();
+        }
+    }
+    report
+}
+
+fn parse_target_index(index: &str) -> Result<TargetIndex, ErrorCode> {
+    let fields = index.split(' ').collect::<Vec<_>>();
+    match fields[..] {
+        ["msl_buffer", index] => Ok(TargetIndex::MslBuffer(parse(index)?)),
+        ["msl_texture", index] => Ok(TargetIndex::MslTexture(parse(index)?)),
+        ["msl_sampler", index] => Ok(TargetIndex::MslSampler(parse(index)?)),
+        ["hlsl", class, register, space] => match class {
+            "b" | "t" | "u" | "s" => Ok(TargetIndex::HlslRegister {
+                class: class.chars().next().unwrap(),
+                register: parse(register)?,
+                space: parse(space)?,
+            }),
+            _ => Err(malformed(&format!("unknown register class `{}`", class))),
+        },
+        _ => Err(malformed(&format!("invalid index `{}`", index))),
+    }
+}
+
+fn write_blob(out: &mut String, key: &str, value: &str) {
+    writeln!(out, "{} {}", key, value.len()).unwrap();
+    out.push_str(value);
+    out.push('\n');
+}
+
+fn malformed(message: &str) -> ErrorCode {
+    ErrorCode::CompilationError(format!("malformed pack: {}", message))
+}
+
+fn parse<T: std::str::FromStr>(value: &str) -> Result<T, ErrorCode> {
+    value
+        .parse()
+        .map_err(|_| malformed(&format!("invalid number `{}`", value)))
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a str, ErrorCode> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| malformed("unexpected end"))?;
+        let value = std::str::from_utf8(&self.bytes[self.position..end])
+            .map_err(|_| malformed("invalid UTF-8"))?;
+        self.position = end;
+        Ok(value)
+    }
+
+    fn line(&mut self) -> Result<&'a str, ErrorCode> {
+        let len = self.bytes[self.position..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .ok_or_else(|| malformed("unexpected end"))?;
+        let line = self.take(len)?;
+        self.position += 1;
+        Ok(line)
+    }
+
+    fn field(&mut self, key: &str) -> Result<&'a str, ErrorCode> {
+        let line = self.line()?;
+        match line
+            .strip_prefix(key)
+            .and_then(|rest| rest.strip_prefix(' '))
+        {
+            Some(value) => Ok(value),
+            None => Err(malformed(&format!("expected `{}`", key))),
+        }
+    }
+
+    fn blob(&mut self, key: &str) -> Result<String, ErrorCode> {
+        let len = parse(self.field(key)?)?;
+        let value = self.take(len)?.to_owned();
+        if !self.line()?.is_empty() {
+            return Err(malformed(&format!("`{}` is longer than its length", key)));
+        }
+        Ok(value)
+    }
+}
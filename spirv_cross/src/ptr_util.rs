@@ -26,6 +26,18 @@ pub unsafe fn read_string_from_ptr(ptr: *const std::os::raw::c_char) -> Result<S
     string
 }
 
+/// Reads a string like `read_string_from_ptr`, but into `string`, reusing its allocation.
+/// Native builds copy compiled shaders with `compiler::read_compiled_shader` instead.
+#[cfg(target_arch = "wasm32")]
+pub unsafe fn read_string_into_from_ptr(
+    ptr: *const std::os::raw::c_char,
+    string: &mut String,
+) -> Result<(), ErrorCode> {
+    string.clear();
+    string.push_str(&read_string_from_ptr(ptr)?);
+    Ok(())
+}
+
 pub unsafe fn read_from_ptr<T>(ptr: *const T) -> T {
     #[cfg(not(target_arch = "wasm32"))]
     let value = ptr.read();
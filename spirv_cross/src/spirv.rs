@@ -1,7 +1,10 @@
 use crate::{compiler, ErrorCode};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::marker::PhantomData;
+use std::rc::Rc;
 
-/// A stage or compute kernel.
+/// A `sampler2D`-style variable combining a separate image and sampler, created for targets
+/// that can't sample with separate images and samplers.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub struct CombinedImageSampler {
     pub combined_id: u32,
@@ -19,6 +22,8 @@ pub enum ExecutionModel {
     Fragment,
     GlCompute,
     Kernel,
+    Task,
+    Mesh,
 }
 
 /// A decoration.
@@ -50,6 +55,7 @@ pub enum Decoration {
     NonWritable,
     NonReadable,
     Uniform,
+    UniformId,
     SaturatedConversion,
     Stream,
     Location,
@@ -67,10 +73,39 @@ pub enum Decoration {
     NoContraction,
     InputAttachmentIndex,
     Alignment,
+    MaxByteOffset,
+    AlignmentId,
+    MaxByteOffsetId,
+    NoSignedWrap,
+    NoUnsignedWrap,
+    ExplicitInterpAmd,
     OverrideCoverageNv,
     PassthroughNv,
     ViewportRelativeNv,
     SecondaryViewportRelativeNv,
+    PerPrimitiveNv,
+    PerViewNv,
+    PerTaskNv,
+    PerVertexNv,
+    NonUniform,
+    RestrictPointer,
+    AliasedPointer,
+    ReferencedIndirectlyIntel,
+    CounterBuffer,
+    HlslSemanticGoogle,
+    UserTypeGoogle,
+    RegisterIntel,
+    MemoryIntel,
+    NumbanksIntel,
+    BankwidthIntel,
+    MaxPrivateCopiesIntel,
+    SinglepumpIntel,
+    DoublepumpIntel,
+    MaxReplicatesIntel,
+    SimpleDualPortIntel,
+    MergeIntel,
+    BankBitsIntel,
+    ForcePow2DepthIntel,
 }
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -172,9 +207,198 @@ pub enum BuiltIn {
     HitTNv,
     HitKindNv,
     IncomingRayFlagsNv,
+    PrimitivePointIndicesExt,
+    PrimitiveLineIndicesExt,
+    PrimitiveTriangleIndicesExt,
+    CullPrimitiveExt,
+}
+
+/// Every `BuiltIn`, in declaration order, so that a `BuiltInSet` can store one bit per variant.
+const BUILT_INS: [BuiltIn; 95] = [
+    BuiltIn::Position,
+    BuiltIn::PointSize,
+    BuiltIn::ClipDistance,
+    BuiltIn::CullDistance,
+    BuiltIn::VertexId,
+    BuiltIn::InstanceId,
+    BuiltIn::PrimitiveId,
+    BuiltIn::InvocationId,
+    BuiltIn::Layer,
+    BuiltIn::ViewportIndex,
+    BuiltIn::TessLevelOuter,
+    BuiltIn::TessLevelInner,
+    BuiltIn::TessCoord,
+    BuiltIn::PatchVertices,
+    BuiltIn::FragCoord,
+    BuiltIn::PointCoord,
+    BuiltIn::FrontFacing,
+    BuiltIn::SampleId,
+    BuiltIn::SamplePosition,
+    BuiltIn::SampleMask,
+    BuiltIn::FragDepth,
+    BuiltIn::HelperInvocation,
+    BuiltIn::NumWorkgroups,
+    BuiltIn::WorkgroupSize,
+    BuiltIn::WorkgroupId,
+    BuiltIn::LocalInvocationId,
+    BuiltIn::GlobalInvocationId,
+    BuiltIn::LocalInvocationIndex,
+    BuiltIn::WorkDim,
+    BuiltIn::GlobalSize,
+    BuiltIn::EnqueuedWorkgroupSize,
+    BuiltIn::GlobalOffset,
+    BuiltIn::GlobalLinearId,
+    BuiltIn::SubgroupSize,
+    BuiltIn::SubgroupMaxSize,
+    BuiltIn::NumSubgroups,
+    BuiltIn::NumEnqueuedSubgroups,
+    BuiltIn::SubgroupId,
+    BuiltIn::SubgroupLocalInvocationId,
+    BuiltIn::VertexIndex,
+    BuiltIn::InstanceIndex,
+    BuiltIn::SubgroupEqMask,
+    BuiltIn::SubgroupGeMask,
+    BuiltIn::SubgroupGtMask,
+    BuiltIn::SubgroupLeMask,
+    BuiltIn::SubgroupLtMask,
+    BuiltIn::BaseVertex,
+    BuiltIn::BaseInstance,
+    BuiltIn::DrawIndex,
+    BuiltIn::DeviceIndex,
+    BuiltIn::ViewIndex,
+    BuiltIn::BaryCoordNoPerspAmd,
+    BuiltIn::BaryCoordNoPerspCentroidAmd,
+    BuiltIn::BaryCoordNoPerspSampleAmd,
+    BuiltIn::BaryCoordSmoothAmd,
+    BuiltIn::BaryCoordSmoothCentroidAmd,
+    BuiltIn::BaryCoordSmoothSampleAmd,
+    BuiltIn::BaryCoordPullModelAmd,
+    BuiltIn::FragStencilRefExt,
+    BuiltIn::ViewportMaskNv,
+    BuiltIn::SecondaryPositionNv,
+    BuiltIn::SecondaryViewportMaskNv,
+    BuiltIn::PositionPerViewNv,
+    BuiltIn::ViewportMaskPerViewNv,
+    BuiltIn::FullyCoveredExt,
+    BuiltIn::TaskCountNv,
+    BuiltIn::PrimitiveCountNv,
+    BuiltIn::PrimitiveIndicesNv,
+    BuiltIn::ClipDistancePerViewNv,
+    BuiltIn::CullDistancePerViewNv,
+    BuiltIn::LayerPerViewNv,
+    BuiltIn::MeshViewCountNv,
+    BuiltIn::MeshViewIndicesNv,
+    BuiltIn::BaryCoordNv,
+    BuiltIn::BaryCoordNoPerspNv,
+    BuiltIn::FragSizeExt,
+    BuiltIn::FragInvocationCountExt,
+    BuiltIn::LaunchIdNv,
+    BuiltIn::LaunchSizeNv,
+    BuiltIn::WorldRayOriginNv,
+    BuiltIn::WorldRayDirectionNv,
+    BuiltIn::ObjectRayOriginNv,
+    BuiltIn::ObjectRayDirectionNv,
+    BuiltIn::RayTminNv,
+    BuiltIn::RayTmaxNv,
+    BuiltIn::InstanceCustomIndexNv,
+    BuiltIn::ObjectToWorldNv,
+    BuiltIn::WorldToObjectNv,
+    BuiltIn::HitTNv,
+    BuiltIn::HitKindNv,
+    BuiltIn::IncomingRayFlagsNv,
+    BuiltIn::PrimitivePointIndicesExt,
+    BuiltIn::PrimitiveLineIndicesExt,
+    BuiltIn::PrimitiveTriangleIndicesExt,
+    BuiltIn::CullPrimitiveExt,
+];
+
+/// A set of `BuiltIn`s, stored as a bitset.
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct BuiltInSet {
+    bits: u128,
+}
+
+impl BuiltInSet {
+    pub fn new() -> Self {
+        BuiltInSet::default()
+    }
+
+    pub fn contains(&self, built_in: BuiltIn) -> bool {
+        self.bits & BuiltInSet::bit(built_in) != 0
+    }
+
+    pub fn insert(&mut self, built_in: BuiltIn) {
+        self.bits |= BuiltInSet::bit(built_in);
+    }
+
+    pub fn remove(&mut self, built_in: BuiltIn) {
+        self.bits &= !BuiltInSet::bit(built_in);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Iterates over the builtins in the set, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = BuiltIn> + '_ {
+        BUILT_INS
+            .iter()
+            .copied()
+            .filter(move |&built_in| self.contains(built_in))
+    }
+
+    fn bit(built_in: BuiltIn) -> u128 {
+        1 << built_in as u32
+    }
+}
+
+impl std::fmt::Debug for BuiltInSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl std::iter::FromIterator<BuiltIn> for BuiltInSet {
+    fn from_iter<I: IntoIterator<Item = BuiltIn>>(iter: I) -> Self {
+        let mut set = BuiltInSet::new();
+        for built_in in iter {
+            set.insert(built_in);
+        }
+        set
+    }
+}
+
+impl std::ops::BitOr for BuiltInSet {
+    type Output = BuiltInSet;
+
+    fn bitor(self, other: BuiltInSet) -> BuiltInSet {
+        BuiltInSet {
+            bits: self.bits | other.bits,
+        }
+    }
+}
+
+impl std::ops::BitAnd for BuiltInSet {
+    type Output = BuiltInSet;
+
+    fn bitand(self, other: BuiltInSet) -> BuiltInSet {
+        BuiltInSet {
+            bits: self.bits & other.bits,
+        }
+    }
+}
+
+pub(crate) fn built_in_from_u32(raw: u32) -> Option<BuiltIn> {
+    BUILT_INS
+        .iter()
+        .copied()
+        .find(|&built_in| built_in_as_raw(Some(built_in)) as u32 == raw)
 }
 
-#[cfg(feature = "msl")]
 pub(crate) fn built_in_as_raw(built_in: Option<BuiltIn>) -> crate::bindings::spv::BuiltIn {
     use crate::bindings as br;
     use BuiltIn::*;
@@ -271,6 +495,113 @@ pub(crate) fn built_in_as_raw(built_in: Option<BuiltIn>) -> crate::bindings::spv
         Some(HitTNv) => br::spv::BuiltIn::BuiltInHitTNV,
         Some(HitKindNv) => br::spv::BuiltIn::BuiltInHitKindNV,
         Some(IncomingRayFlagsNv) => br::spv::BuiltIn::BuiltInIncomingRayFlagsNV,
+        Some(PrimitivePointIndicesExt) => br::spv::BuiltIn::BuiltInPrimitivePointIndicesEXT,
+        Some(PrimitiveLineIndicesExt) => br::spv::BuiltIn::BuiltInPrimitiveLineIndicesEXT,
+        Some(PrimitiveTriangleIndicesExt) => br::spv::BuiltIn::BuiltInPrimitiveTriangleIndicesEXT,
+        Some(CullPrimitiveExt) => br::spv::BuiltIn::BuiltInCullPrimitiveEXT,
+    }
+}
+
+pub(crate) fn built_in_from_raw(raw: crate::bindings::spv::BuiltIn) -> Option<BuiltIn> {
+    use crate::bindings as br;
+    use BuiltIn::*;
+    match raw {
+        br::spv::BuiltIn::BuiltInPosition => Some(Position),
+        br::spv::BuiltIn::BuiltInPointSize => Some(PointSize),
+        br::spv::BuiltIn::BuiltInClipDistance => Some(ClipDistance),
+        br::spv::BuiltIn::BuiltInCullDistance => Some(CullDistance),
+        br::spv::BuiltIn::BuiltInVertexId => Some(VertexId),
+        br::spv::BuiltIn::BuiltInInstanceId => Some(InstanceId),
+        br::spv::BuiltIn::BuiltInPrimitiveId => Some(PrimitiveId),
+        br::spv::BuiltIn::BuiltInInvocationId => Some(InvocationId),
+        br::spv::BuiltIn::BuiltInLayer => Some(Layer),
+        br::spv::BuiltIn::BuiltInViewportIndex => Some(ViewportIndex),
+        br::spv::BuiltIn::BuiltInTessLevelOuter => Some(TessLevelOuter),
+        br::spv::BuiltIn::BuiltInTessLevelInner => Some(TessLevelInner),
+        br::spv::BuiltIn::BuiltInTessCoord => Some(TessCoord),
+        br::spv::BuiltIn::BuiltInPatchVertices => Some(PatchVertices),
+        br::spv::BuiltIn::BuiltInFragCoord => Some(FragCoord),
+        br::spv::BuiltIn::BuiltInPointCoord => Some(PointCoord),
+        br::spv::BuiltIn::BuiltInFrontFacing => Some(FrontFacing),
+        br::spv::BuiltIn::BuiltInSampleId => Some(SampleId),
+        br::spv::BuiltIn::BuiltInSamplePosition => Some(SamplePosition),
+        br::spv::BuiltIn::BuiltInSampleMask => Some(SampleMask),
+        br::spv::BuiltIn::BuiltInFragDepth => Some(FragDepth),
+        br::spv::BuiltIn::BuiltInHelperInvocation => Some(HelperInvocation),
+        br::spv::BuiltIn::BuiltInNumWorkgroups => Some(NumWorkgroups),
+        br::spv::BuiltIn::BuiltInWorkgroupSize => Some(WorkgroupSize),
+        br::spv::BuiltIn::BuiltInWorkgroupId => Some(WorkgroupId),
+        br::spv::BuiltIn::BuiltInLocalInvocationId => Some(LocalInvocationId),
+        br::spv::BuiltIn::BuiltInGlobalInvocationId => Some(GlobalInvocationId),
+        br::spv::BuiltIn::BuiltInLocalInvocationIndex => Some(LocalInvocationIndex),
+        br::spv::BuiltIn::BuiltInWorkDim => Some(WorkDim),
+        br::spv::BuiltIn::BuiltInGlobalSize => Some(GlobalSize),
+        br::spv::BuiltIn::BuiltInEnqueuedWorkgroupSize => Some(EnqueuedWorkgroupSize),
+        br::spv::BuiltIn::BuiltInGlobalOffset => Some(GlobalOffset),
+        br::spv::BuiltIn::BuiltInGlobalLinearId => Some(GlobalLinearId),
+        br::spv::BuiltIn::BuiltInSubgroupSize => Some(SubgroupSize),
+        br::spv::BuiltIn::BuiltInSubgroupMaxSize => Some(SubgroupMaxSize),
+        br::spv::BuiltIn::BuiltInNumSubgroups => Some(NumSubgroups),
+        br::spv::BuiltIn::BuiltInNumEnqueuedSubgroups => Some(NumEnqueuedSubgroups),
+        br::spv::BuiltIn::BuiltInSubgroupId => Some(SubgroupId),
+        br::spv::BuiltIn::BuiltInSubgroupLocalInvocationId => Some(SubgroupLocalInvocationId),
+        br::spv::BuiltIn::BuiltInVertexIndex => Some(VertexIndex),
+        br::spv::BuiltIn::BuiltInInstanceIndex => Some(InstanceIndex),
+        br::spv::BuiltIn::BuiltInSubgroupEqMask => Some(SubgroupEqMask),
+        br::spv::BuiltIn::BuiltInSubgroupGeMask => Some(SubgroupGeMask),
+        br::spv::BuiltIn::BuiltInSubgroupGtMask => Some(SubgroupGtMask),
+        br::spv::BuiltIn::BuiltInSubgroupLeMask => Some(SubgroupLeMask),
+        br::spv::BuiltIn::BuiltInSubgroupLtMask => Some(SubgroupLtMask),
+        br::spv::BuiltIn::BuiltInBaseVertex => Some(BaseVertex),
+        br::spv::BuiltIn::BuiltInBaseInstance => Some(BaseInstance),
+        br::spv::BuiltIn::BuiltInDrawIndex => Some(DrawIndex),
+        br::spv::BuiltIn::BuiltInDeviceIndex => Some(DeviceIndex),
+        br::spv::BuiltIn::BuiltInViewIndex => Some(ViewIndex),
+        br::spv::BuiltIn::BuiltInBaryCoordNoPerspAMD => Some(BaryCoordNoPerspAmd),
+        br::spv::BuiltIn::BuiltInBaryCoordNoPerspCentroidAMD => Some(BaryCoordNoPerspCentroidAmd),
+        br::spv::BuiltIn::BuiltInBaryCoordNoPerspSampleAMD => Some(BaryCoordNoPerspSampleAmd),
+        br::spv::BuiltIn::BuiltInBaryCoordSmoothAMD => Some(BaryCoordSmoothAmd),
+        br::spv::BuiltIn::BuiltInBaryCoordSmoothCentroidAMD => Some(BaryCoordSmoothCentroidAmd),
+        br::spv::BuiltIn::BuiltInBaryCoordSmoothSampleAMD => Some(BaryCoordSmoothSampleAmd),
+        br::spv::BuiltIn::BuiltInBaryCoordPullModelAMD => Some(BaryCoordPullModelAmd),
+        br::spv::BuiltIn::BuiltInFragStencilRefEXT => Some(FragStencilRefExt),
+        br::spv::BuiltIn::BuiltInViewportMaskNV => Some(ViewportMaskNv),
+        br::spv::BuiltIn::BuiltInSecondaryPositionNV => Some(SecondaryPositionNv),
+        br::spv::BuiltIn::BuiltInSecondaryViewportMaskNV => Some(SecondaryViewportMaskNv),
+        br::spv::BuiltIn::BuiltInPositionPerViewNV => Some(PositionPerViewNv),
+        br::spv::BuiltIn::BuiltInViewportMaskPerViewNV => Some(ViewportMaskPerViewNv),
+        br::spv::BuiltIn::BuiltInFullyCoveredEXT => Some(FullyCoveredExt),
+        br::spv::BuiltIn::BuiltInTaskCountNV => Some(TaskCountNv),
+        br::spv::BuiltIn::BuiltInPrimitiveCountNV => Some(PrimitiveCountNv),
+        br::spv::BuiltIn::BuiltInPrimitiveIndicesNV => Some(PrimitiveIndicesNv),
+        br::spv::BuiltIn::BuiltInClipDistancePerViewNV => Some(ClipDistancePerViewNv),
+        br::spv::BuiltIn::BuiltInCullDistancePerViewNV => Some(CullDistancePerViewNv),
+        br::spv::BuiltIn::BuiltInLayerPerViewNV => Some(LayerPerViewNv),
+        br::spv::BuiltIn::BuiltInMeshViewCountNV => Some(MeshViewCountNv),
+        br::spv::BuiltIn::BuiltInMeshViewIndicesNV => Some(MeshViewIndicesNv),
+        br::spv::BuiltIn::BuiltInBaryCoordNV => Some(BaryCoordNv),
+        br::spv::BuiltIn::BuiltInBaryCoordNoPerspNV => Some(BaryCoordNoPerspNv),
+        br::spv::BuiltIn::BuiltInFragSizeEXT => Some(FragSizeExt),
+        br::spv::BuiltIn::BuiltInFragInvocationCountEXT => Some(FragInvocationCountExt),
+        br::spv::BuiltIn::BuiltInLaunchIdNV => Some(LaunchIdNv),
+        br::spv::BuiltIn::BuiltInLaunchSizeNV => Some(LaunchSizeNv),
+        br::spv::BuiltIn::BuiltInWorldRayOriginNV => Some(WorldRayOriginNv),
+        br::spv::BuiltIn::BuiltInWorldRayDirectionNV => Some(WorldRayDirectionNv),
+        br::spv::BuiltIn::BuiltInObjectRayOriginNV => Some(ObjectRayOriginNv),
+        br::spv::BuiltIn::BuiltInObjectRayDirectionNV => Some(ObjectRayDirectionNv),
+        br::spv::BuiltIn::BuiltInRayTminNV => Some(RayTminNv),
+        br::spv::BuiltIn::BuiltInRayTmaxNV => Some(RayTmaxNv),
+        br::spv::BuiltIn::BuiltInInstanceCustomIndexNV => Some(InstanceCustomIndexNv),
+        br::spv::BuiltIn::BuiltInObjectToWorldNV => Some(ObjectToWorldNv),
+        br::spv::BuiltIn::BuiltInWorldToObjectNV => Some(WorldToObjectNv),
+        br::spv::BuiltIn::BuiltInHitTNV => Some(HitTNv),
+        br::spv::BuiltIn::BuiltInHitKindNV => Some(HitKindNv),
+        br::spv::BuiltIn::BuiltInIncomingRayFlagsNV => Some(IncomingRayFlagsNv),
+        br::spv::BuiltIn::BuiltInPrimitivePointIndicesEXT => Some(PrimitivePointIndicesExt),
+        br::spv::BuiltIn::BuiltInPrimitiveLineIndicesEXT => Some(PrimitiveLineIndicesExt),
+        br::spv::BuiltIn::BuiltInPrimitiveTriangleIndicesEXT => Some(PrimitiveTriangleIndicesExt),
+        br::spv::BuiltIn::BuiltInCullPrimitiveEXT => Some(CullPrimitiveExt),
+        _ => None,
     }
 }
 
@@ -290,6 +621,191 @@ pub struct EntryPoint {
     pub work_group_size: WorkGroupSize,
 }
 
+/// The input primitive of a geometry shader.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum GeometryInputPrimitive {
+    Points,
+    Lines,
+    LinesAdjacency,
+    Triangles,
+    TrianglesAdjacency,
+}
+
+/// The output primitive of a geometry shader.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum GeometryOutputPrimitive {
+    Points,
+    LineStrip,
+    TriangleStrip,
+}
+
+/// An execution mode of an entry point.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ExecutionMode {
+    Invocations,
+    SpacingEqual,
+    SpacingFractionalEven,
+    SpacingFractionalOdd,
+    VertexOrderCw,
+    VertexOrderCcw,
+    PixelCenterInteger,
+    OriginUpperLeft,
+    OriginLowerLeft,
+    EarlyFragmentTests,
+    PointMode,
+    Xfb,
+    DepthReplacing,
+    DepthGreater,
+    DepthLess,
+    DepthUnchanged,
+    LocalSize,
+    LocalSizeHint,
+    InputPoints,
+    InputLines,
+    InputLinesAdjacency,
+    Triangles,
+    InputTrianglesAdjacency,
+    Quads,
+    Isolines,
+    OutputVertices,
+    OutputPoints,
+    OutputLineStrip,
+    OutputTriangleStrip,
+    VecTypeHint,
+    ContractionOff,
+    Initializer,
+    Finalizer,
+    SubgroupSize,
+    SubgroupsPerWorkgroup,
+    SubgroupsPerWorkgroupId,
+    LocalSizeId,
+    LocalSizeHintId,
+    PostDepthCoverage,
+    DenormPreserve,
+    DenormFlushToZero,
+    SignedZeroInfNanPreserve,
+    RoundingModeRte,
+    RoundingModeRtz,
+    StencilRefReplacingExt,
+    OutputLinesExt,
+    OutputPrimitivesExt,
+    DerivativeGroupQuadsNv,
+    DerivativeGroupLinearNv,
+    OutputTrianglesExt,
+    PixelInterlockOrderedExt,
+    PixelInterlockUnorderedExt,
+    SampleInterlockOrderedExt,
+    SampleInterlockUnorderedExt,
+    ShadingRateInterlockOrderedExt,
+    ShadingRateInterlockUnorderedExt,
+    MaxWorkgroupSizeIntel,
+    MaxWorkDimIntel,
+    NoGlobalOffsetIntel,
+    NumSimdWorkitemsIntel,
+    /// A mode without its own variant, holding its raw SPIR-V value.
+    Unknown(u32),
+}
+
+/// An execution mode of an entry point, with the arguments SPIRV-Cross keeps for it.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ExecutionModeInfo {
+    pub mode: ExecutionMode,
+    /// The arguments of the mode: the sizes of `LocalSize`, the constant IDs of `LocalSizeId`,
+    /// and the counts of `Invocations`, `OutputVertices` and `OutputPrimitivesExt`. Empty for
+    /// other modes, whose arguments aren't kept when the module is parsed.
+    pub arguments: Vec<u32>,
+}
+
+/// The clocks read with `OpReadClockKHR`, from `SPV_KHR_shader_clock`.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct ShaderClockUsage {
+    /// The subgroup clock is read, which GLSL maps to `clockARB`.
+    pub subgroup: bool,
+    /// The device clock is read, which GLSL maps to `clockRealtimeEXT`.
+    pub device: bool,
+}
+
+/// The memory access decorations of a buffer block, from `Ast::get_buffer_block_flags`.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct BufferBlockFlags {
+    /// The buffer is never written, so it can be bound as a read-only storage buffer in Vulkan
+    /// or a `ByteAddressBuffer` SRV rather than a UAV in D3D.
+    pub non_writable: bool,
+    /// The buffer is never read.
+    pub non_readable: bool,
+    pub restrict: bool,
+    pub coherent: bool,
+    pub volatile: bool,
+}
+
+/// Geometry shader execution modes of an entry point.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct GeometryExecutionModes {
+    /// Number of invocations per input primitive, or 0 if not declared.
+    pub invocations: u32,
+    pub input_primitive: Option<GeometryInputPrimitive>,
+    pub output_primitive: Option<GeometryOutputPrimitive>,
+    /// Maximum number of vertices emitted per invocation, or 0 if not declared.
+    pub max_vertices: u32,
+}
+
+/// A conservative depth layout, promising how the depth written by a fragment shader relates
+/// to the depth of the fragment, so early depth tests can stay enabled.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ConservativeDepth {
+    /// The written depth is never less than the fragment depth (`depth_greater`).
+    Greater,
+    /// The written depth is never greater than the fragment depth (`depth_less`).
+    Less,
+    /// The written depth is the fragment depth (`depth_unchanged`).
+    Unchanged,
+}
+
+/// How a fragment shader interacts with the depth test.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct FragmentDepthModes {
+    /// Whether `gl_FragDepth` (`SV_Depth`) is written, replacing the depth of the fragment.
+    pub writes_depth: bool,
+    /// The conservative depth layout of the written depth, or `None` if it is unconstrained.
+    pub conservative_depth: Option<ConservativeDepth>,
+    /// Whether depth and stencil tests run before the shader (`early_fragment_tests`).
+    pub early_fragment_tests: bool,
+}
+
+/// A stage output, or a member of an output block, captured by transform feedback.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct TransformFeedbackOutput {
+    pub id: u32,
+    /// The index of the captured member of an output block.
+    pub member_index: Option<u32>,
+    /// The name of the output, or `Block.member` for block members, as in GL varyings.
+    pub name: String,
+    /// The `XfbBuffer` decoration.
+    pub buffer: u32,
+    /// The `XfbStride` decoration, the stride of each vertex in the buffer.
+    pub stride: u32,
+    /// The `Offset` decoration, the offset of the output within each vertex.
+    pub offset: u32,
+}
+
+/// An output of a mesh shader.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct MeshOutput {
+    pub id: u32,
+    /// The location of a user-defined output.
+    pub location: Option<u32>,
+    /// The builtin of a builtin output.
+    pub built_in: Option<BuiltIn>,
+}
+
+/// The outputs of a mesh shader, split by whether they are written per vertex or per primitive.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct MeshOutputs {
+    pub per_vertex: Vec<MeshOutput>,
+    pub per_primitive: Vec<MeshOutput>,
+}
+
 /// Description of struct member's range.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct BufferRange {
@@ -310,6 +826,22 @@ pub struct Resource {
     pub name: String,
 }
 
+/// A handle to a resource, tied to the `Ast` it was created by.
+///
+/// Unlike a raw ID, passing a handle to a different `Ast` is reported as an error.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ResourceHandle {
+    id: u32,
+    ast_tag: compiler::AstTag,
+}
+
+impl ResourceHandle {
+    /// Gets the raw ID of the resource.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 /// Specialization constant reference.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub struct SpecializationConstant {
@@ -325,6 +857,203 @@ pub struct WorkGroupSizeSpecializationConstants {
     pub z: SpecializationConstant,
 }
 
+/// Clip-space conversions applied to the position output of a compiled shader.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct ClipSpaceFixups {
+    /// Depth was converted to the target's range: from `[0, 1]` to `[-1, 1]` for GLSL, and
+    /// from `[-1, 1]` to `[0, 1]` for HLSL and MSL.
+    pub transform_clip_space: bool,
+    /// Y was inverted.
+    pub invert_y: bool,
+}
+
+/// An identifier shortened by `Ast::limit_identifier_length`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IdentifierRename {
+    /// The renamed ID, or the struct type ID for a member.
+    pub id: u32,
+    pub member_index: Option<u32>,
+    pub original: String,
+    pub renamed: String,
+}
+
+/// The dimensionality of an image type.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ImageDimension {
+    Dim1D,
+    Dim2D,
+    Dim3D,
+    Cube,
+    Rect,
+    /// A texel buffer, such as `samplerBuffer` or `imageBuffer`.
+    Buffer,
+    /// A subpass input.
+    SubpassData,
+    /// A dimensionality unknown to this crate, as a raw `spv::Dim` value.
+    Unknown(u32),
+}
+
+/// How an `ImageResource` is declared.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ImageResourceKind {
+    /// An image combined with a sampler, such as `sampler2D`.
+    SampledImage,
+    /// An image sampled with a separate sampler, such as `texture2D`.
+    SeparateImage,
+    /// An image read and written without a sampler, such as `image2D`.
+    StorageImage,
+    SubpassInput,
+}
+
+/// An image resource with its image type, from `Ast::get_image_resources`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ImageResource {
+    pub resource: Resource,
+    pub kind: ImageResourceKind,
+    pub image_type: ImageType,
+}
+
+/// The storage format of an image type, as declared by a layout qualifier such as `rgba8`.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ImageFormat {
+    Rgba32f,
+    Rgba16f,
+    R32f,
+    Rgba8,
+    Rgba8Snorm,
+    Rg32f,
+    Rg16f,
+    R11fG11fB10f,
+    R16f,
+    Rgba16,
+    Rgb10A2,
+    Rg16,
+    Rg8,
+    R16,
+    R8,
+    Rgba16Snorm,
+    Rg16Snorm,
+    Rg8Snorm,
+    R16Snorm,
+    R8Snorm,
+    Rgba32i,
+    Rgba16i,
+    Rgba8i,
+    R32i,
+    Rg32i,
+    Rg16i,
+    Rg8i,
+    R16i,
+    R8i,
+    Rgba32ui,
+    Rgba16ui,
+    Rgba8ui,
+    R32ui,
+    Rgb10a2ui,
+    Rg32ui,
+    Rg16ui,
+    Rg8ui,
+    R16ui,
+    R8ui,
+    R64ui,
+    R64i,
+    /// A format unknown to this crate, as a raw `spv::ImageFormat` value.
+    Unknown(u32),
+}
+
+/// How a shader accesses an image.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ImageAccess {
+    ReadOnly,
+    WriteOnly,
+    ReadWrite,
+}
+
+/// The image type of an image resource, from `Ast::get_image_type`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ImageType {
+    pub dimension: ImageDimension,
+    pub arrayed: bool,
+    pub multisampled: bool,
+    /// Whether the image is declared as a depth image, i.e. sampled with depth comparison as
+    /// by a `sampler2DShadow`.
+    pub depth: bool,
+    /// Whether the image is a storage image, such as `image2D`, rather than a sampled image.
+    pub storage: bool,
+    /// The storage format, or `None` if the image doesn't declare one, as sampled images don't.
+    pub format: Option<ImageFormat>,
+    /// The access of a storage image, from its `readonly` and `writeonly` qualifiers. Sampled
+    /// images are always `ReadOnly`.
+    pub access: ImageAccess,
+}
+
+/// Leniency of `Ast::check_interface_compatibility`, for stages whose modules were produced by
+/// different front-ends.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct InterfaceMatchOptions {
+    /// Whether an output may have more vector components than the input at its location reads,
+    /// i.e. a `vec4` output read as a `vec2`.
+    pub allow_component_truncation: bool,
+    /// Whether numeric types of the same class but a different signedness or width match,
+    /// i.e. `int` and `uint`, or `float` and `half`.
+    pub allow_scalar_type_mismatch: bool,
+    /// Whether outputs that no input reads are allowed.
+    pub allow_unused_outputs: bool,
+}
+
+impl Default for InterfaceMatchOptions {
+    fn default() -> Self {
+        InterfaceMatchOptions {
+            allow_component_truncation: false,
+            allow_scalar_type_mismatch: false,
+            allow_unused_outputs: true,
+        }
+    }
+}
+
+/// A stage output and input that don't match, keyed by `Location` and `Component`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub enum InterfaceMismatch {
+    /// An input has no output at its location.
+    MissingOutput {
+        location: u32,
+        component: u32,
+        input: Resource,
+    },
+    /// An output has no input at its location. Only reported if `allow_unused_outputs` is unset.
+    UnusedOutput {
+        location: u32,
+        component: u32,
+        output: Resource,
+    },
+    /// An output and input share a location, but their types don't match, or the input starts
+    /// at a location within a matrix or array output.
+    TypeMismatch {
+        location: u32,
+        component: u32,
+        output: Resource,
+        input: Resource,
+    },
+    /// An output or input has no `Location` decoration, so it can't be matched.
+    MissingLocation { resource: Resource },
+}
+
+/// The result of `Ast::check_interface_compatibility`.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct InterfaceCompatibility {
+    pub mismatches: Vec<InterfaceMismatch>,
+}
+
+impl InterfaceCompatibility {
+    /// Whether the stages can be linked, i.e. there are no mismatches.
+    pub fn is_compatible(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
 /// Shader resources.
 #[derive(Debug, Clone)]
 pub struct ShaderResources {
@@ -339,6 +1068,11 @@ pub struct ShaderResources {
     pub push_constant_buffers: Vec<Resource>,
     pub separate_images: Vec<Resource>,
     pub separate_samplers: Vec<Resource>,
+    /// Ray tracing acceleration structures, such as `accelerationStructureEXT`.
+    pub acceleration_structures: Vec<Resource>,
+    /// Buffers in the `ShaderRecordBufferKHR` storage class, which are read from the shader
+    /// binding table rather than a descriptor set.
+    pub shader_record_buffers: Vec<Resource>,
 }
 
 #[derive(Debug, Clone)]
@@ -426,6 +1160,339 @@ pub enum Type {
     Interpolant,
 }
 
+/// A capability declared by the module with `OpCapability`.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum Capability {
+    Matrix,
+    Shader,
+    Geometry,
+    Tessellation,
+    Addresses,
+    Linkage,
+    Kernel,
+    Vector16,
+    Float16Buffer,
+    Float16,
+    Float64,
+    Int64,
+    Int64Atomics,
+    ImageBasic,
+    ImageReadWrite,
+    ImageMipmap,
+    Pipes,
+    Groups,
+    DeviceEnqueue,
+    LiteralSampler,
+    AtomicStorage,
+    Int16,
+    TessellationPointSize,
+    GeometryPointSize,
+    ImageGatherExtended,
+    StorageImageMultisample,
+    UniformBufferArrayDynamicIndexing,
+    SampledImageArrayDynamicIndexing,
+    StorageBufferArrayDynamicIndexing,
+    StorageImageArrayDynamicIndexing,
+    ClipDistance,
+    CullDistance,
+    ImageCubeArray,
+    SampleRateShading,
+    ImageRect,
+    SampledRect,
+    GenericPointer,
+    Int8,
+    InputAttachment,
+    SparseResidency,
+    MinLod,
+    Sampled1D,
+    Image1D,
+    SampledCubeArray,
+    SampledBuffer,
+    ImageBuffer,
+    ImageMSArray,
+    StorageImageExtendedFormats,
+    ImageQuery,
+    DerivativeControl,
+    InterpolationFunction,
+    TransformFeedback,
+    GeometryStreams,
+    StorageImageReadWithoutFormat,
+    StorageImageWriteWithoutFormat,
+    MultiViewport,
+    SubgroupDispatch,
+    NamedBarrier,
+    PipeStorage,
+    GroupNonUniform,
+    GroupNonUniformVote,
+    GroupNonUniformArithmetic,
+    GroupNonUniformBallot,
+    GroupNonUniformShuffle,
+    GroupNonUniformShuffleRelative,
+    GroupNonUniformClustered,
+    GroupNonUniformQuad,
+    ShaderLayer,
+    ShaderViewportIndex,
+    FragmentShadingRate,
+    SubgroupBallot,
+    DrawParameters,
+    SubgroupVote,
+    StorageBuffer16BitAccess,
+    StorageUniform16,
+    StoragePushConstant16,
+    StorageInputOutput16,
+    DeviceGroup,
+    MultiView,
+    VariablePointersStorageBuffer,
+    VariablePointers,
+    AtomicStorageOps,
+    SampleMaskPostDepthCoverage,
+    StorageBuffer8BitAccess,
+    UniformAndStorageBuffer8BitAccess,
+    StoragePushConstant8,
+    DenormPreserve,
+    DenormFlushToZero,
+    SignedZeroInfNanPreserve,
+    RoundingModeRTE,
+    RoundingModeRTZ,
+    RayQueryProvisional,
+    RayQuery,
+    RayTraversalPrimitiveCulling,
+    RayTracing,
+    Float16ImageAMD,
+    ImageGatherBiasLodAMD,
+    FragmentMaskAMD,
+    StencilExport,
+    ImageReadWriteLodAMD,
+    Int64Image,
+    ShaderClock,
+    SampleMaskOverrideCoverageNV,
+    GeometryShaderPassthroughNV,
+    ShaderViewportIndexLayer,
+    ShaderViewportMaskNV,
+    ShaderStereoViewNV,
+    PerViewAttributesNV,
+    FragmentFullyCovered,
+    MeshShadingNV,
+    ImageFootprintNV,
+    MeshShading,
+    FragmentBarycentricNV,
+    ComputeDerivativeGroupQuadsNV,
+    FragmentDensity,
+    GroupNonUniformPartitionedNV,
+    ShaderNonUniform,
+    RuntimeDescriptorArray,
+    InputAttachmentArrayDynamicIndexing,
+    UniformTexelBufferArrayDynamicIndexing,
+    StorageTexelBufferArrayDynamicIndexing,
+    UniformBufferArrayNonUniformIndexing,
+    SampledImageArrayNonUniformIndexing,
+    StorageBufferArrayNonUniformIndexing,
+    StorageImageArrayNonUniformIndexing,
+    InputAttachmentArrayNonUniformIndexing,
+    UniformTexelBufferArrayNonUniformIndexing,
+    StorageTexelBufferArrayNonUniformIndexing,
+    RayTracingNV,
+    VulkanMemoryModel,
+    VulkanMemoryModelDeviceScope,
+    PhysicalStorageBufferAddresses,
+    ComputeDerivativeGroupLinearNV,
+    RayTracingProvisional,
+    CooperativeMatrixNV,
+    FragmentShaderSampleInterlock,
+    FragmentShaderShadingRateInterlock,
+    ShaderSMBuiltinsNV,
+    FragmentShaderPixelInterlock,
+    DemoteToHelperInvocation,
+    SubgroupShuffleINTEL,
+    SubgroupBufferBlockIOINTEL,
+    SubgroupImageBlockIOINTEL,
+    SubgroupImageMediaBlockIOINTEL,
+    IntegerFunctions2INTEL,
+    FunctionPointersINTEL,
+    IndirectReferencesINTEL,
+    SubgroupAvcMotionEstimationINTEL,
+    SubgroupAvcMotionEstimationIntraINTEL,
+    SubgroupAvcMotionEstimationChromaINTEL,
+    FPGAMemoryAttributesINTEL,
+    UnstructuredLoopControlsINTEL,
+    FPGALoopControlsINTEL,
+    KernelAttributesINTEL,
+    FPGAKernelAttributesINTEL,
+    BlockingPipesINTEL,
+    FPGARegINTEL,
+    AtomicFloat32Add,
+    AtomicFloat64Add,
+    /// A capability unknown to this crate, such as `DotProduct`, as a raw `spv::Capability`
+    /// value.
+    Unknown(u32),
+}
+
+/// The storage class of a pointer type.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum StorageClass {
+    UniformConstant,
+    Input,
+    Uniform,
+    Output,
+    Workgroup,
+    CrossWorkgroup,
+    Private,
+    Function,
+    Generic,
+    PushConstant,
+    AtomicCounter,
+    Image,
+    StorageBuffer,
+    CallableData,
+    IncomingCallableData,
+    RayPayload,
+    HitAttribute,
+    IncomingRayPayload,
+    ShaderRecordBuffer,
+    PhysicalStorageBuffer,
+    /// A storage class unknown to this crate, such as `TaskPayloadWorkgroupEXT`, as a raw
+    /// `spv::StorageClass` value.
+    Unknown(u32),
+}
+
+/// The length of an array dimension of a type.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ArrayDimension {
+    Literal(u32),
+    /// Sized by the specialization constant with this ID, so the length is only known once
+    /// the constant is set.
+    SpecializationConstant(u32),
+    /// A runtime-sized array, whose length depends on the bound buffer.
+    Runtime,
+}
+
+/// A pointer type, such as the type of a variable.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct PointerType {
+    pub storage_class: StorageClass,
+    pub pointee_type_id: u32,
+}
+
+/// A type with the details `Type` leaves out, from `Ast::get_type_details`.
+#[derive(Clone, Debug)]
+pub struct TypeDetails {
+    /// The type, as given by `Ast::get_type`. Pointer types are described by their pointee.
+    pub ty: Type,
+    /// The array dimensions of the type, from the innermost to the outermost as in `ty`.
+    pub array_dimensions: Vec<ArrayDimension>,
+    /// The storage class and pointee of a pointer type, or `None` for other types.
+    pub pointer: Option<PointerType>,
+}
+
+/// The layout of a buffer block struct member, from `Ast::get_struct_member_layout`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct StructMemberLayout {
+    /// The offset in bytes.
+    pub offset: u32,
+    /// The size in bytes.
+    pub size: u32,
+    /// The stride in bytes between the elements of an array member.
+    pub array_stride: Option<u32>,
+    /// The stride in bytes between the columns, or rows if `row_major`, of a matrix member.
+    pub matrix_stride: Option<u32>,
+    /// Whether a matrix member is stored in row-major order.
+    pub row_major: bool,
+}
+
+/// A member of, or padding in, a buffer block struct, from `Ast::get_padded_struct_layout`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct StructField {
+    pub name: String,
+    /// The offset in bytes.
+    pub offset: u32,
+    /// The size in bytes.
+    pub size: u32,
+    /// The index of the struct member, or `None` for padding.
+    pub member_index: Option<u32>,
+}
+
+impl StructField {
+    pub(crate) fn padding(field_index: usize, start: u32, end: u32) -> Self {
+        StructField {
+            name: format!("_pad{}", field_index),
+            offset: start,
+            size: end - start,
+            member_index: None,
+        }
+    }
+}
+
+/// The layered rendering builtins written by a shader.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct LayeredRenderingOutputs {
+    /// Whether `gl_Layer` (`SV_RenderTargetArrayIndex`) is written.
+    pub layer: bool,
+    /// Whether `gl_ViewportIndex` (`SV_ViewportArrayIndex`) is written.
+    pub viewport_index: bool,
+    /// Whether they're written by a vertex or tessellation evaluation shader rather than a
+    /// geometry shader. This needs `shaderOutputLayer` and `shaderOutputViewportIndex` (or
+    /// `VK_EXT_shader_viewport_index_layer`) in Vulkan, `GL_ARB_shader_viewport_layer_array` in
+    /// GL and `VPAndRTArrayIndexFromAnyShaderFeedingRasterizer` in D3D, without which a
+    /// geometry shader must be used instead.
+    pub from_vertex_processing: bool,
+}
+
+/// A loop unrolling hint, stored in the `LoopControl` operand of `OpLoopMerge`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum LoopUnrollHint {
+    /// No hint, leaving the choice to the driver compiler.
+    None,
+    /// Emitted as `[unroll]` in HLSL.
+    Unroll,
+    /// Emitted as `[loop]` in HLSL.
+    DontUnroll,
+}
+
+/// Replaces the unrolling hint of every loop in the SPIR-V `words`, which can then be parsed
+/// with `Module::from_words`. Without this, the hints chosen by the front-end are kept.
+///
+/// Only HLSL output carries the hints; GLSL and MSL output ignore them.
+pub fn set_loop_unroll_hints(words: &mut [u32], hint: LoopUnrollHint) {
+    const HEADER_LEN: usize = 5;
+    const OP_LOOP_MERGE: u32 = 246;
+    const LOOP_CONTROL_UNROLL: u32 = 0x1;
+    const LOOP_CONTROL_DONT_UNROLL: u32 = 0x2;
+
+    let hint_bits = match hint {
+        LoopUnrollHint::None => 0,
+        LoopUnrollHint::Unroll => LOOP_CONTROL_UNROLL,
+        LoopUnrollHint::DontUnroll => LOOP_CONTROL_DONT_UNROLL,
+    };
+
+    let mut offset = HEADER_LEN;
+    while offset < words.len() {
+        let word_count = (words[offset] >> 16) as usize;
+        let opcode = words[offset] & 0xffff;
+        if word_count == 0 {
+            break;
+        }
+        if opcode == OP_LOOP_MERGE && word_count > 3 && offset + 3 < words.len() {
+            let loop_control = &mut words[offset + 3];
+            *loop_control =
+                (*loop_control & !(LOOP_CONTROL_UNROLL | LOOP_CONTROL_DONT_UNROLL)) | hint_bits;
+        }
+        offset += word_count;
+    }
+}
+
+/// A source file named by the debug info of a module.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct SourceFile {
+    /// The ID of the `OpString` holding the file name.
+    pub id: u32,
+    pub name: String,
+    /// The names of the entry points with code from the file, including code in functions they
+    /// call, according to `OpLine` instructions.
+    pub entry_points: Vec<String>,
+}
+
 /// A SPIR-V shader module.
 #[derive(Debug, Clone)]
 pub struct Module<'a> {
@@ -437,12 +1504,132 @@ impl<'a> Module<'a> {
     pub fn from_words(words: &[u32]) -> Module {
         Module { words }
     }
+
+    /// Gets the source files named by `OpSource` and `OpLine` instructions, in the order of
+    /// their `OpString`s. Modules without debug info have none.
+    pub fn get_source_files(&self) -> Vec<SourceFile> {
+        const HEADER_LEN: usize = 5;
+        const OP_SOURCE: u32 = 3;
+        const OP_STRING: u32 = 7;
+        const OP_LINE: u32 = 8;
+        const OP_ENTRY_POINT: u32 = 15;
+        const OP_FUNCTION: u32 = 54;
+        const OP_FUNCTION_END: u32 = 56;
+        const OP_FUNCTION_CALL: u32 = 57;
+
+        let mut strings = Vec::new();
+        let mut source_file_ids = HashSet::new();
+        let mut entry_points = Vec::new();
+        let mut function_files = HashMap::<u32, HashSet<u32>>::new();
+        let mut function_calls = HashMap::<u32, HashSet<u32>>::new();
+        let mut current_function = None;
+
+        let mut offset = HEADER_LEN;
+        while offset < self.words.len() {
+            let word_count = (self.words[offset] >> 16) as usize;
+            let opcode = self.words[offset] & 0xffff;
+            if word_count == 0 || offset + word_count > self.words.len() {
+                break;
+            }
+            let operands = &self.words[offset + 1..offset + word_count];
+            match opcode {
+                OP_STRING if !operands.is_empty() => {
+                    strings.push((operands[0], read_literal_string(&operands[1..])));
+                }
+                OP_SOURCE if operands.len() > 2 => {
+                    source_file_ids.insert(operands[2]);
+                }
+                OP_LINE if !operands.is_empty() => {
+                    source_file_ids.insert(operands[0]);
+                    if let Some(function) = current_function {
+                        function_files
+                            .entry(function)
+                            .or_default()
+                            .insert(operands[0]);
+                    }
+                }
+                OP_ENTRY_POINT if operands.len() > 1 => {
+                    entry_points.push((operands[1], read_literal_string(&operands[2..])));
+                }
+                OP_FUNCTION if operands.len() > 1 => current_function = Some(operands[1]),
+                OP_FUNCTION_END => current_function = None,
+                OP_FUNCTION_CALL if operands.len() > 2 => {
+                    if let Some(function) = current_function {
+                        function_calls
+                            .entry(function)
+                            .or_default()
+                            .insert(operands[2]);
+                    }
+                }
+                _ => {}
+            }
+            offset += word_count;
+        }
+
+        // Gather the files of every function reachable from each entry point.
+        let entry_point_files = entry_points
+            .iter()
+            .map(|(function, name)| {
+                let mut files = HashSet::<u32>::new();
+                let mut visited = HashSet::new();
+                let mut pending = vec![*function];
+                while let Some(function) = pending.pop() {
+                    if !visited.insert(function) {
+                        continue;
+                    }
+                    if let Some(function_files) = function_files.get(&function) {
+                        files.extend(function_files);
+                    }
+                    if let Some(callees) = function_calls.get(&function) {
+                        pending.extend(callees);
+                    }
+                }
+                (name, files)
+            })
+            .collect::<Vec<_>>();
+
+        strings
+            .into_iter()
+            .filter(|(id, _)| source_file_ids.contains(id))
+            .map(|(id, name)| SourceFile {
+                id,
+                name,
+                entry_points: entry_point_files
+                    .iter()
+                    .filter(|(_, files)| files.contains(&id))
+                    .map(|(name, _)| (*name).clone())
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Reads a nul-terminated UTF-8 literal string packed into SPIR-V words.
+fn read_literal_string(words: &[u32]) -> String {
+    let bytes = words
+        .iter()
+        .flat_map(|word| word.to_le_bytes().to_vec())
+        .take_while(|&byte| byte != 0)
+        .collect::<Vec<_>>();
+    String::from_utf8_lossy(&bytes).into_owned()
 }
 
 pub trait Target {
     type Data;
 }
 
+/// The lifecycle state of an `Ast`, from `Ast::state`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum AstState {
+    /// Parsed, with the default compiler options.
+    Parsed,
+    /// Compiler options were set, but `compile` hasn't been called.
+    OptionsSet,
+    /// `compile` was called, so reflection of the compiled shader is available. Later option
+    /// changes apply to the next `compile`.
+    Compiled,
+}
+
 /// An abstract syntax tree that corresponds to a SPIR-V module.
 pub struct Ast<TTarget>
 where
@@ -464,6 +1651,12 @@ pub trait Compile<TTarget> {
         compiler_options: &Self::CompilerOptions,
     ) -> Result<(), ErrorCode>;
     fn compile(&mut self) -> Result<String, ErrorCode>;
+
+    /// Compiles into `shader`, replacing its contents but reusing its allocation.
+    fn compile_into(&mut self, shader: &mut String) -> Result<(), ErrorCode> {
+        *shader = self.compile()?;
+        Ok(())
+    }
 }
 
 impl<TTarget> Ast<TTarget>
@@ -476,18 +1669,58 @@ where
         self.compiler.get_decoration(id, decoration)
     }
 
+    /// Gets whether an ID has a decoration, which `get_decoration` can't tell apart from a
+    /// decoration with the argument 0.
+    pub fn has_decoration(&self, id: u32, decoration: Decoration) -> Result<bool, ErrorCode> {
+        self.compiler.has_decoration(id, decoration)
+    }
+
+    /// Gets the string argument of a decoration, such as the HLSL semantic of
+    /// `HlslSemanticGoogle` or the original HLSL resource type of `UserTypeGoogle`. If not
+    /// defined, an empty string will be returned.
+    pub fn get_decoration_string(
+        &self,
+        id: u32,
+        decoration: Decoration,
+    ) -> Result<String, ErrorCode> {
+        self.compiler.get_decoration_string(id, decoration)
+    }
+
+    /// Sets the string argument of a decoration. Only `HlslSemanticGoogle` and
+    /// `UserTypeGoogle` are kept by SPIRV-Cross.
+    pub fn set_decoration_string(
+        &mut self,
+        id: u32,
+        decoration: Decoration,
+        argument: &str,
+    ) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler
+            .set_decoration_string(id, decoration, argument)
+    }
+
     /// Gets a name. If not defined, an empty string will be returned.
-    pub fn get_name(&mut self, id: u32) -> Result<String, ErrorCode> {
+    pub fn get_name(&self, id: u32) -> Result<String, ErrorCode> {
         self.compiler.get_name(id)
     }
 
-    /// Sets a name.
+    /// Gets a name, shared with a cache held by the `Ast`. Unlike `get_name`, repeated
+    /// queries for the same ID don't allocate. Use `get_name` for an owned copy.
+    pub fn get_name_interned(&self, id: u32) -> Result<Rc<str>, ErrorCode> {
+        self.compiler.get_name_interned(id)
+    }
+
+    /// Sets the name of a variable, type or function, which is used by subsequent reflection
+    /// and compilation. Buffer resources are reflected with the name of their block type.
     pub fn set_name(&mut self, id: u32, name: &str) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.name_cache.invalidate(id);
         self.compiler.set_name(id, name)
     }
 
     /// Unsets a decoration.
     pub fn unset_decoration(&mut self, id: u32, decoration: Decoration) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
         self.compiler.unset_decoration(id, decoration)
     }
 
@@ -498,6 +1731,7 @@ where
         decoration: Decoration,
         argument: u32,
     ) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
         self.compiler.set_decoration(id, decoration, argument)
     }
 
@@ -522,7 +1756,9 @@ where
         }
     }
 
-    /// Gets active buffer ragnes.  Useful for push constants.
+    /// Gets the ranges of the buffer variable `id` which the shader accesses, one per member.
+    /// Members which are never accessed are omitted, so only the used parts of large uniform
+    /// buffers and push constants need to be uploaded.
     pub fn get_active_buffer_ranges(&self, id: u32) -> Result<Vec<BufferRange>, ErrorCode> {
         self.compiler.get_active_buffer_ranges(id)
     }
@@ -536,24 +1772,268 @@ where
     ///
     /// Can be used to override specialization constants.
     pub fn set_scalar_constant(&mut self, id: u32, value: u64) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
         self.compiler.set_scalar_constant(id, value)
     }
 
+    /// Sets a 32-bit float scalar constant, such as a `float` specialization constant, so that
+    /// its value is baked into the compiled source.
+    pub fn set_scalar_constant_f32(&mut self, id: u32, value: f32) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler
+            .set_scalar_constant(id, u64::from(value.to_bits()))
+    }
+
+    /// Sets a 32-bit unsigned integer scalar constant, such as a `uint` specialization
+    /// constant, so that its value is baked into the compiled source.
+    pub fn set_scalar_constant_u32(&mut self, id: u32, value: u32) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.set_scalar_constant(id, u64::from(value))
+    }
+
+    /// Sets a boolean scalar constant, such as a `bool` specialization constant, so that its
+    /// value is baked into the compiled source.
+    pub fn set_scalar_constant_bool(&mut self, id: u32, value: bool) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.set_scalar_constant(id, u64::from(value))
+    }
+
+    /// Gets the builtins which the entry point reads or writes, so that runtimes can bind the
+    /// data some targets need to emulate them, such as the `NumWorkgroups` buffer for HLSL or
+    /// the base vertex and instance for GLSL. Declared but unused builtins are left out.
+    pub fn required_builtins(&self) -> Result<BuiltInSet, ErrorCode> {
+        self.compiler.get_active_builtins()
+    }
+
     /// Gets shader resources.
     pub fn get_shader_resources(&self) -> Result<ShaderResources, ErrorCode> {
         self.compiler.get_shader_resources()
     }
 
+    /// Gets the shader resources which are statically used by the entry point, leaving out
+    /// declared but unused variables so descriptor set layouts can be kept minimal.
+    pub fn get_active_shader_resources(&self) -> Result<ShaderResources, ErrorCode> {
+        self.compiler.get_active_shader_resources()
+    }
+
+    /// Gets the IDs of the variables statically used by the entry point, in ascending order.
+    /// This includes inputs and outputs as well as buffers, images and samplers.
+    pub fn get_active_interface_variables(&self) -> Result<Vec<u32>, ErrorCode> {
+        self.compiler.get_active_interface_variables()
+    }
+
+    /// Restricts the inputs and outputs declared by `compile` to the variables `ids`, such as
+    /// those from `get_active_interface_variables`. Unused inputs and outputs are declared by
+    /// default.
+    pub fn set_enabled_interface_variables(&mut self, ids: &[u32]) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.set_enabled_interface_variables(ids)
+    }
+
+    /// Gets the descriptor-backed resources (buffers, images, samplers, subpass inputs and
+    /// acceleration structures) grouped by their `DescriptorSet` decoration. Resources without one are in set 0.
+    pub fn get_resources_by_descriptor_set(
+        &self,
+    ) -> Result<BTreeMap<u32, Vec<Resource>>, ErrorCode> {
+        self.compiler.get_resources_by_descriptor_set()
+    }
+
+    /// Gets the highest descriptor set used by the module, or `None` if no descriptors are used.
+    pub fn max_set(&self) -> Result<Option<u32>, ErrorCode> {
+        Ok(self
+            .compiler
+            .get_resources_by_descriptor_set()?
+            .keys()
+            .next_back()
+            .copied())
+    }
+
+    /// Gets the number of descriptor set layouts a pipeline layout needs for the module,
+    /// including empty sets below the highest one used.
+    pub fn descriptor_set_count(&self) -> Result<u32, ErrorCode> {
+        Ok(self.max_set()?.map_or(0, |set| set + 1))
+    }
+
+    /// Compares the stage outputs of this module with the stage inputs of `next`, the module of
+    /// the following stage, matching them by `Location` and `Component` decorations and type.
+    /// Matrices and arrays take a location per column or element, and the per-vertex array
+    /// dimension of tessellation and geometry shader variables is left out.
+    ///
+    /// Built-in variables aren't compared.
+    pub fn check_interface_compatibility<TNextTarget>(
+        &self,
+        next: &Ast<TNextTarget>,
+        options: &InterfaceMatchOptions,
+    ) -> Result<InterfaceCompatibility, ErrorCode>
+    where
+        TNextTarget: Target,
+    {
+        self.compiler
+            .check_interface_compatibility(&next.compiler, options)
+    }
+
+    /// Gets the sampled, separate and storage images and subpass inputs with their image
+    /// types, so that bindings such as `VK_IMAGE_VIEW_TYPE_CUBE` or `texturecube` can be
+    /// chosen from reflection. Images are ordered by kind, in the order of `ImageResourceKind`.
+    pub fn get_image_resources(&self) -> Result<Vec<ImageResource>, ErrorCode> {
+        self.compiler.get_image_resources()
+    }
+
+    /// Gets the image type of an image resource, from which a Vulkan descriptor type or Metal
+    /// texture type can be chosen. `resource` must be an image, such as one of the
+    /// `sampled_images`, `separate_images` or `storage_images`.
+    pub fn get_image_type(&self, resource: &Resource) -> Result<ImageType, ErrorCode> {
+        self.compiler.get_image_type(resource)
+    }
+
+    /// Gets the sampled, separate and storage images whose image type is multisampled.
+    pub fn get_multisampled_images(&self) -> Result<Vec<ImageResource>, ErrorCode> {
+        self.compiler.get_multisampled_images()
+    }
+
+    /// Gets a handle to `resource`, which must have been reflected from this `Ast`. Resources
+    /// are matched by `id`, so a resource reflected before it was renamed is still found.
+    pub fn get_resource_handle(&self, resource: &Resource) -> Result<ResourceHandle, ErrorCode> {
+        let resources = self.compiler.get_shader_resources()?;
+        let is_known = [
+            &resources.uniform_buffers,
+            &resources.storage_buffers,
+            &resources.stage_inputs,
+            &resources.stage_outputs,
+            &resources.subpass_inputs,
+            &resources.storage_images,
+            &resources.sampled_images,
+            &resources.atomic_counters,
+            &resources.push_constant_buffers,
+            &resources.separate_images,
+            &resources.separate_samplers,
+            &resources.acceleration_structures,
+            &resources.shader_record_buffers,
+        ]
+        .iter()
+        .any(|resources| resources.iter().any(|known| known.id == resource.id));
+        if !is_known {
+            return Err(ErrorCode::CompilationError(format!(
+                "`{}` is not a resource of this `Ast`",
+                resource.name
+            )));
+        }
+
+        Ok(ResourceHandle {
+            id: resource.id,
+            ast_tag: self.compiler.ast_tag,
+        })
+    }
+
+    fn resolve_handle(&self, handle: ResourceHandle) -> Result<u32, ErrorCode> {
+        if handle.ast_tag != self.compiler.ast_tag {
+            return Err(ErrorCode::CompilationError(String::from(
+                "resource handle belongs to a different `Ast`",
+            )));
+        }
+        Ok(handle.id)
+    }
+
+    /// Gets a decoration of the resource behind `handle`.
+    pub fn get_handle_decoration(
+        &self,
+        handle: ResourceHandle,
+        decoration: Decoration,
+    ) -> Result<u32, ErrorCode> {
+        let id = self.resolve_handle(handle)?;
+        self.get_decoration(id, decoration)
+    }
+
+    /// Sets a decoration of the resource behind `handle`.
+    pub fn set_handle_decoration(
+        &mut self,
+        handle: ResourceHandle,
+        decoration: Decoration,
+        argument: u32,
+    ) -> Result<(), ErrorCode> {
+        let id = self.resolve_handle(handle)?;
+        self.set_decoration(id, decoration, argument)
+    }
+
+    /// Unsets a decoration of the resource behind `handle`.
+    pub fn unset_handle_decoration(
+        &mut self,
+        handle: ResourceHandle,
+        decoration: Decoration,
+    ) -> Result<(), ErrorCode> {
+        let id = self.resolve_handle(handle)?;
+        self.unset_decoration(id, decoration)
+    }
+
+    /// Gets the name of the resource behind `handle`.
+    pub fn get_handle_name(&self, handle: ResourceHandle) -> Result<String, ErrorCode> {
+        let id = self.resolve_handle(handle)?;
+        self.get_name(id)
+    }
+
+    /// Renames the resource behind `handle`.
+    pub fn set_handle_name(&mut self, handle: ResourceHandle, name: &str) -> Result<(), ErrorCode> {
+        let id = self.resolve_handle(handle)?;
+        self.set_name(id, name)
+    }
+
     /// Gets the SPIR-V type associated with an ID.
     pub fn get_type(&self, id: u32) -> Result<Type, ErrorCode> {
         self.compiler.get_type(id)
     }
 
+    /// Gets the SPIR-V type associated with an ID, with its array dimensions, including
+    /// those sized by specialization constants, and its storage class if it is a pointer.
+    /// The `type_id` of a `Resource` is usually a pointer type.
+    pub fn get_type_details(&self, id: u32) -> Result<TypeDetails, ErrorCode> {
+        self.compiler.get_type_details(id)
+    }
+
+    /// Formats the type with the given identifier as a WGSL-style string, such as
+    /// `array<vec4<f32>, 16>` or `mat4x4<f32>`, which reads the same whichever target is used.
+    /// Structs are given by name, and runtime arrays as `array<T>`. Arrays sized by
+    /// specialization constants are given by the name of the constant, such as
+    /// `array<f32, COUNT>`. Images are given by their dimensionality, such as
+    /// `texture_depth_2d_array`, `texture_storage_3d` or `sampled_texture_cube`.
+    pub fn get_type_string(&self, id: u32) -> Result<String, ErrorCode> {
+        self.compiler.get_type_string(id)
+    }
+
+    /// Gets which layered rendering builtins the entry point writes, and whether it needs
+    /// support for writing them before the geometry stage.
+    pub fn get_layered_rendering_outputs(&self) -> Result<LayeredRenderingOutputs, ErrorCode> {
+        self.compiler.get_layered_rendering_outputs()
+    }
+
     /// Gets the identifier for a member located at `index` within an `OpTypeStruct`.
     pub fn get_member_name(&self, id: u32, index: u32) -> Result<String, ErrorCode> {
         self.compiler.get_member_name(id, index)
     }
 
+    /// Gets the identifier for a member located at `index` within an `OpTypeStruct`, shared
+    /// with a cache held by the `Ast`. Use `get_member_name` for an owned copy.
+    pub fn get_member_name_interned(&self, id: u32, index: u32) -> Result<Rc<str>, ErrorCode> {
+        self.compiler.get_member_name_interned(id, index)
+    }
+
+    /// Sets the identifier for a member located at `index` within an `OpTypeStruct`, such as a
+    /// buffer block, which is used by subsequent reflection and compilation.
+    pub fn set_member_name(&mut self, id: u32, index: u32, name: &str) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.name_cache.invalidate(id);
+        self.compiler.set_member_name(id, index, name)
+    }
+
+    /// Gets whether a member located at `index` within an `OpTypeStruct` has a decoration.
+    pub fn has_member_decoration(
+        &self,
+        id: u32,
+        index: u32,
+        decoration: Decoration,
+    ) -> Result<bool, ErrorCode> {
+        self.compiler.has_member_decoration(id, index, decoration)
+    }
+
     /// Gets a decoration for a member located at `index` within an `OpTypeStruct`.
     pub fn get_member_decoration(
         &self,
@@ -572,10 +2052,22 @@ where
         decoration: Decoration,
         argument: u32,
     ) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
         self.compiler
             .set_member_decoration(id, index, decoration, argument)
     }
 
+    /// Unsets a decoration for a member located at `index` within an `OpTypeStruct`.
+    pub fn unset_member_decoration(
+        &mut self,
+        id: u32,
+        index: u32,
+        decoration: Decoration,
+    ) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.unset_member_decoration(id, index, decoration)
+    }
+
     /// Gets the effective size of a buffer block.
     pub fn get_declared_struct_size(&self, id: u32) -> Result<u32, ErrorCode> {
         self.compiler.get_declared_struct_size(id)
@@ -586,6 +2078,23 @@ where
         self.compiler.get_declared_struct_member_size(id, index)
     }
 
+    /// Gets the offset, size and strides of a buffer block struct member, to compute the
+    /// layout of a buffer or validate a CPU-side struct against it.
+    pub fn get_struct_member_layout(
+        &self,
+        id: u32,
+        index: u32,
+    ) -> Result<StructMemberLayout, ErrorCode> {
+        self.compiler.get_struct_member_layout(id, index)
+    }
+
+    /// Gets the members of a buffer block struct in offset order, with padding fields named
+    /// `_padN` filling the gaps between them and up to the declared size. A `#[repr(C)]` struct
+    /// with a field per entry, padding as `[u8; size]`, has the same layout as the buffer.
+    pub fn get_padded_struct_layout(&self, id: u32) -> Result<Vec<StructField>, ErrorCode> {
+        self.compiler.get_padded_struct_layout(id)
+    }
+
     /// Renames an interface variable.
     pub fn rename_interface_variable(
         &mut self,
@@ -593,17 +2102,139 @@ where
         location: u32,
         name: &str,
     ) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.name_cache.clear();
         self.compiler
             .rename_interface_variable(resources, location, name)
     }
 
-    /// Gets work group size specialization constants.
+    /// Gets the specialization constants which control the work group size of a compute shader.
+    /// Dimensions without one have an `id` and `constant_id` of 0. The constants can be
+    /// overridden with `set_scalar_constant_u32` before compiling, to bake a local size into
+    /// targets without specialization.
     pub fn get_work_group_size_specialization_constants(
         &self,
     ) -> Result<WorkGroupSizeSpecializationConstants, ErrorCode> {
         self.compiler.get_work_group_size_specialization_constants()
     }
 
+    /// Gets the access decorations of the buffer variable `id`. A decoration is reported if it's
+    /// on the variable, or on every member of its block, as SPIR-V allows either.
+    pub fn get_buffer_block_flags(&self, id: u32) -> Result<BufferBlockFlags, ErrorCode> {
+        self.compiler.get_buffer_block_flags(id)
+    }
+
+    /// Gets the capabilities declared by the module.
+    ///
+    /// Useful for rejecting modules that rely on features a target cannot express (such as
+    /// integer dot products or cooperative matrices) before attempting to compile them.
+    pub fn get_declared_capabilities(&self) -> Result<Vec<Capability>, ErrorCode> {
+        self.compiler.get_declared_capabilities()
+    }
+
+    /// Gets the extensions declared by the module, i.e. `SPV_KHR_integer_dot_product`.
+    pub fn get_declared_extensions(&self) -> Result<Vec<String>, ErrorCode> {
+        self.compiler.get_declared_extensions()
+    }
+
+    /// Gets the geometry shader execution modes of the current entry point.
+    pub fn get_geometry_execution_modes(&self) -> Result<GeometryExecutionModes, ErrorCode> {
+        self.compiler.get_geometry_execution_modes()
+    }
+
+    /// Gets whether the current entry point writes depth, with its conservative depth layout
+    /// and whether it forces early fragment tests. Non-fragment shaders report the defaults.
+    pub fn get_fragment_depth_modes(&self) -> Result<FragmentDepthModes, ErrorCode> {
+        self.compiler.get_fragment_depth_modes()
+    }
+
+    /// Gets the clocks read anywhere in the module, for profiling shaders. Only GLSL has clock
+    /// intrinsics, so compiling such a module to HLSL or MSL fails with
+    /// `ErrorCode::CompilationError` naming `OpReadClockKHR`.
+    pub fn get_shader_clock_usage(&self) -> ShaderClockUsage {
+        self.compiler.shader_clock_usage
+    }
+
+    /// Gets every execution mode of the current entry point, ordered by their SPIR-V values.
+    /// Modes without their own `ExecutionMode` variant are reported as `ExecutionMode::Unknown`.
+    pub fn get_execution_modes(&self) -> Result<Vec<ExecutionModeInfo>, ErrorCode> {
+        self.compiler.get_execution_modes()
+    }
+
+    /// Gets an argument of an execution mode of the current entry point, such as the `y` size
+    /// (index 1) of `LocalSize` or the count of `OutputVertices`. Arguments SPIRV-Cross does not
+    /// keep read as 0.
+    pub fn get_execution_mode_argument(
+        &self,
+        mode: ExecutionMode,
+        index: u32,
+    ) -> Result<u32, ErrorCode> {
+        self.compiler.get_execution_mode_argument(mode, index)
+    }
+
+    /// Sets an execution mode of the current entry point with up to three `arguments`, replacing
+    /// the arguments of the mode if it's already set. Missing arguments are 0.
+    pub fn set_execution_mode(
+        &mut self,
+        mode: ExecutionMode,
+        arguments: &[u32],
+    ) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.set_execution_mode(mode, arguments)
+    }
+
+    /// Removes an execution mode from the current entry point.
+    pub fn unset_execution_mode(&mut self, mode: ExecutionMode) -> Result<(), ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.unset_execution_mode(mode)
+    }
+
+    /// Gets the stage outputs captured by transform feedback, ordered by buffer and offset, to
+    /// set up the varyings and buffer strides of transform feedback in GL.
+    pub fn get_transform_feedback_outputs(
+        &self,
+    ) -> Result<Vec<TransformFeedbackOutput>, ErrorCode> {
+        self.compiler.get_transform_feedback_outputs()
+    }
+
+    /// Gets the outputs of a mesh shader, split into per-vertex and per-primitive outputs.
+    ///
+    /// Primitive index builtins (i.e. `PrimitiveTriangleIndicesExt`) aren't decorated per
+    /// primitive, so they are reported as per-vertex outputs.
+    pub fn get_mesh_outputs(&self) -> Result<MeshOutputs, ErrorCode> {
+        self.compiler.get_mesh_outputs()
+    }
+
+    /// Gets the clip-space conversions applied by the last `compile`.
+    ///
+    /// Conversions requested through the target's `CompilerVertexOptions` only apply to vertex,
+    /// tessellation evaluation and geometry shaders, so stages of a pipeline can be checked for
+    /// matching conventions. `compile` must be called first.
+    pub fn get_clip_space_fixups(&self) -> Result<ClipSpaceFixups, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+        self.compiler.get_clip_space_fixups()
+    }
+
+    /// Shortens names and struct member names longer than `max_len` bytes, for targets whose
+    /// drivers reject long identifiers.
+    ///
+    /// A shortened name keeps as much of its prefix as fits, followed by `_` and a hash of the
+    /// full name, so results are stable across runs. Names which would clash with another are
+    /// hashed again until they are unique. Builtins and names reserved by GLSL, such as those
+    /// starting with `gl_`, are left as is. `max_len` must be at least 10. Returns the renames so
+    /// that names can be mapped back to the original ones.
+    pub fn limit_identifier_length(
+        &mut self,
+        max_len: usize,
+    ) -> Result<Vec<IdentifierRename>, ErrorCode> {
+        self.compiler.has_been_modified = true;
+        self.compiler.limit_identifier_length(max_len)
+    }
+
     /// Parses a module into `Ast`.
     pub fn parse(module: &Module) -> Result<Self, ErrorCode> {
         Parse::<TTarget>::parse(&module)
@@ -614,12 +2245,53 @@ where
         &mut self,
         options: &<Self as Compile<TTarget>>::CompilerOptions,
     ) -> Result<(), ErrorCode> {
-        Compile::<TTarget>::set_compiler_options(self, options)
+        // Options are applied again by every call, so don't count as modifications.
+        let has_been_modified = self.compiler.has_been_modified;
+        Compile::<TTarget>::set_compiler_options(self, options)?;
+        self.compiler.has_compiler_options = true;
+        self.compiler.has_been_modified = has_been_modified;
+        Ok(())
+    }
+
+    /// Gets how far the `Ast` has progressed from parsing to compilation.
+    pub fn state(&self) -> AstState {
+        if self.compiler.has_been_compiled {
+            AstState::Compiled
+        } else if self.compiler.has_compiler_options {
+            AstState::OptionsSet
+        } else {
+            AstState::Parsed
+        }
+    }
+
+    /// Parses the module again, discarding compiler options, renames, decorations and any other
+    /// changes made since `parse`. Handles created before the reset remain valid.
+    pub fn reset_to_parsed(&mut self) -> Result<(), ErrorCode> {
+        let mut ast = Self::parse(&Module::from_words(&self.compiler.module_words))?;
+        std::mem::swap(&mut ast.compiler.ast_tag, &mut self.compiler.ast_tag);
+        *self = ast;
+        Ok(())
     }
 
     /// Compiles an abstract syntax tree to a `String` in the specified `TTarget` language.
     pub fn compile(&mut self) -> Result<String, ErrorCode> {
+        let mut shader = String::new();
+        self.compile_into(&mut shader)?;
+        Ok(shader)
+    }
+
+    /// Compiles like `compile`, but into `shader`, replacing its contents. The allocation of
+    /// `shader` is reused, so tooling compiling many variants can keep a single buffer instead
+    /// of allocating a `String` for every shader. SPIRV-Cross's copy of the shader is kept
+    /// until its length is known, `shader` is grown to fit it at most once, and the source is
+    /// copied straight into it, so no other copy is made. On WebAssembly the shader is still
+    /// copied through a C string.
+    pub fn compile_into(&mut self, shader: &mut String) -> Result<(), ErrorCode> {
+        self.compiler.add_header_macros()?;
+        // Compilation may rename IDs which clash with reserved identifiers.
+        self.compiler.name_cache.clear();
+        Compile::<TTarget>::compile_into(self, shader)?;
         self.compiler.has_been_compiled = true;
-        Compile::<TTarget>::compile(self)
+        Ok(())
     }
 }
@@ -0,0 +1,185 @@
+use crate::spirv::{self, Decoration};
+use crate::ErrorCode;
+
+#[cfg(feature = "glsl")]
+use crate::glsl;
+#[cfg(all(feature = "hlsl", not(target_arch = "wasm32")))]
+use crate::hlsl;
+#[cfg(all(feature = "msl", not(target_arch = "wasm32")))]
+use crate::msl;
+
+/// The language and version to translate to with `translate`.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum TargetSpec {
+    /// GLSL, including the ES versions of `glsl::Version`.
+    #[cfg(feature = "glsl")]
+    Glsl { version: glsl::Version },
+    #[cfg(all(feature = "hlsl", not(target_arch = "wasm32")))]
+    Hlsl { shader_model: hlsl::ShaderModel },
+    #[cfg(all(feature = "msl", not(target_arch = "wasm32")))]
+    Msl {
+        version: msl::Version,
+        platform: msl::Platform,
+    },
+}
+
+/// An index a resource is declared with in translated source, for targets which don't keep
+/// the descriptor set and binding.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum TargetIndex {
+    /// An MSL `[[buffer(n)]]` index.
+    MslBuffer(u32),
+    /// An MSL `[[texture(n)]]` index.
+    MslTexture(u32),
+    /// An MSL `[[sampler(n)]]` index.
+    MslSampler(u32),
+    /// A HLSL register, such as `register(t1, space2)`, with its class as the letter `b`, `t`,
+    /// `u` or `s`.
+    HlslRegister {
+        class: char,
+        register: u32,
+        space: u32,
+    },
+}
+
+/// The descriptor set and binding of a resource.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct DescriptorBinding {
+    pub id: u32,
+    pub name: String,
+    pub desc_set: u32,
+    pub binding: u32,
+    /// The indices the resource is declared with in the translated source, i.e. both a
+    /// texture and a sampler index for a combined image sampler in MSL. Empty for GLSL, which
+    /// keeps the descriptor set and binding.
+    pub target_indices: Vec<TargetIndex>,
+}
+
+/// A translated shader and its reflection, from `translate`.
+#[derive(Clone, Debug)]
+pub struct Translation {
+    pub source: String,
+    pub entry_points: Vec<spirv::EntryPoint>,
+    pub resources: spirv::ShaderResources,
+    /// The descriptor bindings of every resource other than stage inputs, stage outputs and
+    /// push constant buffers, ordered by descriptor set and binding.
+    pub bindings: Vec<DescriptorBinding>,
+}
+
+/// Translates a SPIR-V module, given as little-endian bytes, with the default options of the
+/// target. Use `spirv::Ast` directly to set other options, or to choose the entry point.
+pub fn translate(spirv_bytes: &[u8], target: TargetSpec) -> Result<Translation, ErrorCode> {
+    let chunks = spirv_bytes.chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return Err(ErrorCode::CompilationError(String::from(
+            "SPIR-V must be a whole number of 32-bit words",
+        )));
+    }
+    let words = chunks
+        .map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+        .collect::<Vec<_>>();
+    let module = spirv::Module::from_words(&words);
+
+    match target {
+        #[cfg(feature = "glsl")]
+        TargetSpec::Glsl { version } => {
+            let options = glsl::CompilerOptions {
+                version,
+                ..Default::default()
+            };
+            translate_to::<glsl::Target>(&module, &options, |_, _| Ok(Vec::new()))
+        }
+        #[cfg(all(feature = "hlsl", not(target_arch = "wasm32")))]
+        TargetSpec::Hlsl { shader_model } => {
+            let options = hlsl::CompilerOptions {
+                shader_model,
+                ..Default::default()
+            };
+            translate_to::<hlsl::Target>(&module, &options, |ast, resource| {
+                Ok(ast
+                    .get_resource_registers()?
+                    .into_iter()
+                    .filter(|register| register.id == resource.id)
+                    .map(|register| TargetIndex::HlslRegister {
+                        class: match register.register_type {
+                            hlsl::RegisterType::ConstantBuffer => 'b',
+                            hlsl::RegisterType::ShaderResource => 't',
+                            hlsl::RegisterType::UnorderedAccess => 'u',
+                            hlsl::RegisterType::Sampler => 's',
+                        },
+                        register: register.register.register,
+                        space: register.register.space,
+                    })
+                    .collect())
+            })
+        }
+        #[cfg(all(feature = "msl", not(target_arch = "wasm32")))]
+        TargetSpec::Msl { version, platform } => {
+            let options = msl::CompilerOptions {
+                version,
+                platform,
+                ..Default::default()
+            };
+            translate_to::<msl::Target>(&module, &options, |ast, resource| {
+                let indices = ast.get_resource_indices(resource)?;
+                Ok(indices
+                    .buffer
+                    .map(TargetIndex::MslBuffer)
+                    .into_iter()
+                    .chain(indices.texture.map(TargetIndex::MslTexture))
+                    .chain(indices.sampler.map(TargetIndex::MslSampler))
+                    .collect())
+            })
+        }
+    }
+}
+
+fn translate_to<TTarget>(
+    module: &spirv::Module,
+    options: &<spirv::Ast<TTarget> as spirv::Compile<TTarget>>::CompilerOptions,
+    target_indices: impl Fn(
+        &spirv::Ast<TTarget>,
+        &spirv::Resource,
+    ) -> Result<Vec<TargetIndex>, ErrorCode>,
+) -> Result<Translation, ErrorCode>
+where
+    TTarget: spirv::Target,
+    spirv::Ast<TTarget>: spirv::Parse<TTarget> + spirv::Compile<TTarget>,
+{
+    let mut ast = spirv::Ast::<TTarget>::parse(module)?;
+    ast.set_compiler_options(options)?;
+    let source = ast.compile()?;
+    let resources = ast.get_shader_resources()?;
+
+    let mut bindings = Vec::new();
+    for resource in resources
+        .uniform_buffers
+        .iter()
+        .chain(&resources.storage_buffers)
+        .chain(&resources.subpass_inputs)
+        .chain(&resources.storage_images)
+        .chain(&resources.sampled_images)
+        .chain(&resources.atomic_counters)
+        .chain(&resources.separate_images)
+        .chain(&resources.separate_samplers)
+        .chain(&resources.acceleration_structures)
+    {
+        bindings.push(DescriptorBinding {
+            id: resource.id,
+            name: resource.name.clone(),
+            desc_set: ast.get_decoration(resource.id, Decoration::DescriptorSet)?,
+            binding: ast.get_decoration(resource.id, Decoration::Binding)?,
+            target_indices: target_indices(&ast, resource)?,
+        });
+    }
+    bindings.sort_by_key(|binding| (binding.desc_set, binding.binding, binding.id));
+
+    Ok(Translation {
+        source,
+        entry_points: ast.get_entry_points()?,
+        resources,
+        bindings,
+    })
+}
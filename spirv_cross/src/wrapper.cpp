@@ -7,7 +7,8 @@
 #include "vendor/SPIRV-Cross/spirv_glsl.hpp"
 #include "wrapper.hpp"
 
-static const char *latest_exception_message;
+// Per thread, as the message is read (and freed) by the thread whose call failed.
+static thread_local const char *latest_exception_message;
 
 #ifdef SPIRV_CROSS_WRAPPER_NO_EXCEPTIONS
 #define INTERNAL_RESULT(block_to_attempt)                 \
@@ -46,6 +47,21 @@ static const char *latest_exception_message;
     } while (0);
 #endif
 
+// `update_active_builtins` and `has_active_builtin` are protected, so they're called through
+// member pointers, which may be formed in a derived class.
+struct ScActiveBuiltins : spirv_cross::Compiler
+{
+    static void update(spirv_cross::Compiler &compiler)
+    {
+        (compiler.*(&ScActiveBuiltins::update_active_builtins))();
+    }
+
+    static bool has(const spirv_cross::Compiler &compiler, const spv::BuiltIn builtin, const spv::StorageClass storage)
+    {
+        return (compiler.*(&ScActiveBuiltins::has_active_builtin))(builtin, storage);
+    }
+};
+
 extern "C"
 {
     ScInternalResult sc_internal_get_latest_exception_message(const char **message)
@@ -68,6 +84,9 @@ extern "C"
                 glsl_options.vertex.fixup_clipspace = options->vertex_transform_clip_space;
                 glsl_options.vertex.flip_vert_y = options->vertex_invert_y;
                 glsl_options.force_zero_initialized_variables = options->force_zero_initialized_variables;
+                glsl_options.relax_nan_checks = options->relax_nan_checks;
+                glsl_options.emit_line_directives = options->emit_line_directives;
+                glsl_options.enable_storage_image_qualifier_deduction = options->enable_storage_image_qualifier_deduction;
                 compiler_glsl->set_common_options(glsl_options);
 
                 auto compiler_hlsl = (spirv_cross::CompilerHLSL *)compiler;
@@ -78,6 +97,11 @@ extern "C"
                 hlsl_options.force_storage_buffer_as_uav = options->force_storage_buffer_as_uav;
                 hlsl_options.nonwritable_uav_texture_as_srv = options->nonwritable_uav_texture_as_srv;
 
+                hlsl_options.support_nonzero_base_vertex_base_instance = options->support_nonzero_base_vertex_base_instance;
+                hlsl_options.enable_16bit_types = options->enable_16bit_types;
+                hlsl_options.flatten_matrix_vertex_input_semantics = options->flatten_matrix_vertex_input_semantics;
+                hlsl_options.use_entry_point_name = options->use_entry_point_name;
+                hlsl_options.preserve_structured_buffers = options->preserve_structured_buffers;
                 compiler_hlsl->set_hlsl_options(hlsl_options);
             } while (0);)
     }
@@ -101,6 +125,75 @@ extern "C"
                 compiler_hlsl->set_root_constant_layouts(root_constants);
             } while (0);)
     }
+
+    ScInternalResult sc_internal_compiler_hlsl_add_vertex_attribute_remap(const ScInternalCompilerHlsl *compiler, const uint32_t location, const char *semantic)
+    {
+        INTERNAL_RESULT(
+            do {
+                spirv_cross::HLSLVertexAttributeRemap remap = {location, semantic};
+                ((spirv_cross::CompilerHLSL *)compiler)->add_vertex_attribute_remap(remap);
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_hlsl_set_base_vertex_instance_binding(const ScInternalCompilerHlsl *compiler, const uint32_t register_index, const uint32_t register_space)
+    {
+        INTERNAL_RESULT(((spirv_cross::CompilerHLSL *)compiler)->set_hlsl_aux_buffer_binding(spirv_cross::HLSL_AUX_BINDING_BASE_VERTEX_INSTANCE, register_index, register_space);)
+    }
+
+    ScInternalResult sc_internal_compiler_hlsl_is_base_vertex_instance_binding_used(const ScInternalCompilerHlsl *compiler, bool *is_used)
+    {
+        INTERNAL_RESULT(*is_used = ((spirv_cross::CompilerHLSL *)compiler)->is_hlsl_aux_buffer_binding_used(spirv_cross::HLSL_AUX_BINDING_BASE_VERTEX_INSTANCE);)
+    }
+
+    ScInternalResult sc_internal_compiler_hlsl_add_resource_bindings(const ScInternalCompilerHlsl *compiler, const ScHlslResourceBinding *bindings, size_t count)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_hlsl = (spirv_cross::CompilerHLSL *)compiler;
+                for (size_t i = 0; i < count; i++)
+                {
+                    spirv_cross::HLSLResourceBinding binding;
+                    binding.stage = bindings[i].stage;
+                    binding.desc_set = bindings[i].desc_set;
+                    binding.binding = bindings[i].binding;
+                    binding.cbv.register_binding = bindings[i].cbv_register;
+                    binding.cbv.register_space = bindings[i].cbv_space;
+                    binding.uav.register_binding = bindings[i].uav_register;
+                    binding.uav.register_space = bindings[i].uav_space;
+                    binding.srv.register_binding = bindings[i].srv_register;
+                    binding.srv.register_space = bindings[i].srv_space;
+                    binding.sampler.register_binding = bindings[i].sampler_register;
+                    binding.sampler.register_space = bindings[i].sampler_space;
+                    compiler_hlsl->add_hlsl_resource_binding(binding);
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_hlsl_is_resource_binding_used(const ScInternalCompilerHlsl *compiler, const spv::ExecutionModel model, uint32_t desc_set, uint32_t binding, bool *is_used)
+    {
+        INTERNAL_RESULT(*is_used = ((spirv_cross::CompilerHLSL *)compiler)->is_hlsl_resource_binding_used(model, desc_set, binding);)
+    }
+
+    ScInternalResult sc_internal_compiler_hlsl_remap_num_workgroups_builtin(const ScInternalCompilerHlsl *compiler, uint32_t *id)
+    {
+        INTERNAL_RESULT(*id = ((spirv_cross::CompilerHLSL *)compiler)->remap_num_workgroups_builtin();)
+    }
+
+    ScInternalResult sc_internal_compiler_hlsl_buffer_is_counter_buffer(const ScInternalCompilerHlsl *compiler, const uint32_t id, bool *is_counter_buffer)
+    {
+        INTERNAL_RESULT(*is_counter_buffer = ((spirv_cross::CompilerHLSL *)compiler)->buffer_is_hlsl_counter_buffer(id);)
+    }
+
+    ScInternalResult sc_internal_compiler_hlsl_buffer_get_counter_buffer(const ScInternalCompilerHlsl *compiler, const uint32_t id, bool *has_counter_buffer, uint32_t *counter_id)
+    {
+        INTERNAL_RESULT(
+            do {
+                uint32_t counter = 0;
+                *has_counter_buffer = ((spirv_cross::CompilerHLSL *)compiler)->buffer_get_hlsl_counter_buffer(id, counter);
+                *counter_id = counter;
+            } while (0);)
+    }
+
 #endif
 
 #ifdef SPIRV_CROSS_WRAPPER_MSL
@@ -109,7 +202,7 @@ extern "C"
         INTERNAL_RESULT(*compiler = new spirv_cross::CompilerMSL(ir, size);)
     }
 
-    ScInternalResult sc_internal_compiler_msl_compile(const ScInternalCompilerBase *compiler, const char **shader,
+    ScInternalResult sc_internal_compiler_msl_compile(const ScInternalCompilerBase *compiler, ScInternalCompiledShader **shader, size_t *length,
                                                       const spirv_cross::MSLShaderInput *p_vat_overrides, const size_t vat_override_count,
                                                       const spirv_cross::MSLResourceBinding *p_res_overrides, const size_t res_override_count,
                                                       const ScMslConstSamplerMapping *p_const_samplers, const size_t const_sampler_count)
@@ -134,7 +227,9 @@ extern "C"
                     compiler_msl->remap_constexpr_sampler_by_binding(mapping.desc_set, mapping.binding, mapping.sampler);
                 }
 
-                *shader = strdup(compiler_msl->compile().c_str());
+                auto source = new std::string(compiler_msl->compile());
+                *length = source->size();
+                *shader = source;
             } while (0);)
     }
 
@@ -148,6 +243,9 @@ extern "C"
                 glsl_options.vertex.fixup_clipspace = options->vertex_transform_clip_space;
                 glsl_options.vertex.flip_vert_y = options->vertex_invert_y;
                 glsl_options.force_zero_initialized_variables = options->force_zero_initialized_variables;
+                glsl_options.relax_nan_checks = options->relax_nan_checks;
+                glsl_options.emit_line_directives = options->emit_line_directives;
+                glsl_options.enable_storage_image_qualifier_deduction = options->enable_storage_image_qualifier_deduction;
                 compiler_msl->set_common_options(glsl_options);
 
                 auto msl_options = compiler_msl->get_msl_options();
@@ -167,6 +265,15 @@ extern "C"
                 msl_options.argument_buffers = options->argument_buffers;
                 msl_options.pad_fragment_output_components = options->pad_fragment_output_components;
                 msl_options.force_native_arrays = options->force_native_arrays;
+                msl_options.check_discarded_frag_stores = options->check_discarded_frag_stores;
+                msl_options.readwrite_texture_fences = options->readwrite_texture_fences;
+                msl_options.texel_buffer_texture_width = options->texel_buffer_texture_width;
+                msl_options.pad_argument_buffer_resources = options->pad_argument_buffer_resources;
+                msl_options.enable_decoration_binding = options->enable_decoration_binding;
+                msl_options.arrayed_subpass_input = options->arrayed_subpass_input;
+                msl_options.ios_use_simdgroup_functions = options->ios_use_simdgroup_functions;
+                msl_options.use_framebuffer_fetch_subpasses = options->use_framebuffer_fetch_subpasses;
+                msl_options.runtime_array_rich_descriptor = options->runtime_array_rich_descriptor;
                 compiler_msl->set_msl_options(msl_options);
             } while (0);)
     }
@@ -175,6 +282,77 @@ extern "C"
     {
         INTERNAL_RESULT(*is_rasterization_disabled = ((spirv_cross::CompilerMSL *)compiler)->get_is_rasterization_disabled();)
     }
+
+    ScInternalResult sc_internal_compiler_msl_set_combined_sampler_suffix(const ScInternalCompilerMsl *compiler, const char *suffix)
+    {
+        INTERNAL_RESULT(((spirv_cross::CompilerMSL *)compiler)->set_combined_sampler_suffix(suffix);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_get_combined_sampler_suffix(const ScInternalCompilerMsl *compiler, const char **suffix)
+    {
+        INTERNAL_RESULT(*suffix = strdup(((spirv_cross::CompilerMSL *)compiler)->get_combined_sampler_suffix());)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_is_shader_input_used(const ScInternalCompilerMsl *compiler, uint32_t location, bool *is_used)
+    {
+        INTERNAL_RESULT(*is_used = ((spirv_cross::CompilerMSL *)compiler)->is_msl_shader_input_used(location);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_is_resource_binding_used(const ScInternalCompilerMsl *compiler, const spv::ExecutionModel model, uint32_t desc_set, uint32_t binding, bool *is_used)
+    {
+        INTERNAL_RESULT(*is_used = ((spirv_cross::CompilerMSL *)compiler)->is_msl_resource_binding_used(model, desc_set, binding);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_buffer_requires_array_length(const ScInternalCompilerMsl *compiler, uint32_t id, bool *requires_array_length)
+    {
+        INTERNAL_RESULT(*requires_array_length = ((spirv_cross::CompilerMSL *)compiler)->buffer_requires_array_length(id);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_get_resource_indices(const ScInternalCompilerMsl *compiler, uint32_t id, uint32_t *primary, uint32_t *secondary)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_msl = (const spirv_cross::CompilerMSL *)compiler;
+                *primary = compiler_msl->get_automatic_msl_resource_binding(id);
+                *secondary = compiler_msl->get_automatic_msl_resource_binding_secondary(id);
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_get_options_usage(const ScInternalCompilerMsl *compiler, ScMslCompilerOptionsUsage *usage)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_msl = (const spirv_cross::CompilerMSL *)compiler;
+                usage->swizzle_buffer_index = compiler_msl->needs_swizzle_buffer();
+                usage->buffer_size_buffer_index = compiler_msl->needs_buffer_size_buffer();
+                usage->output_buffer_index = compiler_msl->needs_output_buffer();
+                usage->patch_output_buffer_index = compiler_msl->needs_patch_output_buffer();
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_get_feature_requirements(const ScInternalCompilerMsl *compiler, ScMslFeatureRequirements *requirements)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_msl = (const spirv_cross::CompilerMSL *)compiler;
+                auto const &msl_options = compiler_msl->get_msl_options();
+                auto const resources = compiler_msl->get_shader_resources();
+                auto const execution_model = compiler_msl->get_execution_model();
+                auto const &capabilities = compiler_msl->get_declared_capabilities();
+
+                bool has_descriptors = !resources.uniform_buffers.empty() || !resources.storage_buffers.empty() ||
+                                       !resources.storage_images.empty() || !resources.sampled_images.empty() ||
+                                       !resources.separate_images.empty() || !resources.separate_samplers.empty();
+
+                requirements->argument_buffers = msl_options.argument_buffers && has_descriptors;
+                requirements->function_constants = !compiler_msl->get_specialization_constants().empty();
+                requirements->framebuffer_fetch = !resources.subpass_inputs.empty() &&
+                                                  (msl_options.is_ios() || msl_options.use_framebuffer_fetch_subpasses);
+                requirements->tessellation = execution_model == spv::ExecutionModelTessellationControl ||
+                                             execution_model == spv::ExecutionModelTessellationEvaluation;
+                requirements->ray_queries = std::find(capabilities.begin(), capabilities.end(), spv::CapabilityRayQueryKHR) != capabilities.end();
+            } while (0);)
+    }
 #endif
 
 #ifdef SPIRV_CROSS_WRAPPER_GLSL
@@ -201,6 +379,9 @@ extern "C"
                 glsl_options.emit_line_directives = options->emit_line_directives;
                 glsl_options.enable_storage_image_qualifier_deduction = options->enable_storage_image_qualifier_deduction;
                 glsl_options.force_zero_initialized_variables = options->force_zero_initialized_variables;
+                glsl_options.relax_nan_checks = options->relax_nan_checks;
+                glsl_options.ovr_multiview_view_count = options->ovr_multiview_view_count;
+                glsl_options.force_flattened_io_blocks = options->force_flattened_io_blocks;
                 glsl_options.vertex.fixup_clipspace = options->vertex_transform_clip_space;
                 glsl_options.vertex.flip_vert_y = options->vertex_invert_y;
                 glsl_options.vertex.support_nonzero_base_instance = options->vertex_support_nonzero_base_instance;
@@ -210,6 +391,11 @@ extern "C"
             } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_glsl_build_dummy_sampler_for_combined_images(const ScInternalCompilerBase *compiler, uint32_t *sampler_id)
+    {
+        INTERNAL_RESULT(*sampler_id = ((spirv_cross::CompilerGLSL *)compiler)->build_dummy_sampler_for_combined_images();)
+    }
+
     ScInternalResult sc_internal_compiler_glsl_build_combined_image_samplers(const ScInternalCompilerBase *compiler)
     {
         INTERNAL_RESULT(
@@ -228,11 +414,33 @@ extern "C"
             } while (0);)
     }
 
-    ScInternalResult sc_internal_compiler_glsl_add_header_line(const ScInternalCompilerBase *compiler, const char *str)
+    ScInternalResult sc_internal_compiler_glsl_require_extension(const ScInternalCompilerBase *compiler, const char *extension)
     {
         INTERNAL_RESULT(
             do {
-                ((spirv_cross::CompilerGLSL *)compiler)->add_header_line(std::string(str));
+                ((spirv_cross::CompilerGLSL *)compiler)->require_extension(std::string(extension));
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_glsl_remap_ext_framebuffer_fetch(const ScInternalCompilerBase *compiler, uint32_t input_attachment_index, uint32_t color_location, bool coherent)
+    {
+        INTERNAL_RESULT(((spirv_cross::CompilerGLSL *)compiler)->remap_ext_framebuffer_fetch(input_attachment_index, color_location, coherent);)
+    }
+
+    ScInternalResult sc_internal_compiler_glsl_set_variable_type_remaps(const ScInternalCompilerBase *compiler, const char *const *variable_names, const char *const *type_names, const size_t size)
+    {
+        INTERNAL_RESULT(
+            do {
+                std::unordered_map<std::string, std::string> remaps;
+                for (size_t i = 0; i < size; i++)
+                    remaps[variable_names[i]] = type_names[i];
+
+                ((spirv_cross::CompilerGLSL *)compiler)->set_variable_type_remap_callback(
+                    [remaps](const spirv_cross::SPIRType &, const std::string &var_name, std::string &name_of_type) {
+                        auto remap = remaps.find(var_name);
+                        if (remap != remaps.end())
+                            name_of_type = remap->second;
+                    });
             } while (0);)
     }
 
@@ -243,6 +451,11 @@ extern "C"
                 ((spirv_cross::CompilerGLSL *)compiler)->flatten_buffer_block(id);
             } while (0);)
     }
+
+    ScInternalResult sc_internal_compiler_glsl_is_base_instance_used(const ScInternalCompilerBase *compiler, bool *is_used)
+    {
+        INTERNAL_RESULT(*is_used = ScActiveBuiltins::has(*(spirv_cross::Compiler *)compiler, spv::BuiltInInstanceIndex, spv::StorageClassInput);)
+    }
 #endif
 
     ScInternalResult sc_internal_compiler_get_decoration(const ScInternalCompilerBase *compiler, uint32_t *result, const uint32_t id, const spv::Decoration decoration)
@@ -250,6 +463,11 @@ extern "C"
         INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->get_decoration(id, decoration);)
     }
 
+    ScInternalResult sc_internal_compiler_has_decoration(const ScInternalCompilerBase *compiler, bool *result, const uint32_t id, const spv::Decoration decoration)
+    {
+        INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->has_decoration(id, decoration);)
+    }
+
     ScInternalResult sc_internal_compiler_unset_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration)
     {
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->unset_decoration(id, decoration);)
@@ -274,6 +492,16 @@ extern "C"
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_name(id, std::string(name));)
     }
 
+    ScInternalResult sc_internal_compiler_get_decoration_string(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration, const char **argument)
+    {
+        INTERNAL_RESULT(*argument = strdup(((spirv_cross::Compiler *)compiler)->get_decoration_string(id, decoration).c_str());)
+    }
+
+    ScInternalResult sc_internal_compiler_set_decoration_string(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration, const char *argument)
+    {
+        INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_decoration_string(id, decoration, std::string(argument));)
+    }
+
     ScInternalResult sc_internal_compiler_get_entry_points(const ScInternalCompilerBase *compiler, ScEntryPoint **entry_points, size_t *size)
     {
         INTERNAL_RESULT(
@@ -353,23 +581,64 @@ extern "C"
         }
     }
 
+    void fill_shader_resources(ScShaderResources *shader_resources, const spirv_cross::ShaderResources &sc_resources)
+    {
+        fill_resource_array(&shader_resources->uniform_buffers, sc_resources.uniform_buffers);
+        fill_resource_array(&shader_resources->storage_buffers, sc_resources.storage_buffers);
+        fill_resource_array(&shader_resources->stage_inputs, sc_resources.stage_inputs);
+        fill_resource_array(&shader_resources->stage_outputs, sc_resources.stage_outputs);
+        fill_resource_array(&shader_resources->subpass_inputs, sc_resources.subpass_inputs);
+        fill_resource_array(&shader_resources->storage_images, sc_resources.storage_images);
+        fill_resource_array(&shader_resources->sampled_images, sc_resources.sampled_images);
+        fill_resource_array(&shader_resources->atomic_counters, sc_resources.atomic_counters);
+        fill_resource_array(&shader_resources->push_constant_buffers, sc_resources.push_constant_buffers);
+        fill_resource_array(&shader_resources->separate_images, sc_resources.separate_images);
+        fill_resource_array(&shader_resources->separate_samplers, sc_resources.separate_samplers);
+        fill_resource_array(&shader_resources->acceleration_structures, sc_resources.acceleration_structures);
+        fill_resource_array(&shader_resources->shader_record_buffers, sc_resources.shader_record_buffers);
+    }
+
     ScInternalResult sc_internal_compiler_get_shader_resources(const ScInternalCompilerBase *compiler, ScShaderResources *shader_resources)
+    {
+        INTERNAL_RESULT(fill_shader_resources(shader_resources, ((const spirv_cross::Compiler *)compiler)->get_shader_resources());)
+    }
+
+    ScInternalResult sc_internal_compiler_get_active_shader_resources(const ScInternalCompilerBase *compiler, ScShaderResources *shader_resources)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const comp = (const spirv_cross::Compiler *)compiler;
+                fill_shader_resources(shader_resources, comp->get_shader_resources(comp->get_active_interface_variables()));
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_active_interface_variables(const ScInternalCompilerBase *compiler, uint32_t **ids, size_t *size)
     {
         INTERNAL_RESULT(
             do {
-                auto const sc_resources = ((const spirv_cross::Compiler *)compiler)->get_shader_resources();
+                auto const sc_variables = ((const spirv_cross::Compiler *)compiler)->get_active_interface_variables();
+                auto const sc_size = sc_variables.size();
 
-                fill_resource_array(&shader_resources->uniform_buffers, sc_resources.uniform_buffers);
-                fill_resource_array(&shader_resources->storage_buffers, sc_resources.storage_buffers);
-                fill_resource_array(&shader_resources->stage_inputs, sc_resources.stage_inputs);
-                fill_resource_array(&shader_resources->stage_outputs, sc_resources.stage_outputs);
-                fill_resource_array(&shader_resources->subpass_inputs, sc_resources.subpass_inputs);
-                fill_resource_array(&shader_resources->storage_images, sc_resources.storage_images);
-                fill_resource_array(&shader_resources->sampled_images, sc_resources.sampled_images);
-                fill_resource_array(&shader_resources->atomic_counters, sc_resources.atomic_counters);
-                fill_resource_array(&shader_resources->push_constant_buffers, sc_resources.push_constant_buffers);
-                fill_resource_array(&shader_resources->separate_images, sc_resources.separate_images);
-                fill_resource_array(&shader_resources->separate_samplers, sc_resources.separate_samplers);
+                *ids = (uint32_t *)malloc(sc_size * sizeof(uint32_t));
+                *size = sc_size;
+                uint32_t i = 0;
+                for (auto const &sc_variable : sc_variables)
+                {
+                    (*ids)[i++] = sc_variable;
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_set_enabled_interface_variables(const ScInternalCompilerBase *compiler, const uint32_t *ids, const size_t size)
+    {
+        INTERNAL_RESULT(
+            do {
+                std::unordered_set<spirv_cross::VariableID> sc_variables;
+                for (size_t i = 0; i < size; i++)
+                {
+                    sc_variables.insert(ids[i]);
+                }
+                ((spirv_cross::Compiler *)compiler)->set_enabled_interface_variables(std::move(sc_variables));
             } while (0);)
     }
 
@@ -415,6 +684,9 @@ extern "C"
                 ty->columns = type.columns;
                 ty->member_types_size = member_types_size;
                 ty->array_size = array_size;
+                ty->pointer = type.pointer;
+                ty->storage = type.storage;
+                ty->parent_type = type.parent_type;
 
                 if (member_types_size > 0)
                 {
@@ -438,6 +710,15 @@ extern "C"
                     }
 
                     ty->array = array;
+
+                    auto const &array_size_literal = (bool *)malloc(array_size * sizeof(bool));
+
+                    for (size_t i = 0; i < array_size; i++)
+                    {
+                        array_size_literal[i] = type.array_size_literal[i];
+                    }
+
+                    ty->array_size_literal = array_size_literal;
                 }
 
                 *spirv_type = ty;
@@ -453,6 +734,16 @@ extern "C"
             } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_set_member_name(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const char *name)
+    {
+        INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_member_name(id, index, std::string(name));)
+    }
+
+    ScInternalResult sc_internal_compiler_has_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration, bool *result)
+    {
+        INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->has_member_decoration(id, index, decoration);)
+    }
+
     ScInternalResult sc_internal_compiler_get_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration, uint32_t *result)
     {
         INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->get_member_decoration(id, index, decoration);)
@@ -463,6 +754,11 @@ extern "C"
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_member_decoration(id, index, decoration, argument);)
     }
 
+    ScInternalResult sc_internal_compiler_unset_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration)
+    {
+        INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->unset_member_decoration(id, index, decoration);)
+    }
+
     ScInternalResult sc_internal_compiler_get_declared_struct_size(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t *result)
     {
         INTERNAL_RESULT(do {
@@ -521,6 +817,269 @@ extern "C"
         } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_get_buffer_block_flags(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t **decorations, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                std::vector<uint32_t> sc_decorations;
+                ((const spirv_cross::Compiler *)compiler)->get_buffer_block_flags(id).for_each_bit([&](uint32_t decoration) {
+                    sc_decorations.push_back(decoration);
+                });
+
+                *decorations = (uint32_t *)malloc(sc_decorations.size() * sizeof(uint32_t));
+                *size = sc_decorations.size();
+                for (uint32_t i = 0; i < sc_decorations.size(); i++)
+                {
+                    (*decorations)[i] = sc_decorations[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_declared_capabilities(const ScInternalCompilerBase *compiler, uint32_t **capabilities, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &sc_capabilities = ((const spirv_cross::Compiler *)compiler)->get_declared_capabilities();
+                auto const sc_size = sc_capabilities.size();
+
+                *capabilities = (uint32_t *)malloc(sc_size * sizeof(uint32_t));
+                *size = sc_size;
+                for (uint32_t i = 0; i < sc_size; i++)
+                {
+                    (*capabilities)[i] = sc_capabilities[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_declared_extensions(const ScInternalCompilerBase *compiler, const char ***extensions, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &sc_extensions = ((const spirv_cross::Compiler *)compiler)->get_declared_extensions();
+                auto const sc_size = sc_extensions.size();
+
+                *extensions = (const char **)malloc(sc_size * sizeof(char *));
+                *size = sc_size;
+                for (uint32_t i = 0; i < sc_size; i++)
+                {
+                    (*extensions)[i] = strdup(sc_extensions[i].c_str());
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_geometry_execution_modes(const ScInternalCompilerBase *compiler, ScGeometryExecutionModes *modes)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &comp = *((const spirv_cross::Compiler *)compiler);
+                auto const &sc_modes = comp.get_execution_mode_bitset();
+
+                modes->invocations = comp.get_execution_mode_argument(spv::ExecutionModeInvocations);
+                modes->max_vertices = comp.get_execution_mode_argument(spv::ExecutionModeOutputVertices);
+
+                modes->input_primitive = spv::ExecutionModeMax;
+                for (auto mode : {spv::ExecutionModeInputPoints, spv::ExecutionModeInputLines, spv::ExecutionModeInputLinesAdjacency,
+                                  spv::ExecutionModeTriangles, spv::ExecutionModeInputTrianglesAdjacency})
+                {
+                    if (sc_modes.get(mode))
+                        modes->input_primitive = mode;
+                }
+
+                modes->output_primitive = spv::ExecutionModeMax;
+                for (auto mode : {spv::ExecutionModeOutputPoints, spv::ExecutionModeOutputLineStrip, spv::ExecutionModeOutputTriangleStrip})
+                {
+                    if (sc_modes.get(mode))
+                        modes->output_primitive = mode;
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_depth_execution_modes(const ScInternalCompilerBase *compiler, ScDepthExecutionModes *modes)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &sc_modes = ((const spirv_cross::Compiler *)compiler)->get_execution_mode_bitset();
+
+                modes->conservative_depth = spv::ExecutionModeMax;
+                for (auto mode : {spv::ExecutionModeDepthGreater, spv::ExecutionModeDepthLess, spv::ExecutionModeDepthUnchanged})
+                {
+                    if (sc_modes.get(mode))
+                        modes->conservative_depth = mode;
+                }
+
+                modes->early_fragment_tests = sc_modes.get(spv::ExecutionModeEarlyFragmentTests);
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_execution_modes(const ScInternalCompilerBase *compiler, spv::ExecutionMode **modes, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                std::vector<spv::ExecutionMode> sc_modes;
+                ((const spirv_cross::Compiler *)compiler)->get_execution_mode_bitset().for_each_bit([&](uint32_t mode) {
+                    sc_modes.push_back(spv::ExecutionMode(mode));
+                });
+
+                *modes = (spv::ExecutionMode *)malloc(sc_modes.size() * sizeof(spv::ExecutionMode));
+                *size = sc_modes.size();
+                for (uint32_t i = 0; i < sc_modes.size(); i++)
+                {
+                    (*modes)[i] = sc_modes[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_execution_mode_argument(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode, const uint32_t index, uint32_t *result)
+    {
+        INTERNAL_RESULT(*result = ((const spirv_cross::Compiler *)compiler)->get_execution_mode_argument(mode, index);)
+    }
+
+    ScInternalResult sc_internal_compiler_set_execution_mode(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode, const uint32_t arg0, const uint32_t arg1, const uint32_t arg2)
+    {
+        INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_execution_mode(mode, arg0, arg1, arg2);)
+    }
+
+    ScInternalResult sc_internal_compiler_unset_execution_mode(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode)
+    {
+        INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->unset_execution_mode(mode);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_mesh_outputs(const ScInternalCompilerBase *compiler, ScMeshOutput **outputs, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &comp = *((const spirv_cross::Compiler *)compiler);
+                auto const sc_resources = comp.get_shader_resources();
+                std::vector<ScMeshOutput> sc_outputs;
+
+                for (auto const &sc_output : sc_resources.stage_outputs)
+                {
+                    ScMeshOutput output;
+                    output.id = sc_output.id;
+                    output.location = comp.has_decoration(sc_output.id, spv::DecorationLocation)
+                                          ? comp.get_decoration(sc_output.id, spv::DecorationLocation)
+                                          : ~0u;
+                    output.built_in = spv::BuiltInMax;
+                    output.per_primitive = comp.has_decoration(sc_output.id, spv::DecorationPerPrimitiveEXT);
+                    sc_outputs.push_back(output);
+                }
+
+                for (auto const &sc_output : sc_resources.builtin_outputs)
+                {
+                    ScMeshOutput output;
+                    output.id = sc_output.resource.id;
+                    output.location = ~0u;
+                    output.built_in = sc_output.builtin;
+                    output.per_primitive = comp.has_decoration(sc_output.resource.id, spv::DecorationPerPrimitiveEXT);
+
+                    // Builtins in gl_MeshPerPrimitiveEXT are decorated per member.
+                    auto const &type = comp.get_type(sc_output.resource.base_type_id);
+                    for (uint32_t i = 0; i < type.member_types.size(); i++)
+                    {
+                        if (comp.has_member_decoration(type.self, i, spv::DecorationBuiltIn) &&
+                            comp.get_member_decoration(type.self, i, spv::DecorationBuiltIn) == uint32_t(sc_output.builtin) &&
+                            comp.has_member_decoration(type.self, i, spv::DecorationPerPrimitiveEXT))
+                        {
+                            output.per_primitive = true;
+                        }
+                    }
+                    sc_outputs.push_back(output);
+                }
+
+                auto const sc_size = sc_outputs.size();
+                *outputs = (ScMeshOutput *)malloc(sc_size * sizeof(ScMeshOutput));
+                *size = sc_size;
+                for (uint32_t i = 0; i < sc_size; i++)
+                {
+                    (*outputs)[i] = sc_outputs[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_current_id_bound(const ScInternalCompilerBase *compiler, uint32_t *bound)
+    {
+        INTERNAL_RESULT(*bound = ((spirv_cross::Compiler *)compiler)->get_current_id_bound();)
+    }
+
+    ScInternalResult sc_internal_compiler_get_execution_model(const ScInternalCompilerBase *compiler, spv::ExecutionModel *execution_model)
+    {
+        INTERNAL_RESULT(*execution_model = ((spirv_cross::Compiler *)compiler)->get_execution_model();)
+    }
+
+    // HLSL and MSL compilers derive from the GLSL compiler, which holds the header lines.
+    ScInternalResult sc_internal_compiler_glsl_add_header_line(const ScInternalCompilerBase *compiler, const char *str)
+    {
+        INTERNAL_RESULT(
+            do {
+                ((spirv_cross::CompilerGLSL *)compiler)->add_header_line(std::string(str));
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_is_builtin_output_active(const ScInternalCompilerBase *compiler, const spv::BuiltIn builtin, bool *is_active)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto &compiler_base = *(spirv_cross::Compiler *)compiler;
+                ScActiveBuiltins::update(compiler_base);
+                *is_active = ScActiveBuiltins::has(compiler_base, builtin, spv::StorageClassOutput);
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_active_builtins(const ScInternalCompilerBase *compiler, uint32_t **builtins, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto &compiler_base = *(spirv_cross::Compiler *)compiler;
+                ScActiveBuiltins::update(compiler_base);
+                auto const resources = compiler_base.get_shader_resources();
+
+                std::vector<uint32_t> sc_builtins;
+                for (auto const &resource : resources.builtin_inputs)
+                {
+                    if (ScActiveBuiltins::has(compiler_base, resource.builtin, spv::StorageClassInput))
+                    {
+                        sc_builtins.push_back(resource.builtin);
+                    }
+                }
+                for (auto const &resource : resources.builtin_outputs)
+                {
+                    if (ScActiveBuiltins::has(compiler_base, resource.builtin, spv::StorageClassOutput))
+                    {
+                        sc_builtins.push_back(resource.builtin);
+                    }
+                }
+
+                *builtins = (uint32_t *)malloc(sc_builtins.size() * sizeof(uint32_t));
+                *size = sc_builtins.size();
+                for (uint32_t i = 0; i < sc_builtins.size(); i++)
+                {
+                    (*builtins)[i] = sc_builtins[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_type_self(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t *self_id)
+    {
+        INTERNAL_RESULT(*self_id = ((spirv_cross::Compiler *)compiler)->get_type(id).self;)
+    }
+
+    ScInternalResult sc_internal_compiler_get_image_type(const ScInternalCompilerBase *compiler, const uint32_t id, ScImageType *image_type)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &type = ((spirv_cross::Compiler *)compiler)->get_type(id);
+                auto const is_image = type.basetype == spirv_cross::SPIRType::Image || type.basetype == spirv_cross::SPIRType::SampledImage;
+                image_type->arrayed = is_image && type.image.arrayed;
+                image_type->multisampled = is_image && type.image.ms;
+                image_type->dim = is_image ? type.image.dim : spv::DimMax;
+                image_type->depth = is_image && type.image.depth;
+                // Subpass inputs are also declared with `Sampled` 2, but aren't storage images.
+                image_type->storage = type.basetype == spirv_cross::SPIRType::Image && type.image.sampled == 2 && type.image.dim != spv::DimSubpassData;
+                image_type->format = is_image ? type.image.format : spv::ImageFormatUnknown;
+                image_type->access = is_image ? type.image.access : spv::AccessQualifierMax;
+            } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_set_entry_point(const ScInternalCompilerBase *compiler, const char *name, const spv::ExecutionModel execution_model)
     {
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_entry_point(name, execution_model);)
@@ -531,6 +1090,32 @@ extern "C"
         INTERNAL_RESULT(*shader = strdup(((spirv_cross::Compiler *)compiler)->compile().c_str());)
     }
 
+    // Keeps the compiled source, so its length can be read before it's copied into a buffer of
+    // the caller's, without an intermediate copy.
+    ScInternalResult sc_internal_compiler_compile_retained(const ScInternalCompilerBase *compiler, ScInternalCompiledShader **shader, size_t *length)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto source = new std::string(((spirv_cross::Compiler *)compiler)->compile());
+                *length = source->size();
+                *shader = source;
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiled_shader_copy(const ScInternalCompiledShader *shader, char *buffer)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto source = (const std::string *)shader;
+                memcpy(buffer, source->data(), source->size());
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiled_shader_delete(ScInternalCompiledShader *shader)
+    {
+        INTERNAL_RESULT(delete (std::string *)shader;)
+    }
+
     ScInternalResult sc_internal_compiler_delete(ScInternalCompilerBase *compiler)
     {
         INTERNAL_RESULT(delete (spirv_cross::Compiler *)compiler;)
@@ -8,6 +8,7 @@ typedef void ScInternalCompilerBase;
 typedef void ScInternalCompilerHlsl;
 typedef void ScInternalCompilerMsl;
 typedef void ScInternalCompilerGlsl;
+typedef void ScInternalCompiledShader;
 
 extern "C"
 {
@@ -27,6 +28,39 @@ extern "C"
         uint32_t work_group_size_z;
     } ScEntryPoint;
 
+    typedef struct ScGeometryExecutionModes
+    {
+        uint32_t invocations;
+        uint32_t max_vertices;
+        spv::ExecutionMode input_primitive;
+        spv::ExecutionMode output_primitive;
+    } ScGeometryExecutionModes;
+
+    typedef struct ScDepthExecutionModes
+    {
+        spv::ExecutionMode conservative_depth;
+        bool early_fragment_tests;
+    } ScDepthExecutionModes;
+
+    typedef struct ScImageType
+    {
+        bool arrayed;
+        bool multisampled;
+        spv::Dim dim;
+        bool depth;
+        bool storage;
+        spv::ImageFormat format;
+        spv::AccessQualifier access;
+    } ScImageType;
+
+    typedef struct ScMeshOutput
+    {
+        uint32_t id;
+        uint32_t location;
+        spv::BuiltIn built_in;
+        bool per_primitive;
+    } ScMeshOutput;
+
     typedef struct ScBufferRange
     {
         unsigned index;
@@ -49,6 +83,21 @@ extern "C"
         uint32_t space;
     } ScHlslRootConstant;
 
+    typedef struct ScHlslResourceBinding
+    {
+        spv::ExecutionModel stage;
+        uint32_t desc_set;
+        uint32_t binding;
+        uint32_t cbv_register;
+        uint32_t cbv_space;
+        uint32_t uav_register;
+        uint32_t uav_space;
+        uint32_t srv_register;
+        uint32_t srv_space;
+        uint32_t sampler_register;
+        uint32_t sampler_space;
+    } ScHlslResourceBinding;
+
     typedef struct ScHlslCompilerOptions
     {
         int32_t shader_model;
@@ -59,6 +108,14 @@ extern "C"
         bool force_storage_buffer_as_uav;
         bool nonwritable_uav_texture_as_srv;
         bool force_zero_initialized_variables;
+        bool enable_storage_image_qualifier_deduction;
+        bool support_nonzero_base_vertex_base_instance;
+        bool enable_16bit_types;
+        bool flatten_matrix_vertex_input_semantics;
+        bool use_entry_point_name;
+        bool preserve_structured_buffers;
+        bool relax_nan_checks;
+        bool emit_line_directives;
     } ScHlslCompilerOptions;
 
     typedef struct ScMslCompilerOptions
@@ -82,8 +139,37 @@ extern "C"
         bool pad_fragment_output_components;
         bool force_native_arrays;
         bool force_zero_initialized_variables;
+        bool check_discarded_frag_stores;
+        bool readwrite_texture_fences;
+        bool enable_storage_image_qualifier_deduction;
+        uint32_t texel_buffer_texture_width;
+        bool pad_argument_buffer_resources;
+        bool enable_decoration_binding;
+        bool arrayed_subpass_input;
+        bool ios_use_simdgroup_functions;
+        bool use_framebuffer_fetch_subpasses;
+        bool relax_nan_checks;
+        bool emit_line_directives;
+        bool runtime_array_rich_descriptor;
     } ScMslCompilerOptions;
 
+    typedef struct ScMslCompilerOptionsUsage
+    {
+        bool swizzle_buffer_index;
+        bool buffer_size_buffer_index;
+        bool output_buffer_index;
+        bool patch_output_buffer_index;
+    } ScMslCompilerOptionsUsage;
+
+    typedef struct ScMslFeatureRequirements
+    {
+        bool argument_buffers;
+        bool function_constants;
+        bool framebuffer_fetch;
+        bool tessellation;
+        bool ray_queries;
+    } ScMslFeatureRequirements;
+
     typedef struct ScGlslCompilerOptions
     {
         bool vertex_transform_clip_space;
@@ -103,6 +189,9 @@ extern "C"
         bool emit_line_directives;
         bool enable_storage_image_qualifier_deduction;
         bool force_zero_initialized_variables;
+        uint32_t ovr_multiview_view_count;
+        bool force_flattened_io_blocks;
+        bool relax_nan_checks;
     } ScGlslCompilerOptions;
 
     typedef struct ScResource
@@ -132,6 +221,8 @@ extern "C"
         ScResourceArray push_constant_buffers;
         ScResourceArray separate_images;
         ScResourceArray separate_samplers;
+        ScResourceArray acceleration_structures;
+        ScResourceArray shader_record_buffers;
     } ScShaderResources;
 
     typedef struct ScSpecializationConstant
@@ -149,6 +240,10 @@ extern "C"
         size_t member_types_size;
         uint32_t *array;
         size_t array_size;
+        bool *array_size_literal;
+        bool pointer;
+        spv::StorageClass storage;
+        uint32_t parent_type;
     } ScType;
 
     ScInternalResult sc_internal_get_latest_exception_message(const char **message);
@@ -157,6 +252,14 @@ extern "C"
     ScInternalResult sc_internal_compiler_hlsl_new(ScInternalCompilerHlsl **compiler, const uint32_t *ir, const size_t size);
     ScInternalResult sc_internal_compiler_hlsl_set_options(const ScInternalCompilerHlsl *compiler, const ScHlslCompilerOptions *options);
     ScInternalResult sc_internal_compiler_hlsl_set_root_constant_layout(const ScInternalCompilerHlsl *compiler, const ScHlslRootConstant *constants, size_t count);
+    ScInternalResult sc_internal_compiler_hlsl_add_vertex_attribute_remap(const ScInternalCompilerHlsl *compiler, const uint32_t location, const char *semantic);
+    ScInternalResult sc_internal_compiler_hlsl_set_base_vertex_instance_binding(const ScInternalCompilerHlsl *compiler, const uint32_t register_index, const uint32_t register_space);
+    ScInternalResult sc_internal_compiler_hlsl_is_base_vertex_instance_binding_used(const ScInternalCompilerHlsl *compiler, bool *is_used);
+    ScInternalResult sc_internal_compiler_hlsl_add_resource_bindings(const ScInternalCompilerHlsl *compiler, const ScHlslResourceBinding *bindings, size_t count);
+    ScInternalResult sc_internal_compiler_hlsl_is_resource_binding_used(const ScInternalCompilerHlsl *compiler, const spv::ExecutionModel model, uint32_t desc_set, uint32_t binding, bool *is_used);
+    ScInternalResult sc_internal_compiler_hlsl_remap_num_workgroups_builtin(const ScInternalCompilerHlsl *compiler, uint32_t *id);
+    ScInternalResult sc_internal_compiler_hlsl_buffer_is_counter_buffer(const ScInternalCompilerHlsl *compiler, const uint32_t id, bool *is_counter_buffer);
+    ScInternalResult sc_internal_compiler_hlsl_buffer_get_counter_buffer(const ScInternalCompilerHlsl *compiler, const uint32_t id, bool *has_counter_buffer, uint32_t *counter_id);
 #endif
 
 #ifdef SPIRV_CROSS_WRAPPER_MSL
@@ -169,7 +272,15 @@ extern "C"
     ScInternalResult sc_internal_compiler_msl_new(ScInternalCompilerMsl **compiler, const uint32_t *ir, const size_t size);
     ScInternalResult sc_internal_compiler_msl_set_options(const ScInternalCompilerMsl *compiler, const ScMslCompilerOptions *options);
     ScInternalResult sc_internal_compiler_msl_get_is_rasterization_disabled(const ScInternalCompilerMsl *compiler, bool *is_rasterization_disabled);
-    ScInternalResult sc_internal_compiler_msl_compile(const ScInternalCompilerBase *compiler, const char **shader,
+    ScInternalResult sc_internal_compiler_msl_set_combined_sampler_suffix(const ScInternalCompilerMsl *compiler, const char *suffix);
+    ScInternalResult sc_internal_compiler_msl_get_combined_sampler_suffix(const ScInternalCompilerMsl *compiler, const char **suffix);
+    ScInternalResult sc_internal_compiler_msl_is_shader_input_used(const ScInternalCompilerMsl *compiler, uint32_t location, bool *is_used);
+    ScInternalResult sc_internal_compiler_msl_is_resource_binding_used(const ScInternalCompilerMsl *compiler, const spv::ExecutionModel model, uint32_t desc_set, uint32_t binding, bool *is_used);
+    ScInternalResult sc_internal_compiler_msl_buffer_requires_array_length(const ScInternalCompilerMsl *compiler, uint32_t id, bool *requires_array_length);
+    ScInternalResult sc_internal_compiler_msl_get_resource_indices(const ScInternalCompilerMsl *compiler, uint32_t id, uint32_t *primary, uint32_t *secondary);
+    ScInternalResult sc_internal_compiler_msl_get_options_usage(const ScInternalCompilerMsl *compiler, ScMslCompilerOptionsUsage *usage);
+    ScInternalResult sc_internal_compiler_msl_get_feature_requirements(const ScInternalCompilerMsl *compiler, ScMslFeatureRequirements *requirements);
+    ScInternalResult sc_internal_compiler_msl_compile(const ScInternalCompilerBase *compiler, ScInternalCompiledShader **shader, size_t *length,
                                                       const spirv_cross::MSLShaderInput *p_vat_overrides, const size_t vat_override_count,
                                                       const spirv_cross::MSLResourceBinding *p_res_overrides, const size_t res_override_count,
                                                       const ScMslConstSamplerMapping *p_const_samplers, const size_t const_sampler_count);
@@ -178,34 +289,67 @@ extern "C"
 #ifdef SPIRV_CROSS_WRAPPER_GLSL
     ScInternalResult sc_internal_compiler_glsl_new(ScInternalCompilerGlsl **compiler, const uint32_t *ir, const size_t size);
     ScInternalResult sc_internal_compiler_glsl_set_options(const ScInternalCompilerGlsl *compiler, const ScGlslCompilerOptions *options);
+    ScInternalResult sc_internal_compiler_glsl_build_dummy_sampler_for_combined_images(const ScInternalCompilerBase *compiler, uint32_t *sampler_id);
     ScInternalResult sc_internal_compiler_glsl_build_combined_image_samplers(const ScInternalCompilerBase *compiler);
     ScInternalResult sc_internal_compiler_glsl_get_combined_image_samplers(const ScInternalCompilerBase *compiler, const ScCombinedImageSampler **samplers, size_t *size);
-    ScInternalResult sc_internal_compiler_glsl_add_header_line(const ScInternalCompilerBase *compiler, const char *str);
+    ScInternalResult sc_internal_compiler_glsl_require_extension(const ScInternalCompilerBase *compiler, const char *extension);
+    ScInternalResult sc_internal_compiler_glsl_remap_ext_framebuffer_fetch(const ScInternalCompilerBase *compiler, uint32_t input_attachment_index, uint32_t color_location, bool coherent);
+    ScInternalResult sc_internal_compiler_glsl_set_variable_type_remaps(const ScInternalCompilerBase *compiler, const char *const *variable_names, const char *const *type_names, const size_t size);
     ScInternalResult sc_internal_compiler_glsl_flatten_buffer_block(const ScInternalCompilerBase *compiler, const uint32_t id);
+    ScInternalResult sc_internal_compiler_glsl_is_base_instance_used(const ScInternalCompilerBase *compiler, bool *is_used);
 #endif
 
     ScInternalResult sc_internal_compiler_get_decoration(const ScInternalCompilerBase *compiler, uint32_t *result, const uint32_t id, const spv::Decoration decoration);
     ScInternalResult sc_internal_compiler_set_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration, const uint32_t argument);
+    ScInternalResult sc_internal_compiler_has_decoration(const ScInternalCompilerBase *compiler, bool *result, const uint32_t id, const spv::Decoration decoration);
     ScInternalResult sc_internal_compiler_unset_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration);
     ScInternalResult sc_internal_compiler_get_name(const ScInternalCompilerBase *compiler, const uint32_t id, const char **name);
     ScInternalResult sc_internal_compiler_set_name(const ScInternalCompilerBase *compiler, const uint32_t id, const char *name);
+    ScInternalResult sc_internal_compiler_get_decoration_string(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration, const char **argument);
+    ScInternalResult sc_internal_compiler_set_decoration_string(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration, const char *argument);
     ScInternalResult sc_internal_compiler_get_entry_points(const ScInternalCompilerBase *compiler, ScEntryPoint **entry_points, size_t *size);
     ScInternalResult sc_internal_compiler_get_active_buffer_ranges(const ScInternalCompilerBase *compiler, uint32_t id, ScBufferRange **active_buffer_ranges, size_t *size);
     ScInternalResult sc_internal_compiler_get_cleansed_entry_point_name(const ScInternalCompilerBase *compiler, const char *original_entry_point_name, const spv::ExecutionModel execution_model, const char **compiled_entry_point_name);
     ScInternalResult sc_internal_compiler_get_shader_resources(const ScInternalCompilerBase *compiler, ScShaderResources *shader_resources);
+    ScInternalResult sc_internal_compiler_get_active_shader_resources(const ScInternalCompilerBase *compiler, ScShaderResources *shader_resources);
+    ScInternalResult sc_internal_compiler_get_active_interface_variables(const ScInternalCompilerBase *compiler, uint32_t **ids, size_t *size);
+    ScInternalResult sc_internal_compiler_set_enabled_interface_variables(const ScInternalCompilerBase *compiler, const uint32_t *ids, const size_t size);
     ScInternalResult sc_internal_compiler_get_specialization_constants(const ScInternalCompilerBase *compiler, ScSpecializationConstant **constants, size_t *size);
     // `uint64_t` isn't supported in Emscripten without implicitly splitting the value into two `uint32_t` - instead do it explicitly
     ScInternalResult sc_internal_compiler_set_scalar_constant(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t constant_high_bits, const uint32_t constant_low_bits);
     ScInternalResult sc_internal_compiler_get_type(const ScInternalCompilerBase *compiler, const uint32_t id, const ScType **spirv_type);
     ScInternalResult sc_internal_compiler_get_member_name(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const char **name);
+    ScInternalResult sc_internal_compiler_set_member_name(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const char *name);
+    ScInternalResult sc_internal_compiler_has_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration, bool *result);
     ScInternalResult sc_internal_compiler_get_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration, uint32_t *result);
     ScInternalResult sc_internal_compiler_set_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration, const uint32_t argument);
+    ScInternalResult sc_internal_compiler_unset_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration);
     ScInternalResult sc_internal_compiler_get_declared_struct_size(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t *result);
     ScInternalResult sc_internal_compiler_get_declared_struct_member_size(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, uint32_t *result);
     ScInternalResult sc_internal_compiler_rename_interface_variable(const ScInternalCompilerBase *compiler, const ScResource *resources, const size_t resources_size, uint32_t location, const char *name);
     ScInternalResult sc_internal_compiler_get_work_group_size_specialization_constants(const ScInternalCompilerBase *compiler, ScSpecializationConstant **constants);
+    ScInternalResult sc_internal_compiler_get_buffer_block_flags(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t **decorations, size_t *size);
+    ScInternalResult sc_internal_compiler_get_declared_capabilities(const ScInternalCompilerBase *compiler, uint32_t **capabilities, size_t *size);
+    ScInternalResult sc_internal_compiler_get_declared_extensions(const ScInternalCompilerBase *compiler, const char ***extensions, size_t *size);
+    ScInternalResult sc_internal_compiler_get_geometry_execution_modes(const ScInternalCompilerBase *compiler, ScGeometryExecutionModes *modes);
+    ScInternalResult sc_internal_compiler_get_depth_execution_modes(const ScInternalCompilerBase *compiler, ScDepthExecutionModes *modes);
+    ScInternalResult sc_internal_compiler_get_execution_modes(const ScInternalCompilerBase *compiler, spv::ExecutionMode **modes, size_t *size);
+    ScInternalResult sc_internal_compiler_get_execution_mode_argument(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode, const uint32_t index, uint32_t *result);
+    ScInternalResult sc_internal_compiler_set_execution_mode(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode, const uint32_t arg0, const uint32_t arg1, const uint32_t arg2);
+    ScInternalResult sc_internal_compiler_unset_execution_mode(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode);
+    ScInternalResult sc_internal_compiler_get_mesh_outputs(const ScInternalCompilerBase *compiler, ScMeshOutput **outputs, size_t *size);
+    ScInternalResult sc_internal_compiler_get_current_id_bound(const ScInternalCompilerBase *compiler, uint32_t *bound);
+    ScInternalResult sc_internal_compiler_get_execution_model(const ScInternalCompilerBase *compiler, spv::ExecutionModel *execution_model);
+    ScInternalResult sc_internal_compiler_glsl_add_header_line(const ScInternalCompilerBase *compiler, const char *str);
+    ScInternalResult sc_internal_compiler_is_builtin_output_active(const ScInternalCompilerBase *compiler, const spv::BuiltIn builtin, bool *is_active);
+    ScInternalResult sc_internal_compiler_get_active_builtins(const ScInternalCompilerBase *compiler, uint32_t **builtins, size_t *size);
+    ScInternalResult sc_internal_compiler_get_type_self(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t *self_id);
+    ScInternalResult sc_internal_compiler_get_image_type(const ScInternalCompilerBase *compiler, const uint32_t id, ScImageType *image_type);
     ScInternalResult sc_internal_compiler_set_entry_point(const ScInternalCompilerBase *compiler, const char *name, const spv::ExecutionModel execution_model);
     ScInternalResult sc_internal_compiler_compile(const ScInternalCompilerBase *compiler, const char **shader);
+    ScInternalResult sc_internal_compiler_compile_retained(const ScInternalCompilerBase *compiler, ScInternalCompiledShader **shader, size_t *length);
+    ScInternalResult sc_internal_compiled_shader_copy(const ScInternalCompiledShader *shader, char *buffer);
+    ScInternalResult sc_internal_compiled_shader_delete(ScInternalCompiledShader *shader);
     ScInternalResult sc_internal_compiler_delete(ScInternalCompilerBase *compiler);
 
     ScInternalResult sc_internal_free_pointer(void *pointer);
@@ -0,0 +1,109 @@
+//! Compares the output of the bindings with the `spirv-cross` command line tool built from
+//! the vendored SPIRV-Cross, to catch options which are not passed through to the backends.
+//! The tests are ignored by default, and need `SPIRV_CROSS_CLI` set to the path of the tool
+//! when run with `--ignored`.
+#![cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+
+use spirv_cross::spirv;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn shader_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("shaders")
+        .join(name)
+}
+
+fn cli_output(shader: &str, args: &[&str]) -> String {
+    let cli = std::env::var_os("SPIRV_CROSS_CLI")
+        .expect("SPIRV_CROSS_CLI must be set to the path of the SPIRV-Cross command line tool");
+
+    let output = Command::new(cli)
+        .arg(shader_path(shader))
+        .args(args)
+        .output()
+        .expect("failed to run the SPIRV-Cross command line tool");
+    assert!(
+        output.status.success(),
+        "SPIRV-Cross command line tool failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn compare_with_cli<TTarget>(
+    shader: &str,
+    options: &<spirv::Ast<TTarget> as spirv::Compile<TTarget>>::CompilerOptions,
+    args: &[&str],
+) where
+    TTarget: spirv::Target,
+    spirv::Ast<TTarget>: spirv::Parse<TTarget> + spirv::Compile<TTarget>,
+{
+    let expected = cli_output(shader, args);
+
+    let bytes = std::fs::read(shader_path(shader)).unwrap();
+    let words = bytes
+        .chunks_exact(4)
+        .map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+        .collect::<Vec<_>>();
+    let module = spirv::Module::from_words(&words);
+    let mut ast = spirv::Ast::<TTarget>::parse(&module).unwrap();
+    ast.set_compiler_options(options).unwrap();
+
+    assert_eq!(ast.compile().unwrap().trim_end(), expected.trim_end());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+#[ignore = "requires SPIRV_CROSS_CLI"]
+fn glsl_matches_cli() {
+    use spirv_cross::glsl;
+
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_10Es;
+    options.vertex.invert_y = true;
+    options.vertex.transform_clip_space = true;
+    compare_with_cli::<glsl::Target>(
+        "simple.vert.spv",
+        &options,
+        &[
+            "--version",
+            "310",
+            "--es",
+            "--flip-vert-y",
+            "--fixup-clipspace",
+        ],
+    );
+}
+
+#[cfg(feature = "hlsl")]
+#[test]
+#[ignore = "requires SPIRV_CROSS_CLI"]
+fn hlsl_matches_cli() {
+    use spirv_cross::hlsl;
+
+    let mut options = hlsl::CompilerOptions::default();
+    options.shader_model = hlsl::ShaderModel::V5_0;
+    compare_with_cli::<hlsl::Target>(
+        "simple.vert.spv",
+        &options,
+        &["--hlsl", "--shader-model", "50"],
+    );
+}
+
+#[cfg(feature = "msl")]
+#[test]
+#[ignore = "requires SPIRV_CROSS_CLI"]
+fn msl_matches_cli() {
+    use spirv_cross::msl;
+
+    let mut options = msl::CompilerOptions::default();
+    options.version = msl::Version::V2_0;
+    options.vertex.invert_y = true;
+    compare_with_cli::<msl::Target>(
+        "simple.vert.spv",
+        &options,
+        &["--msl", "--msl-version", "20000", "--flip-vert-y"],
+    );
+}
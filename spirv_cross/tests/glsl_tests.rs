@@ -201,6 +201,53 @@ void main()
     );
 }
 
+#[test]
+fn builds_dummy_sampler_for_fetched_images() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/texel_fetch.frag.spv"),
+    )))
+    .unwrap();
+
+    let sampler_id = ast.build_dummy_sampler_for_combined_images().unwrap();
+    assert!(sampler_id.is_some());
+    assert_eq!(
+        ast.build_dummy_sampler_for_combined_images().unwrap(),
+        sampler_id
+    );
+
+    let combined_image_samplers = ast.get_combined_image_samplers().unwrap();
+    assert_eq!(combined_image_samplers.len(), 1);
+    assert_eq!(Some(combined_image_samplers[0].sampler_id), sampler_id);
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("uniform sampler2D"));
+    assert!(shader.contains("texelFetch("));
+}
+
+#[test]
+fn does_not_build_dummy_sampler_for_sampled_images() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/sampler.frag.spv"),
+    )))
+    .unwrap();
+
+    assert_eq!(ast.build_dummy_sampler_for_combined_images().unwrap(), None);
+}
+
+#[test]
+fn ast_names_combined_image_samplers() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/sampler.frag.spv"),
+    )))
+    .unwrap();
+    ast.name_combined_image_samplers(|image, sampler| format!("{}_{}", image, sampler))
+        .unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("uniform sampler2D u_texture_u_sampler;"));
+    assert!(shader.contains("texture(u_texture_u_sampler, v_uv)"));
+}
+
 #[test]
 fn flatten_uniform_buffers() {
     let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
@@ -234,6 +281,23 @@ void main()
     );
 }
 
+#[test]
+fn flattens_all_uniform_buffers() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/two_ubo.vert.spv"),
+    )))
+    .unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_30;
+    options.emit_uniform_buffer_as_plain_uniforms = true;
+    options.enable_420_pack_extension = false;
+    options.flatten_uniform_buffers = true;
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("uniform vec4 ubo1[7];\nuniform vec4 ubo2[3];\n"));
+}
+
 #[test]
 fn add_header_line() {
     let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
@@ -390,3 +454,863 @@ void main()
         assert_eq!(&ast.compile().unwrap(), expected_result);
     }
 }
+
+#[test]
+fn forwards_specialization_constant_macros() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/specialization.comp.spv"),
+    )))
+    .unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options
+        .specialization_constant_macros
+        .insert(10, String::from("USE_CONSTANT"));
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains(
+        "\
+#ifdef USE_CONSTANT
+#define SPIRV_CROSS_CONSTANT_ID_10 USE_CONSTANT
+#endif
+"
+    ));
+    assert!(shader.contains("#ifndef SPIRV_CROSS_CONSTANT_ID_10"));
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/spec_values.comp.spv"),
+    )))
+    .unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options
+        .specialization_constant_macros
+        .insert(2, String::from("ENABLE_VALUES"));
+    ast.set_compiler_options(&options).unwrap();
+
+    // `bool` constants are given `true` or `false` rather than the integer value of the macro.
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains(
+        "\
+#ifdef ENABLE_VALUES
+#if ENABLE_VALUES
+#define SPIRV_CROSS_CONSTANT_ID_2 true
+#else
+#define SPIRV_CROSS_CONSTANT_ID_2 false
+#endif
+#endif
+"
+    ));
+}
+
+#[test]
+fn ast_compiles_dual_es_and_desktop_glsl() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    let mut es_options = glsl::CompilerOptions::default();
+    es_options.version = glsl::Version::V3_00Es;
+    let mut desktop_options = glsl::CompilerOptions::default();
+    desktop_options.version = glsl::Version::V3_30;
+
+    let (es_source, desktop_source) = ast.compile_dual(&es_options, &desktop_options).unwrap();
+    assert!(es_source.starts_with("#version 300 es\n"));
+    assert!(desktop_source.starts_with("#version 330\n"));
+
+    assert!(ast.compile_dual(&desktop_options, &es_options).is_err());
+
+    // Options which change the module would carry over from the ES variant.
+    es_options.flatten_uniform_buffers = true;
+    assert!(ast.compile_dual(&es_options, &desktop_options).is_err());
+    es_options.flatten_uniform_buffers = false;
+
+    // Vertex attribute locations omitted for ES change the interface.
+    es_options.omit_vertex_attribute_locations = true;
+    assert!(ast.compile_dual(&es_options, &desktop_options).is_err());
+}
+
+#[test]
+fn reflects_and_assigns_atomic_counter_bindings() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/atomic_counter.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    let bindings = ast.get_atomic_counter_bindings().unwrap();
+    assert_eq!(bindings.len(), 1);
+    assert_eq!(bindings[0].binding, 1);
+    assert_eq!(bindings[0].offset, 4);
+
+    ast.set_atomic_counter_binding(bindings[0].id, 2, 8)
+        .unwrap();
+    assert_eq!(
+        ast.get_atomic_counter_bindings().unwrap(),
+        [glsl::AtomicCounterBinding {
+            id: bindings[0].id,
+            binding: 2,
+            offset: 8,
+        }]
+    );
+
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V4_50;
+    ast.set_compiler_options(&options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("layout(binding = 2, offset = 8) uniform atomic_uint counter;"));
+}
+
+#[test]
+fn limits_identifier_length() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    assert!(ast.limit_identifier_length(5).is_err());
+
+    let renames = ast.limit_identifier_length(16).unwrap();
+    let block = renames
+        .iter()
+        .find(|rename| rename.original == "uniform_buffer_object")
+        .unwrap();
+    assert_eq!(block.member_index, None);
+    assert_eq!(block.renamed.len(), 16);
+    assert!(block.renamed.starts_with("uniform"));
+    let member = renames
+        .iter()
+        .find(|rename| rename.original == "u_model_view_projection")
+        .unwrap();
+    assert_eq!(member.member_index, Some(0));
+    assert_eq!(
+        ast.get_member_name(member.id, 0).unwrap(),
+        member.renamed.as_str()
+    );
+    assert!(renames.iter().all(|rename| rename.renamed.len() <= 16));
+
+    let mut other_ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    assert_eq!(other_ast.limit_identifier_length(16).unwrap(), renames);
+
+    // Builtins such as `gl_PerVertex` keep their names, and a prefix ending in `_`, such as
+    // `u_`, doesn't double the separator before the hash.
+    let mut short_ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let short_renames = short_ast.limit_identifier_length(11).unwrap();
+    assert!(short_renames
+        .iter()
+        .all(|rename| !rename.original.starts_with("gl_") && !rename.renamed.contains("__")));
+    assert!(short_renames
+        .iter()
+        .any(|rename| rename.original == "u_model_view_projection"
+            && rename.renamed.starts_with("u_")));
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains(&format!("uniform {}", block.renamed)));
+    assert!(shader.contains(&member.renamed));
+    assert!(!shader.contains("u_model_view_projection"));
+}
+
+#[test]
+fn reports_clip_space_fixups() {
+    let mut options = glsl::CompilerOptions::default();
+    options.vertex.transform_clip_space = true;
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&options).unwrap();
+    assert!(ast.get_clip_space_fixups().is_err());
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("gl_Position.z = 2.0 * gl_Position.z - gl_Position.w;"));
+    assert_eq!(
+        ast.get_clip_space_fixups().unwrap(),
+        spirv::ClipSpaceFixups {
+            transform_clip_space: true,
+            invert_y: false,
+        }
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&options).unwrap();
+    ast.compile().unwrap();
+    assert_eq!(
+        ast.get_clip_space_fixups().unwrap(),
+        spirv::ClipSpaceFixups::default()
+    );
+}
+
+#[test]
+fn inverts_y_of_vertex_positions() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    ast.set_compiler_options(&options).unwrap();
+    assert!(!ast
+        .compile()
+        .unwrap()
+        .contains("gl_Position.y = -gl_Position.y;"));
+
+    options.vertex.invert_y = true;
+    ast.set_compiler_options(&options).unwrap();
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("gl_Position.y = -gl_Position.y;"));
+}
+
+#[test]
+fn names_anonymous_blocks() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/anonymous_block.vert.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let block = ast.get_shader_resources().unwrap().uniform_buffers[0].clone();
+
+    let mut options = glsl::CompilerOptions::default();
+    options
+        .anonymous_block_names
+        .insert(block.id, String::from("Globals"));
+    ast.set_compiler_options(&options).unwrap();
+    assert_eq!(ast.get_name(block.base_type_id).unwrap(), "Globals");
+    assert!(ast.compile().unwrap().contains("uniform Globals\n"));
+
+    options.anonymous_block_names.clear();
+    options
+        .anonymous_block_names
+        .insert(0xffff, String::from("Missing"));
+    assert!(ast.set_compiler_options(&options).is_err());
+}
+
+#[test]
+fn emits_preprocessor_defines() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.preprocessor_defines = vec![
+        (String::from("USE_SHADOWS"), None),
+        (String::from("QUALITY"), Some(String::from("2"))),
+    ];
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.starts_with("#version 450\n#define USE_SHADOWS\n#define QUALITY 2\n"));
+    // Defines are only added once, however often the module is compiled.
+    assert_eq!(ast.compile().unwrap().matches("#define QUALITY").count(), 1);
+
+    // The header lines can't be taken back, so changing the defines is refused.
+    ast.set_compiler_options(&compiler_options).unwrap();
+    compiler_options.preprocessor_defines.pop();
+    assert!(ast.set_compiler_options(&compiler_options).is_err());
+}
+
+#[test]
+fn emits_push_constant_as_uniform_buffer() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/push_constant.vert.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("uniform push_constants pc;"));
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    compiler_options.emit_push_constant_as_uniform_buffer = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("std140) uniform push_constants\n{"));
+    assert!(!shader.contains("uniform push_constants pc;"));
+}
+
+#[test]
+fn emits_uniform_buffer_as_plain_uniforms() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.version = glsl::Version::V1_00Es;
+    compiler_options.emit_uniform_buffer_as_plain_uniforms = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("struct uniform_buffer_object"));
+    assert!(shader.contains("uniform uniform_buffer_object "));
+    assert!(!shader.contains("std140"));
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    assert_eq!(uniform_buffers.len(), 1);
+    assert_eq!(uniform_buffers[0].name, "uniform_buffer_object");
+    assert_eq!(
+        ast.get_member_name(uniform_buffers[0].base_type_id, 1)
+            .unwrap(),
+        "u_scale"
+    );
+}
+
+#[test]
+fn reports_unsupported_multisampled_images() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/multisample.frag.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let images = ast.get_multisampled_images().unwrap();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].resource.name, "u_texture");
+    assert!(!images[0].image_type.arrayed);
+    assert!(!images[0].image_type.storage);
+
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.version = glsl::Version::V3_10Es;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast
+        .get_unsupported_multisampled_images()
+        .unwrap()
+        .is_empty());
+
+    compiler_options.version = glsl::Version::V3_00Es;
+    compiler_options.reject_unsupported_multisampled_images = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert_eq!(ast.get_unsupported_multisampled_images().unwrap(), images);
+    match ast.compile() {
+        Err(spirv_cross::ErrorCode::CompilationError(message)) => {
+            assert!(message.contains("u_texture"))
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    assert!(ast.get_multisampled_images().unwrap().is_empty());
+}
+
+#[test]
+fn redeclares_per_vertex_for_separate_shader_objects() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(!ast.compile().unwrap().contains("out gl_PerVertex"));
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    compiler_options.separate_shader_objects = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.compile().unwrap().contains("out gl_PerVertex"));
+}
+
+#[test]
+fn omits_vertex_attribute_locations() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.version = glsl::Version::V3_30;
+    compiler_options.omit_vertex_attribute_locations = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.get_vertex_attribute_locations().is_err());
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("\nin vec4 a_position;"));
+    assert!(shader.contains("\nin vec3 a_normal;"));
+    assert!(!shader.contains("layout(location"));
+
+    let locations = ast.get_vertex_attribute_locations().unwrap();
+    assert_eq!(locations.len(), 2);
+    assert_eq!(locations["a_position"], 0);
+    assert_eq!(locations["a_normal"], 1);
+}
+
+#[test]
+fn keeps_omitted_vertex_attribute_locations_across_option_changes() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.version = glsl::Version::V3_30;
+    compiler_options.omit_vertex_attribute_locations = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.compile().unwrap();
+
+    let locations = ast.get_vertex_attribute_locations().unwrap();
+    assert_eq!(locations.len(), 2);
+    assert_eq!(locations["a_position"], 0);
+    assert_eq!(locations["a_normal"], 1);
+
+    compiler_options.omit_vertex_attribute_locations = false;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("layout(location = 0) in vec4 a_position;"));
+    assert!(shader.contains("layout(location = 1) in vec3 a_normal;"));
+    assert!(ast.get_vertex_attribute_locations().unwrap().is_empty());
+}
+
+#[test]
+fn toggles_420_pack_extension() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/rasterize_disabled.vert.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.version = glsl::Version::V3_30;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("#extension GL_ARB_shading_language_420pack : require"));
+    assert!(shader.contains("layout(binding = 0"));
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    compiler_options.enable_420_pack_extension = false;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(!shader.contains("GL_ARB_shading_language_420pack"));
+    assert!(!shader.contains("binding ="));
+}
+
+#[test]
+fn reports_base_instance_uniform_name() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/instance_index.vert.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.version = glsl::Version::V3_30;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.get_base_instance_uniform_name().is_err());
+
+    let shader = ast.compile().unwrap();
+    let name = ast.get_base_instance_uniform_name().unwrap().unwrap();
+    assert!(shader.contains(&format!("uniform int {};", name)));
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    compiler_options.vertex.support_nonzero_base_instance = false;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.compile().unwrap();
+    assert_eq!(ast.get_base_instance_uniform_name().unwrap(), None);
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::default())
+        .unwrap();
+    ast.compile().unwrap();
+    assert_eq!(ast.get_base_instance_uniform_name().unwrap(), None);
+}
+
+#[test]
+fn emits_vulkan_semantics() {
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.vulkan_semantics = true;
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("layout(set = 0, binding = 0) uniform texture2D u_texture;"));
+    assert!(shader.contains("layout(set = 0, binding = 1) uniform sampler u_sampler;"));
+    assert!(shader.contains("sampler2D(u_texture, u_sampler)"));
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/push_constant.vert.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("layout(push_constant"));
+    assert!(shader.contains("uniform push_constants"));
+}
+
+#[test]
+fn reports_extension_directives() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/sampler.frag.spv"),
+    )))
+    .unwrap();
+    assert!(ast.get_extension_directives().is_err());
+
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V4_10;
+    options.enable_420_pack_extension = true;
+    ast.set_compiler_options(&options).unwrap();
+    ast.compile().unwrap();
+
+    assert_eq!(
+        ast.get_extension_directives().unwrap(),
+        vec![glsl::ExtensionDirective {
+            name: String::from("GL_ARB_shading_language_420pack"),
+            behavior: glsl::ExtensionBehavior::Require,
+            conditional: true,
+        }]
+    );
+}
+
+#[test]
+fn adds_header_lines_and_required_extensions() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/simple.vert.spv"),
+    )))
+    .unwrap();
+    ast.require_extension("GL_EXT_texture_buffer").unwrap();
+    ast.add_header_line("#define ENGINE_VERSION 3").unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("#extension GL_EXT_texture_buffer : require\n"));
+    assert!(shader.contains("#define ENGINE_VERSION 3\n"));
+    assert!(
+        shader.find("#extension GL_EXT_texture_buffer").unwrap()
+            < shader.find("#define ENGINE_VERSION").unwrap()
+    );
+    assert!(ast
+        .get_extension_directives()
+        .unwrap()
+        .iter()
+        .any(|directive| directive.name == "GL_EXT_texture_buffer" && !directive.conditional));
+}
+
+#[test]
+fn declares_ovr_multiview_view_count() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/multiview.vert.spv"),
+    )))
+    .unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_00Es;
+    options.ovr_multiview_view_count = 2;
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("#extension GL_OVR_multiview2 : require\n"));
+    assert!(shader.contains("layout(num_views = 2) in;\n"));
+    assert!(shader.contains("gl_ViewID_OVR"));
+}
+
+#[test]
+fn flattens_io_blocks() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/io_block.vert.spv"),
+    )))
+    .unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_10Es;
+    ast.set_compiler_options(&options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("GL_EXT_shader_io_blocks"));
+    assert!(shader.contains("out VertexData"));
+
+    options.force_flattened_io_blocks = true;
+    ast.set_compiler_options(&options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(!shader.contains("GL_EXT_shader_io_blocks"));
+    assert!(shader.contains("out vec4 v_out_color;"));
+}
+
+#[test]
+fn translates_to_glsl_keeping_descriptor_bindings() {
+    let translation = spirv_cross::translate(
+        include_bytes!("shaders/sampler.frag.spv"),
+        spirv_cross::TargetSpec::Glsl {
+            version: glsl::Version::V4_50,
+        },
+    )
+    .unwrap();
+
+    assert!(translation.source.contains("#version 450"));
+    assert_eq!(translation.entry_points[0].name, "main");
+    let bindings = translation
+        .bindings
+        .iter()
+        .map(|binding| {
+            (
+                binding.name.as_str(),
+                binding.desc_set,
+                binding.binding,
+                binding.target_indices.is_empty(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        bindings,
+        [("u_texture", 0, 0, true), ("u_sampler", 0, 1, true)]
+    );
+}
+
+#[test]
+fn round_trips_shader_packs() {
+    use spirv_cross::pack::{self, Pack, PackedShader, TargetKind};
+
+    let spirv_bytes = include_bytes!("shaders/simple.vert.spv");
+    let target = spirv_cross::TargetSpec::Glsl {
+        version: glsl::Version::V4_50,
+    };
+    let translation = spirv_cross::translate(spirv_bytes, target).unwrap();
+    let mut pack = Pack::new();
+    pack.shaders.push(PackedShader::new(
+        "simple.vert",
+        spirv_bytes,
+        target,
+        &translation,
+    ));
+
+    let read = Pack::read(&pack.write()).unwrap();
+    assert_eq!(read, pack);
+    let shader = &read.shaders[0];
+    assert_eq!(shader.spirv_hash, pack::spirv_hash(spirv_bytes));
+    assert_eq!(shader.target, TargetKind::Glsl);
+    assert_eq!(shader.target_version, "450");
+    assert_eq!(shader.target_spec().unwrap(), target);
+    assert_eq!(shader.source, translation.source);
+    assert!(shader
+        .report
+        .contains("uniform_buffer uniform_buffer_object"));
+    assert_eq!(shader.bindings, translation.bindings);
+
+    // Binding names are length-prefixed, so they may contain line breaks.
+    pack.shaders[0].bindings[0].name = String::from("uniform\nbuffer");
+    assert_eq!(Pack::read(&pack.write()).unwrap(), pack);
+
+    let mut newer = pack.write();
+    newer[17] = b'9';
+    assert!(Pack::read(&newer).is_err());
+}
+
+#[test]
+fn relaxes_nan_checks() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/nmin.frag.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.compile().unwrap().contains("isnan("));
+
+    compiler_options.relax_nan_checks = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(!ast.compile().unwrap().contains("isnan("));
+}
+
+#[test]
+fn targets_custom_versions() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/simple.vert.spv"),
+    )))
+    .unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::Custom {
+        version: 460,
+        es: false,
+    };
+    ast.set_compiler_options(&options).unwrap();
+    assert!(ast.compile().unwrap().starts_with("#version 460\n"));
+
+    options.version = glsl::Version::Custom {
+        version: 320,
+        es: true,
+    };
+    ast.set_compiler_options(&options).unwrap();
+    assert!(ast.compile().unwrap().starts_with("#version 320 es\n"));
+}
+
+#[test]
+fn emits_line_directives() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/source_files.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = glsl::CompilerOptions::default();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(!ast.compile().unwrap().contains("#line"));
+
+    compiler_options.emit_line_directives = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("#line 4 \"shaders/main.comp\""));
+}
+
+#[test]
+fn reads_subpass_inputs_with_ext_framebuffer_fetch() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/subpass_input.frag.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_10Es;
+    options.ext_framebuffer_fetch_color_locations.insert(1, 0);
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("#extension GL_EXT_shader_framebuffer_fetch : require"));
+    assert!(shader.contains("inout highp vec4 color;"));
+    assert!(!shader.contains("u_albedo"));
+    // Compiling again doesn't remap the subpass input twice.
+    assert_eq!(ast.compile().unwrap(), shader);
+
+    // The subpass input was already replaced, so the mapping can't change.
+    options.ext_framebuffer_fetch_noncoherent = true;
+    assert!(ast.set_compiler_options(&options).is_err());
+}
+
+#[test]
+fn lints_compiled_source() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let shader = ast.compile().unwrap();
+    let mut rules = spirv_cross::LintRules::default();
+    assert!(spirv_cross::lint(&ast, &shader, &rules).unwrap().is_empty());
+
+    rules.max_varyings = Some(0);
+    rules.max_uniform_buffer_size = Some(64);
+    let findings = spirv_cross::lint(&ast, &shader, &rules).unwrap();
+    assert_eq!(
+        findings.iter().map(|f| f.rule).collect::<Vec<_>>(),
+        vec![
+            spirv_cross::LintRule::TooManyVaryings,
+            spirv_cross::LintRule::UniformBufferTooLarge,
+        ]
+    );
+    assert_eq!(
+        findings[0].message,
+        "1 varying slots are used, but at most 0 are allowed"
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/sampler_array.frag.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    // Desktop GLSL allows dynamic sampler array indices.
+    let shader = ast.compile().unwrap();
+    assert!(spirv_cross::lint(&ast, &shader, &Default::default())
+        .unwrap()
+        .is_empty());
+
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_00Es;
+    ast.set_compiler_options(&options).unwrap();
+    let shader = ast.compile().unwrap();
+    let findings = spirv_cross::lint(&ast, &shader, &Default::default()).unwrap();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].rule,
+        spirv_cross::LintRule::DynamicSamplerArrayIndex
+    );
+    assert_eq!(
+        findings[0].id,
+        Some(ast.get_shader_resources().unwrap().sampled_images[0].id)
+    );
+}
+
+#[test]
+fn remaps_variable_types() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/external_texture.frag.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V1_00Es;
+    ast.set_compiler_options(&options).unwrap();
+    ast.require_extension("GL_OES_EGL_image_external").unwrap();
+    let mut remapped = Vec::new();
+    ast.set_variable_type_remap(|name, ty| match ty {
+        spirv::Type::SampledImage { .. } => {
+            remapped.push(name.to_owned());
+            Some(String::from("samplerExternalOES"))
+        }
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!(remapped, vec![String::from("u_camera")]);
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("#version 100"));
+    assert!(shader.contains("#extension GL_OES_EGL_image_external : require"));
+    assert!(shader.contains("samplerExternalOES u_camera;"));
+    assert!(shader.contains("varying"));
+}
+
+#[test]
+fn reads_shader_clock() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/clock.comp.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("GL_ARB_shader_clock"));
+    assert!(shader.contains("clock2x32ARB()"));
+}
+
+#[test]
+fn demotes_float16_to_mediump() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/half.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_10Es;
+    options.float16_to_mediump = true;
+    ast.set_compiler_options(&options).unwrap();
+    assert_eq!(
+        ast.get_float16_demotions().unwrap(),
+        vec![
+            glsl::DemotedOperation {
+                id: 16,
+                opcode: 115
+            },
+            glsl::DemotedOperation {
+                id: 17,
+                opcode: 133
+            },
+        ]
+    );
+
+    let shader = ast.compile().unwrap();
+    assert!(!shader.contains("float16_t"));
+    assert!(shader.contains("mediump float"));
+
+    options.float16_to_mediump = false;
+    ast.set_compiler_options(&options).unwrap();
+    assert_eq!(ast.get_float16_demotions().unwrap(), vec![]);
+}
+
+#[test]
+fn rejects_float16_to_mediump_changes_after_modification() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/half.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_10Es;
+    ast.set_compiler_options(&options).unwrap();
+    ast.set_name(16, "scaled").unwrap();
+
+    options.float16_to_mediump = true;
+    assert!(ast.set_compiler_options(&options).is_err());
+    assert_eq!(ast.get_name(16).unwrap(), "scaled");
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&options).unwrap();
+    ast.set_compiler_options(&options).unwrap();
+    ast.compile().unwrap();
+    options.float16_to_mediump = false;
+    assert!(ast.set_compiler_options(&options).is_err());
+}
+
+#[test]
+fn rejects_float16_to_mediump_with_bitcasts() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/half_bitcast.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_10Es;
+    options.float16_to_mediump = true;
+    assert_eq!(
+        ast.set_compiler_options(&options),
+        Err(spirv_cross::ErrorCode::CompilationError(String::from(
+            "bitcasts of 16-bit floats can't be demoted to mediump"
+        )))
+    );
+}
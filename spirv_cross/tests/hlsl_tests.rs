@@ -11,6 +11,15 @@ fn hlsl_compiler_options_has_default() {
     assert_eq!(compiler_options.point_coord_compat, false);
     assert_eq!(compiler_options.vertex.invert_y, false);
     assert_eq!(compiler_options.vertex.transform_clip_space, false);
+    assert!(!compiler_options.force_storage_buffer_as_uav);
+    assert!(!compiler_options.nonwritable_uav_texture_as_srv);
+    assert!(!compiler_options.support_nonzero_base_vertex_base_instance);
+    assert!(!compiler_options.enable_16bit_types);
+    assert!(!compiler_options.flatten_matrix_vertex_input_semantics);
+    assert!(!compiler_options.use_entry_point_name);
+    assert!(!compiler_options.preserve_structured_buffers);
+    assert!(compiler_options.resource_binding_overrides.is_empty());
+    assert!(compiler_options.root_constant_layouts.is_empty());
 }
 
 #[test]
@@ -85,6 +94,12 @@ fn ast_compiles_all_shader_models_to_hlsl() {
         hlsl::ShaderModel::V5_0,
         hlsl::ShaderModel::V5_1,
         hlsl::ShaderModel::V6_0,
+        hlsl::ShaderModel::V6_1,
+        hlsl::ShaderModel::V6_2,
+        hlsl::ShaderModel::V6_3,
+        hlsl::ShaderModel::V6_4,
+        hlsl::ShaderModel::V6_5,
+        hlsl::ShaderModel::V6_6,
     ];
     for &shader_model in shader_models.iter() {
         let mut options = hlsl::CompilerOptions::default();
@@ -285,3 +300,482 @@ SPIRV_Cross_Output main()
         assert_eq!(&ast.compile().unwrap(), expected_result);
     }
 }
+
+#[test]
+fn emits_push_constants_as_root_constants() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/push_constant.vert.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V5_1;
+    compiler_options.root_constant_layouts = vec![hlsl::RootConstant {
+        start: 0,
+        end: 16,
+        binding: 3,
+        space: 1,
+    }];
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("cbuffer SPIRV_CROSS_RootConstant_pc : register(b3, space1)"));
+
+    let push_constants = &ast.get_shader_resources().unwrap().push_constant_buffers[0];
+    assert_eq!(
+        ast.get_resource_registers().unwrap(),
+        [hlsl::ResourceRegister {
+            id: push_constants.id,
+            desc_set: 0,
+            binding: 0,
+            register_type: hlsl::RegisterType::ConstantBuffer,
+            register: hlsl::Register {
+                register: 3,
+                space: 1,
+            },
+        }]
+    );
+}
+
+#[test]
+fn translates_ray_queries_for_shader_model_6_5() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/ray_query.comp.asm.spv"
+    )));
+
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V6_0;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.compile().is_err());
+
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    compiler_options.shader_model = hlsl::ShaderModel::V6_5;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("RaytracingAccelerationStructure tlas"));
+    assert!(shader.contains("RayQuery<"));
+}
+
+#[test]
+fn remaps_vertex_attribute_semantics() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V5_0;
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    ast.add_vertex_attribute_remap(0, "POSITION").unwrap();
+    ast.add_vertex_attribute_remap(1, "NORMAL").unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("float4 a_position : POSITION;"));
+    assert!(shader.contains("float3 a_normal : NORMAL;"));
+}
+
+#[test]
+fn supports_nonzero_base_vertex_base_instance() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/base_vertex.vert.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V5_1;
+    compiler_options.support_nonzero_base_vertex_base_instance = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.set_base_vertex_instance_binding(1, 2).unwrap();
+    assert!(ast.is_base_vertex_instance_binding_used().is_err());
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("cbuffer SPIRV_Cross_VertexInfo : register(b1"));
+    assert!(shader.contains("SPIRV_Cross_BaseVertex"));
+    assert!(shader.contains("SPIRV_Cross_BaseInstance"));
+    assert!(ast.is_base_vertex_instance_binding_used().unwrap());
+}
+
+#[test]
+fn emits_native_16bit_types() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/half.comp.asm.spv"
+    )));
+
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V6_2;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.compile().unwrap().contains("min16float"));
+
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    compiler_options.enable_16bit_types = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("half"));
+    assert!(!shader.contains("min16float"));
+}
+
+#[test]
+fn overrides_resource_bindings() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+
+    let texture = hlsl::ResourceBindingLocation {
+        stage: spirv::ExecutionModel::Fragment,
+        desc_set: 0,
+        binding: 0,
+    };
+    let sampler = hlsl::ResourceBindingLocation {
+        stage: spirv::ExecutionModel::Fragment,
+        desc_set: 0,
+        binding: 1,
+    };
+    let unused = hlsl::ResourceBindingLocation {
+        stage: spirv::ExecutionModel::Fragment,
+        desc_set: 5,
+        binding: 5,
+    };
+
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V5_1;
+    compiler_options.resource_binding_overrides.insert(
+        texture.clone(),
+        hlsl::ResourceBinding {
+            srv: hlsl::Register {
+                register: 3,
+                space: 1,
+            },
+            ..Default::default()
+        },
+    );
+    compiler_options.resource_binding_overrides.insert(
+        sampler.clone(),
+        hlsl::ResourceBinding {
+            sampler: hlsl::Register {
+                register: 2,
+                space: 4,
+            },
+            ..Default::default()
+        },
+    );
+    compiler_options
+        .resource_binding_overrides
+        .insert(unused.clone(), hlsl::ResourceBinding::default());
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    assert!(ast.is_resource_binding_used(&texture).is_err());
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("u_texture : register(t3, space1);"));
+    assert!(shader.contains("u_sampler : register(s2, space4);"));
+    assert!(ast.is_resource_binding_used(&texture).unwrap());
+    assert!(ast.is_resource_binding_used(&sampler).unwrap());
+    assert!(!ast.is_resource_binding_used(&unused).unwrap());
+}
+
+#[test]
+fn remaps_num_workgroups_builtin() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    assert_eq!(ast.remap_num_workgroups_builtin().unwrap(), None);
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/num_workgroups.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V5_1;
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let id = ast.remap_num_workgroups_builtin().unwrap().unwrap();
+    ast.set_decoration(id, spirv::Decoration::DescriptorSet, 1)
+        .unwrap();
+    ast.set_decoration(id, spirv::Decoration::Binding, 2)
+        .unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("SPIRV_Cross_NumWorkgroups"));
+    assert!(shader.contains("register(b2, space1)"));
+}
+
+#[test]
+fn reports_automatic_resource_registers() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V5_1;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.get_resource_registers().is_err());
+
+    let resources = ast.get_shader_resources().unwrap();
+    let texture = &resources.separate_images[0];
+    let sampler = &resources.separate_samplers[0];
+    ast.set_decoration(texture.id, spirv::Decoration::DescriptorSet, 2)
+        .unwrap();
+    ast.set_decoration(texture.id, spirv::Decoration::Binding, 3)
+        .unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("register(t3, space2)"));
+
+    let registers = ast.get_resource_registers().unwrap();
+    assert_eq!(registers.len(), 2);
+    assert!(registers.contains(&hlsl::ResourceRegister {
+        id: texture.id,
+        desc_set: 2,
+        binding: 3,
+        register_type: hlsl::RegisterType::ShaderResource,
+        register: hlsl::Register {
+            register: 3,
+            space: 2,
+        },
+    }));
+    assert!(registers.contains(&hlsl::ResourceRegister {
+        id: sampler.id,
+        desc_set: 0,
+        binding: 1,
+        register_type: hlsl::RegisterType::Sampler,
+        register: hlsl::Register {
+            register: 1,
+            space: 0,
+        },
+    }));
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/counter_buffer.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V5_0;
+    compiler_options.resource_binding_overrides.insert(
+        hlsl::ResourceBindingLocation {
+            stage: spirv::ExecutionModel::GlCompute,
+            desc_set: 0,
+            binding: 1,
+        },
+        hlsl::ResourceBinding {
+            uav: hlsl::Register {
+                register: 7,
+                space: 0,
+            },
+            ..Default::default()
+        },
+    );
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.compile().unwrap();
+
+    let mut registers = ast
+        .get_resource_registers()
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.binding, r.register_type, r.register.register))
+        .collect::<Vec<_>>();
+    registers.sort_by_key(|r| r.0);
+    assert_eq!(
+        registers,
+        [
+            (0, hlsl::RegisterType::UnorderedAccess, 0),
+            (1, hlsl::RegisterType::UnorderedAccess, 7),
+        ]
+    );
+}
+
+#[test]
+fn reflects_hlsl_counter_buffers() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/counter_buffer.comp.asm.spv"
+    )));
+    let ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let resources = ast.get_shader_resources().unwrap();
+    let values = resources
+        .storage_buffers
+        .iter()
+        .find(|r| r.name == "values")
+        .unwrap();
+    let counter = resources
+        .storage_buffers
+        .iter()
+        .find(|r| r.name == "counter.var.values")
+        .unwrap();
+
+    assert!(!ast.buffer_is_hlsl_counter_buffer(values.id).unwrap());
+    assert!(ast.buffer_is_hlsl_counter_buffer(counter.id).unwrap());
+    assert_eq!(
+        ast.buffer_get_hlsl_counter_buffer(values.id).unwrap(),
+        Some(counter.id)
+    );
+    assert_eq!(
+        ast.buffer_get_hlsl_counter_buffer(counter.id).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn flattens_matrix_vertex_input_semantics() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/matrix_input.vert.asm.spv"
+    )));
+
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V5_0;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("a_transform : TEXCOORD0;"));
+    assert!(!shader.contains("TEXCOORD3"));
+
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    compiler_options.flatten_matrix_vertex_input_semantics = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("TEXCOORD3"));
+}
+
+#[test]
+fn uses_entry_point_name() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/vs_and_fs.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.shader_model = hlsl::ShaderModel::V6_0;
+    compiler_options.use_entry_point_name = true;
+    compiler_options.entry_point = Some((String::from("main_fs"), spirv::ExecutionModel::Fragment));
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("SPIRV_Cross_Output main_fs()"));
+    assert!(!shader.contains(" main()"));
+}
+
+#[test]
+fn emits_preprocessor_defines() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    compiler_options.preprocessor_defines = vec![
+        (String::from("USE_SHADOWS"), None),
+        (String::from("QUALITY"), Some(String::from("2"))),
+    ];
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("#define USE_SHADOWS\n#define QUALITY 2\n"));
+    // Defines are only added once, however often the module is compiled.
+    assert_eq!(ast.compile().unwrap().matches("#define QUALITY").count(), 1);
+}
+
+#[test]
+fn emits_loop_unroll_hints() {
+    let compile = |hint| {
+        let mut words =
+            words_from_bytes(include_bytes!("shaders/rasterize_disabled.vert.spv")).to_vec();
+        if let Some(hint) = hint {
+            spirv::set_loop_unroll_hints(&mut words, hint);
+        }
+        let module = spirv::Module::from_words(&words);
+        let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+        let mut compiler_options = hlsl::CompilerOptions::default();
+        compiler_options.shader_model = hlsl::ShaderModel::V5_0;
+        ast.set_compiler_options(&compiler_options).unwrap();
+        ast.compile().unwrap()
+    };
+
+    let shader = compile(None);
+    assert!(!shader.contains("[unroll]"));
+    assert!(!shader.contains("[loop]"));
+    assert!(compile(Some(spirv::LoopUnrollHint::Unroll)).contains("[unroll]"));
+    assert!(compile(Some(spirv::LoopUnrollHint::DontUnroll)).contains("[loop]"));
+    assert!(!compile(Some(spirv::LoopUnrollHint::None)).contains("[unroll]"));
+}
+
+#[test]
+fn translates_to_hlsl_with_registers() {
+    use spirv_cross::pack::{Pack, PackedShader};
+
+    let spirv_bytes = include_bytes!("shaders/sampler.frag.spv");
+    let target = spirv_cross::TargetSpec::Hlsl {
+        shader_model: hlsl::ShaderModel::V5_0,
+    };
+    let translation = spirv_cross::translate(spirv_bytes, target).unwrap();
+
+    assert!(translation.source.contains("register(t0)"));
+    let indices = translation
+        .bindings
+        .iter()
+        .map(|binding| (binding.name.as_str(), binding.target_indices.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        indices,
+        [
+            (
+                "u_texture",
+                vec![spirv_cross::TargetIndex::HlslRegister {
+                    class: 't',
+                    register: 0,
+                    space: 0,
+                }]
+            ),
+            (
+                "u_sampler",
+                vec![spirv_cross::TargetIndex::HlslRegister {
+                    class: 's',
+                    register: 1,
+                    space: 0,
+                }]
+            ),
+        ]
+    );
+
+    // The registers are kept by packs.
+    let mut pack = Pack::new();
+    pack.shaders.push(PackedShader::new(
+        "sampler.frag",
+        spirv_bytes,
+        target,
+        &translation,
+    ));
+    assert_eq!(Pack::read(&pack.write()).unwrap(), pack);
+    assert_eq!(pack.shaders[0].target_version, "50");
+    assert_eq!(pack.shaders[0].target_spec().unwrap(), target);
+}
+
+#[test]
+fn emits_line_directives() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/source_files.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    let mut compiler_options = hlsl::CompilerOptions::default();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(!ast.compile().unwrap().contains("#line"));
+
+    compiler_options.emit_line_directives = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    // The directives are emitted in the HLSL entry point and the functions it calls, without
+    // the `GL_GOOGLE_cpp_style_line_directive` extension GLSL needs for file names.
+    let entry_point = shader.find("void comp_main()").unwrap();
+    assert!(shader[entry_point..].contains("#line 4 \"shaders/main.comp\""));
+    assert!(shader.contains("#line 2 \"shaders/common.glsl\""));
+    assert!(!shader.contains("GL_GOOGLE_cpp_style_line_directive"));
+}
+
+#[test]
+fn rejects_shader_clock() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/clock.comp.spv")));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.compile(),
+        Err(spirv_cross::ErrorCode::CompilationError(String::from(
+            "OpReadClockKHR is not supported in HLSL"
+        )))
+    );
+    // A failed compile doesn't count as compiled.
+    assert_eq!(ast.state(), spirv::AstState::Parsed);
+    assert!(ast.get_resource_registers().is_err());
+}
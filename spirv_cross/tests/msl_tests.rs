@@ -12,6 +12,15 @@ fn msl_compiler_options_has_default() {
     assert_eq!(compiler_options.vertex.transform_clip_space, false);
     assert!(compiler_options.resource_binding_overrides.is_empty());
     assert!(compiler_options.vertex_attribute_overrides.is_empty());
+    assert!(!compiler_options.check_discarded_frag_stores);
+    assert!(compiler_options.readwrite_texture_fences);
+    assert_eq!(compiler_options.texel_buffer_texture_width, 4096);
+    assert!(!compiler_options.pad_argument_buffer_resources);
+    assert!(!compiler_options.enable_decoration_binding);
+    assert!(!compiler_options.arrayed_subpass_input);
+    assert!(!compiler_options.ios_use_simdgroup_functions);
+    assert!(!compiler_options.use_framebuffer_fetch_subpasses);
+    assert!(compiler_options.sampler_coalescing.is_none());
 }
 
 #[test]
@@ -577,3 +586,348 @@ fragment main_fs_out main_fs()
         assert_eq!(&ast.compile().unwrap(), expected_result);
     }
 }
+
+#[test]
+fn sets_combined_sampler_suffix() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    assert_eq!(ast.get_combined_sampler_suffix().unwrap(), "Smplr");
+
+    ast.set_combined_sampler_suffix("_sampler").unwrap();
+    assert_eq!(ast.get_combined_sampler_suffix().unwrap(), "_sampler");
+}
+
+#[test]
+fn queries_used_inputs_and_resource_bindings() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let binding_location = msl::ResourceBindingLocation {
+        stage: spirv::ExecutionModel::Vertex,
+        desc_set: 0,
+        binding: 0,
+    };
+    let unused_binding_location = msl::ResourceBindingLocation {
+        stage: spirv::ExecutionModel::Vertex,
+        desc_set: 0,
+        binding: 1,
+    };
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    for location in &[&binding_location, &unused_binding_location] {
+        compiler_options.resource_binding_overrides.insert(
+            (*location).clone(),
+            msl::ResourceBinding {
+                buffer_id: 5,
+                texture_id: 6,
+                sampler_id: 7,
+                count: 0,
+            },
+        );
+    }
+    compiler_options.vertex_attribute_overrides.insert(
+        msl::VertexAttributeLocation(0),
+        msl::VertexAttribute {
+            buffer_id: 1,
+            format: msl::Format::Other,
+            built_in: None,
+            vecsize: 0,
+        },
+    );
+
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.is_resource_binding_used(&binding_location).is_err());
+
+    ast.compile().unwrap();
+    assert!(ast.is_resource_binding_used(&binding_location).unwrap());
+    assert!(!ast
+        .is_resource_binding_used(&unused_binding_location)
+        .unwrap());
+    assert!(ast
+        .is_shader_input_used(&msl::VertexAttributeLocation(0))
+        .unwrap());
+}
+
+#[test]
+fn reports_options_usage() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    assert!(ast.get_options_usage().is_err());
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.compile().unwrap();
+    assert!(!ast.get_options_usage().unwrap().swizzle_buffer_index);
+
+    compiler_options.swizzle_texture_samples = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.compile().unwrap();
+    let usage = ast.get_options_usage().unwrap();
+    assert!(usage.swizzle_buffer_index);
+    assert!(!usage.output_buffer_index);
+    assert!(!usage.patch_output_buffer_index);
+}
+
+#[test]
+fn takes_resource_indices_from_binding_decorations() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let uniform_buffer = ast.get_shader_resources().unwrap().uniform_buffers[0].id;
+    ast.set_decoration(uniform_buffer, spirv::Decoration::Binding, 3)
+        .unwrap();
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.enable_decoration_binding = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("constant uniform_buffer_object& _22 [[buffer(3)]]"));
+}
+
+#[test]
+fn translates_ray_queries_for_msl_2_4() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/ray_query.comp.asm.spv"
+    )));
+
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = msl::Version::V2_2;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.compile().is_err());
+
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    compiler_options.version = msl::Version::V2_4;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("intersection_query<"));
+}
+
+#[test]
+fn reports_feature_requirements() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.enable_argument_buffers = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.get_feature_requirements().is_err());
+    ast.compile().unwrap();
+    assert_eq!(
+        ast.get_feature_requirements().unwrap(),
+        msl::FeatureRequirements {
+            argument_buffers: true,
+            function_constants: false,
+            framebuffer_fetch: false,
+            tessellation: false,
+            ray_queries: false,
+        }
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/specialization.comp.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    ast.compile().unwrap();
+    let requirements = ast.get_feature_requirements().unwrap();
+    assert!(requirements.function_constants);
+    assert!(!requirements.argument_buffers);
+}
+
+#[test]
+fn emits_preprocessor_defines() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.preprocessor_defines = vec![
+        (String::from("USE_SHADOWS"), None),
+        (String::from("QUALITY"), Some(String::from("2"))),
+    ];
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("#define USE_SHADOWS\n#define QUALITY 2\n"));
+    // Defines are only added once, however often the module is compiled.
+    assert_eq!(ast.compile().unwrap().matches("#define QUALITY").count(), 1);
+}
+
+fn sampler_data(filter: msl::SamplerFilter) -> msl::SamplerData {
+    msl::SamplerData {
+        coord: msl::SamplerCoord::Normalized,
+        min_filter: filter,
+        mag_filter: filter,
+        mip_filter: msl::SamplerMipFilter::None,
+        s_address: msl::SamplerAddress::Repeat,
+        t_address: msl::SamplerAddress::Repeat,
+        r_address: msl::SamplerAddress::Repeat,
+        compare_func: msl::SamplerCompareFunc::Always,
+        border_color: msl::SamplerBorderColor::TransparentBlack,
+        lod_clamp_min: msl::LodBase16::ZERO,
+        lod_clamp_max: msl::LodBase16::MAX,
+        max_anisotropy: 0,
+        planes: 0,
+        resolution: msl::FormatResolution::_444,
+        chroma_filter: msl::SamplerFilter::Nearest,
+        x_chroma_offset: msl::ChromaLocation::CositedEven,
+        y_chroma_offset: msl::ChromaLocation::CositedEven,
+        swizzle: [msl::ComponentSwizzle::Identity; 4],
+        ycbcr_conversion_enable: false,
+        ycbcr_model: msl::SamplerYCbCrModelConversion::RgbIdentity,
+        ycbcr_range: msl::SamplerYCbCrRange::ItuFull,
+        bpc: 8,
+    }
+}
+
+#[test]
+fn coalesces_identical_samplers() {
+    let location = |binding| msl::SamplerLocation {
+        desc_set: 0,
+        binding,
+    };
+    let mut samplers = BTreeMap::new();
+    samplers.insert(location(0), sampler_data(msl::SamplerFilter::Linear));
+    samplers.insert(location(1), sampler_data(msl::SamplerFilter::Nearest));
+    samplers.insert(location(2), sampler_data(msl::SamplerFilter::Linear));
+
+    let coalescing = msl::coalesce_samplers(&samplers, 2);
+    assert_eq!(coalescing.sampler_ids[&location(0)], 2);
+    assert_eq!(coalescing.sampler_ids[&location(1)], 3);
+    assert_eq!(coalescing.sampler_ids[&location(2)], 2);
+    assert_eq!(coalescing.states.len(), 2);
+    assert_eq!(
+        coalescing.states[&3],
+        sampler_data(msl::SamplerFilter::Nearest)
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.sampler_coalescing = Some(coalescing);
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("sampler u_sampler [[sampler(3)]]"));
+}
+
+#[test]
+fn reads_subpass_inputs_with_framebuffer_fetch() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/subpass_input.frag.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = msl::Version::V2_3;
+    compiler_options.use_framebuffer_fetch_subpasses = true;
+    compiler_options.input_attachment_color_indices.insert(1, 3);
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    assert!(ast.compile().unwrap().contains("[[color(3)]]"));
+    // Compiling again remaps from the declared index, which reflection still reports.
+    assert!(ast.compile().unwrap().contains("[[color(3)]]"));
+    let albedo = ast.get_shader_resources().unwrap().subpass_inputs[0].id;
+    assert_eq!(
+        ast.get_decoration(albedo, spirv::Decoration::InputAttachmentIndex)
+            .unwrap(),
+        1
+    );
+    let inputs = ast.get_framebuffer_fetch_inputs().unwrap();
+    assert_eq!(inputs.len(), 1);
+    assert_eq!(inputs[0].resource.name, "u_albedo");
+    assert_eq!(inputs[0].input_attachment_index, 1);
+    assert_eq!(inputs[0].color_index, 3);
+
+    compiler_options.use_framebuffer_fetch_subpasses = false;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(!ast.compile().unwrap().contains("[[color(3)]]"));
+    assert!(ast.get_framebuffer_fetch_inputs().unwrap().is_empty());
+}
+
+#[test]
+fn translates_to_msl_with_resource_indices() {
+    use spirv_cross::pack::PackedShader;
+
+    let spirv_bytes = include_bytes!("shaders/sampler.frag.spv");
+    let target = spirv_cross::TargetSpec::Msl {
+        version: msl::Version::V2_0,
+        platform: msl::Platform::macOS,
+    };
+    let translation = spirv_cross::translate(spirv_bytes, target).unwrap();
+
+    assert!(translation.source.contains("#include <metal_stdlib>"));
+    let indices = translation
+        .bindings
+        .iter()
+        .map(|binding| (binding.name.as_str(), binding.target_indices.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        indices,
+        [
+            ("u_texture", vec![spirv_cross::TargetIndex::MslTexture(0)]),
+            ("u_sampler", vec![spirv_cross::TargetIndex::MslSampler(0)]),
+        ]
+    );
+    let shader = PackedShader::new("sampler.frag", spirv_bytes, target, &translation);
+    assert_eq!(shader.target_version, "20000 macos");
+    assert_eq!(shader.target_spec().unwrap(), target);
+}
+
+#[test]
+fn emits_line_directives() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/source_files.comp.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(!ast.compile().unwrap().contains("#line"));
+
+    compiler_options.emit_line_directives = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    // The directives are emitted in the MSL entry point and the functions it calls, without
+    // the `GL_GOOGLE_cpp_style_line_directive` extension GLSL needs for file names.
+    let entry_point = shader.find("kernel void main0(").unwrap();
+    assert!(shader[entry_point..].contains("#line 4 \"shaders/main.comp\""));
+    assert!(shader.contains("#line 2 \"shaders/common.glsl\""));
+    assert!(!shader.contains("GL_GOOGLE_cpp_style_line_directive"));
+}
+
+#[test]
+fn reports_buffers_requiring_array_length() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/array_length.comp.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&msl::CompilerOptions::default())
+        .unwrap();
+    assert!(ast.get_buffers_requiring_array_length().is_err());
+
+    assert!(ast.compile().unwrap().contains("spvBufferSizeConstants"));
+    let buffers = ast.get_buffers_requiring_array_length().unwrap();
+    assert_eq!(buffers.len(), 1);
+    assert_eq!(buffers[0].name, "values");
+    assert!(ast.get_options_usage().unwrap().buffer_size_buffer_index);
+}
+
+#[test]
+fn rejects_shader_clock() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/clock.comp.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.compile(),
+        Err(spirv_cross::ErrorCode::CompilationError(String::from(
+            "OpReadClockKHR is not supported in MSL"
+        )))
+    );
+}
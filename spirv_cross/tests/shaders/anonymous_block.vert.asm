@@ -0,0 +1,38 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 19
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %gl_Position
+               OpSource HLSL 500
+               OpName %main "main"
+               OpMemberName %block 0 "scale"
+               OpName %gl_Position "gl_Position"
+               OpDecorate %block Block
+               OpMemberDecorate %block 0 Offset 0
+               OpDecorate %globals DescriptorSet 0
+               OpDecorate %globals Binding 0
+               OpDecorate %gl_Position BuiltIn Position
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+      %block = OpTypeStruct %float
+%_ptr_Uniform_block = OpTypePointer Uniform %block
+    %globals = OpVariable %_ptr_Uniform_block Uniform
+%_ptr_Uniform_float = OpTypePointer Uniform %float
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+%gl_Position = OpVariable %_ptr_Output_v4float Output
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %16 = OpAccessChain %_ptr_Uniform_float %globals %int_0
+         %17 = OpLoad %float %16
+         %18 = OpCompositeConstruct %v4float %17 %17 %17 %17
+               OpStore %gl_Position %18
+               OpReturn
+               OpFunctionEnd
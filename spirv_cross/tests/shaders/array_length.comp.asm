@@ -0,0 +1,51 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 23
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Values "Values"
+               OpMemberName %Values 0 "count"
+               OpMemberName %Values 1 "data"
+               OpName %values "values"
+               OpName %Other "Other"
+               OpMemberName %Other 0 "data"
+               OpName %other "other"
+               OpDecorate %_runtimearr_uint ArrayStride 4
+               OpMemberDecorate %Values 0 Offset 0
+               OpMemberDecorate %Values 1 Offset 4
+               OpDecorate %Values BufferBlock
+               OpDecorate %values DescriptorSet 0
+               OpDecorate %values Binding 0
+               OpMemberDecorate %Other 0 Offset 0
+               OpDecorate %Other BufferBlock
+               OpDecorate %other DescriptorSet 0
+               OpDecorate %other Binding 1
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+%_runtimearr_uint = OpTypeRuntimeArray %uint
+     %Values = OpTypeStruct %uint %_runtimearr_uint
+%_ptr_Uniform_Values = OpTypePointer Uniform %Values
+     %values = OpVariable %_ptr_Uniform_Values Uniform
+      %Other = OpTypeStruct %uint
+%_ptr_Uniform_Other = OpTypePointer Uniform %Other
+      %other = OpVariable %_ptr_Uniform_Other Uniform
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+%_ptr_Uniform_uint = OpTypePointer Uniform %uint
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpArrayLength %uint %values 1
+         %21 = OpAccessChain %_ptr_Uniform_uint %values %int_0
+               OpStore %21 %20
+         %22 = OpAccessChain %_ptr_Uniform_uint %other %int_0
+               OpStore %22 %20
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,39 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 19
+; Schema: 0
+               OpCapability Shader
+               OpCapability DrawParameters
+               OpExtension "SPV_KHR_shader_draw_parameters"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %gl_BaseVertex %gl_BaseInstance %gl_Position
+               OpSource GLSL 460
+               OpName %main "main"
+               OpName %gl_BaseVertex "gl_BaseVertex"
+               OpName %gl_BaseInstance "gl_BaseInstance"
+               OpName %gl_Position "gl_Position"
+               OpDecorate %gl_BaseVertex BuiltIn BaseVertex
+               OpDecorate %gl_BaseInstance BuiltIn BaseInstance
+               OpDecorate %gl_Position BuiltIn Position
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+        %int = OpTypeInt 32 1
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Input_int = OpTypePointer Input %int
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+%gl_BaseVertex = OpVariable %_ptr_Input_int Input
+%gl_BaseInstance = OpVariable %_ptr_Input_int Input
+%gl_Position = OpVariable %_ptr_Output_v4float Output
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %14 = OpLoad %int %gl_BaseVertex
+         %15 = OpLoad %int %gl_BaseInstance
+         %16 = OpIAdd %int %14 %15
+         %17 = OpConvertSToF %float %16
+         %18 = OpCompositeConstruct %v4float %17 %17 %17 %17
+               OpStore %gl_Position %18
+               OpReturn
+               OpFunctionEnd
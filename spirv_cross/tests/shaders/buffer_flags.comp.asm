@@ -0,0 +1,50 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 23
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Input "Input"
+               OpMemberName %Input 0 "value"
+               OpName %input "input"
+               OpName %Output "Output"
+               OpMemberName %Output 0 "value"
+               OpName %output "output"
+               OpMemberDecorate %Input 0 NonWritable
+               OpMemberDecorate %Input 0 Offset 0
+               OpDecorate %Input BufferBlock
+               OpDecorate %input DescriptorSet 0
+               OpDecorate %input Binding 0
+               OpDecorate %input Restrict
+               OpMemberDecorate %Output 0 Coherent
+               OpMemberDecorate %Output 0 NonReadable
+               OpMemberDecorate %Output 0 Offset 0
+               OpDecorate %Output BufferBlock
+               OpDecorate %output DescriptorSet 0
+               OpDecorate %output Binding 1
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+      %Input = OpTypeStruct %float
+%_ptr_Uniform_Input = OpTypePointer Uniform %Input
+      %input = OpVariable %_ptr_Uniform_Input Uniform
+     %Output = OpTypeStruct %float
+%_ptr_Uniform_Output = OpTypePointer Uniform %Output
+     %output = OpVariable %_ptr_Uniform_Output Uniform
+%_ptr_Uniform_float = OpTypePointer Uniform %float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpAccessChain %_ptr_Uniform_float %input %int_0
+         %21 = OpLoad %float %20
+         %22 = OpAccessChain %_ptr_Uniform_float %output %int_0
+               OpStore %22 %21
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,40 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 22
+; Schema: 0
+               OpCapability Shader
+               OpCapability ShaderClockKHR
+               OpExtension "SPV_KHR_shader_clock"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpSourceExtension "GL_ARB_shader_clock"
+               OpName %main "main"
+               OpName %Timings "Timings"
+               OpMemberName %Timings 0 "start"
+               OpName %timings "timings"
+               OpMemberDecorate %Timings 0 Offset 0
+               OpDecorate %Timings BufferBlock
+               OpDecorate %timings DescriptorSet 0
+               OpDecorate %timings Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+     %v2uint = OpTypeVector %uint 2
+        %int = OpTypeInt 32 1
+     %uint_3 = OpConstant %uint 3
+      %int_0 = OpConstant %int 0
+    %Timings = OpTypeStruct %v2uint
+%_ptr_Uniform_Timings = OpTypePointer Uniform %Timings
+    %timings = OpVariable %_ptr_Uniform_Timings Uniform
+%_ptr_Uniform_v2uint = OpTypePointer Uniform %v2uint
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpReadClockKHR %v2uint %uint_3
+         %21 = OpAccessChain %_ptr_Uniform_v2uint %timings %int_0
+               OpStore %21 %20
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,40 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 19
+; Schema: 0
+               OpCapability Shader
+               OpCapability Float16
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Buffer "Buffer"
+               OpMemberName %Buffer 0 "value"
+               OpName %buf "buf"
+               OpDecorate %Buffer BufferBlock
+               OpMemberDecorate %Buffer 0 Offset 0
+               OpDecorate %buf DescriptorSet 0
+               OpDecorate %buf Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+       %half = OpTypeFloat 16
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+     %Buffer = OpTypeStruct %float
+%_ptr_Uniform_Buffer = OpTypePointer Uniform %Buffer
+        %buf = OpVariable %_ptr_Uniform_Buffer Uniform
+%_ptr_Uniform_float = OpTypePointer Uniform %float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %14 = OpAccessChain %_ptr_Uniform_float %buf %int_0
+         %15 = OpLoad %float %14
+         %16 = OpFConvert %half %15
+         %17 = OpFMul %half %16 %16
+         %18 = OpFConvert %float %17
+               OpStore %14 %18
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,47 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 24
+; Schema: 0
+               OpCapability Shader
+               OpCapability Float16
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Buffer "Buffer"
+               OpMemberName %Buffer 0 "value"
+               OpMemberName %Buffer 1 "packed"
+               OpName %buf "buf"
+               OpDecorate %Buffer BufferBlock
+               OpMemberDecorate %Buffer 0 Offset 0
+               OpMemberDecorate %Buffer 1 Offset 4
+               OpDecorate %buf DescriptorSet 0
+               OpDecorate %buf Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+       %uint = OpTypeInt 32 0
+       %half = OpTypeFloat 16
+     %v2half = OpTypeVector %half 2
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+      %int_1 = OpConstant %int 1
+     %Buffer = OpTypeStruct %float %uint
+%_ptr_Uniform_Buffer = OpTypePointer Uniform %Buffer
+        %buf = OpVariable %_ptr_Uniform_Buffer Uniform
+%_ptr_Uniform_float = OpTypePointer Uniform %float
+%_ptr_Uniform_uint = OpTypePointer Uniform %uint
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %18 = OpAccessChain %_ptr_Uniform_float %buf %int_0
+         %19 = OpLoad %float %18
+         %20 = OpFConvert %half %19
+         %21 = OpCompositeConstruct %v2half %20 %20
+         %22 = OpBitcast %uint %21
+         %23 = OpAccessChain %_ptr_Uniform_uint %buf %int_1
+               OpStore %23 %22
+               OpReturn
+               OpFunctionEnd
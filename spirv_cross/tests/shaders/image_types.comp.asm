@@ -0,0 +1,58 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 30
+; Schema: 0
+               OpCapability Shader
+               OpCapability StorageImageMultisample
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %shadow_maps "shadow_maps"
+               OpName %source "source"
+               OpName %destination "destination"
+               OpName %resolve "resolve"
+               OpDecorate %shadow_maps DescriptorSet 0
+               OpDecorate %shadow_maps Binding 0
+               OpDecorate %source DescriptorSet 0
+               OpDecorate %source Binding 1
+               OpDecorate %source NonWritable
+               OpDecorate %destination DescriptorSet 0
+               OpDecorate %destination Binding 2
+               OpDecorate %destination NonReadable
+               OpDecorate %resolve DescriptorSet 0
+               OpDecorate %resolve Binding 3
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+        %int = OpTypeInt 32 1
+      %v2int = OpTypeVector %int 2
+      %v3int = OpTypeVector %int 3
+      %int_0 = OpConstant %int 0
+    %v2int_0 = OpConstantComposite %v2int %int_0 %int_0
+    %v3int_0 = OpConstantComposite %v3int %int_0 %int_0 %int_0
+%shadow_array = OpTypeImage %float 2D 1 1 0 1 Unknown
+%sampled_shadow_array = OpTypeSampledImage %shadow_array
+%_ptr_UniformConstant_sampled_shadow_array = OpTypePointer UniformConstant %sampled_shadow_array
+%shadow_maps = OpVariable %_ptr_UniformConstant_sampled_shadow_array UniformConstant
+%image_rgba8 = OpTypeImage %float 2D 0 0 0 2 Rgba8
+%_ptr_UniformConstant_image_rgba8 = OpTypePointer UniformConstant %image_rgba8
+     %source = OpVariable %_ptr_UniformConstant_image_rgba8 UniformConstant
+%image_r32f = OpTypeImage %float 3D 0 0 0 2 R32f
+%_ptr_UniformConstant_image_r32f = OpTypePointer UniformConstant %image_r32f
+%destination = OpVariable %_ptr_UniformConstant_image_r32f UniformConstant
+%image_ms = OpTypeImage %float 2D 0 0 1 2 Rgba16f
+%_ptr_UniformConstant_image_ms = OpTypePointer UniformConstant %image_ms
+    %resolve = OpVariable %_ptr_UniformConstant_image_ms UniformConstant
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+          %6 = OpLoad %image_rgba8 %source
+          %7 = OpImageRead %v4float %6 %v2int_0
+          %8 = OpLoad %image_r32f %destination
+               OpImageWrite %8 %v3int_0 %7
+               OpReturn
+               OpFunctionEnd
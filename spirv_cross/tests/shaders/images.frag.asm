@@ -0,0 +1,68 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 48
+; Schema: 0
+               OpCapability Shader
+               OpCapability StorageImageWriteWithoutFormat
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %frag_color
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %frag_color "frag_color"
+               OpName %environment "environment"
+               OpName %volume "volume"
+               OpName %volume_sampler "volume_sampler"
+               OpName %layers "layers"
+               OpDecorate %frag_color Location 0
+               OpDecorate %environment DescriptorSet 0
+               OpDecorate %environment Binding 0
+               OpDecorate %volume DescriptorSet 0
+               OpDecorate %volume Binding 1
+               OpDecorate %volume_sampler DescriptorSet 0
+               OpDecorate %volume_sampler Binding 2
+               OpDecorate %layers DescriptorSet 0
+               OpDecorate %layers Binding 3
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v3float = OpTypeVector %float 3
+    %v4float = OpTypeVector %float 4
+        %int = OpTypeInt 32 1
+      %v3int = OpTypeVector %int 3
+    %float_0 = OpConstant %float 0
+      %int_0 = OpConstant %int 0
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+ %frag_color = OpVariable %_ptr_Output_v4float Output
+       %cube = OpTypeImage %float Cube 0 0 0 1 Unknown
+%sampled_cube = OpTypeSampledImage %cube
+%_ptr_UniformConstant_sampled_cube = OpTypePointer UniformConstant %sampled_cube
+%environment = OpVariable %_ptr_UniformConstant_sampled_cube UniformConstant
+   %image_3d = OpTypeImage %float 3D 0 0 0 1 Unknown
+%_ptr_UniformConstant_image_3d = OpTypePointer UniformConstant %image_3d
+     %volume = OpVariable %_ptr_UniformConstant_image_3d UniformConstant
+    %sampler = OpTypeSampler
+%_ptr_UniformConstant_sampler = OpTypePointer UniformConstant %sampler
+%volume_sampler = OpVariable %_ptr_UniformConstant_sampler UniformConstant
+%sampled_image_3d = OpTypeSampledImage %image_3d
+%storage_2d_array = OpTypeImage %float 2D 0 1 0 2 Unknown
+%_ptr_UniformConstant_storage_2d_array = OpTypePointer UniformConstant %storage_2d_array
+     %layers = OpVariable %_ptr_UniformConstant_storage_2d_array UniformConstant
+     %coords = OpConstantComposite %v3float %float_0 %float_0 %float_0
+  %int_coords = OpConstantComposite %v3int %int_0 %int_0 %int_0
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %40 = OpLoad %sampled_cube %environment
+         %41 = OpImageSampleImplicitLod %v4float %40 %coords
+         %42 = OpLoad %image_3d %volume
+         %43 = OpLoad %sampler %volume_sampler
+         %44 = OpSampledImage %sampled_image_3d %42 %43
+         %45 = OpImageSampleImplicitLod %v4float %44 %coords
+         %46 = OpFAdd %v4float %41 %45
+         %47 = OpLoad %storage_2d_array %layers
+               OpImageWrite %47 %int_coords %46
+               OpStore %frag_color %46
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,28 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 13
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %v_instance %gl_InstanceIndex
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %v_instance "v_instance"
+               OpName %gl_InstanceIndex "gl_InstanceIndex"
+               OpDecorate %v_instance Location 0
+               OpDecorate %gl_InstanceIndex BuiltIn InstanceIndex
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+        %int = OpTypeInt 32 1
+%_ptr_Output_int = OpTypePointer Output %int
+ %v_instance = OpVariable %_ptr_Output_int Output
+%_ptr_Input_int = OpTypePointer Input %int
+%gl_InstanceIndex = OpVariable %_ptr_Input_int Input
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %12 = OpLoad %int %gl_InstanceIndex
+               OpStore %v_instance %12
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,37 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 19
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %v_out %a_color
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %VertexData "VertexData"
+               OpMemberName %VertexData 0 "color"
+               OpName %v_out "v_out"
+               OpName %a_color "a_color"
+               OpDecorate %VertexData Block
+               OpDecorate %v_out Location 0
+               OpDecorate %a_color Location 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+ %VertexData = OpTypeStruct %v4float
+%_ptr_Output_VertexData = OpTypePointer Output %VertexData
+      %v_out = OpVariable %_ptr_Output_VertexData Output
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+%_ptr_Input_v4float = OpTypePointer Input %v4float
+    %a_color = OpVariable %_ptr_Input_v4float Input
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %16 = OpLoad %v4float %a_color
+         %18 = OpAccessChain %_ptr_Output_v4float %v_out %int_0
+               OpStore %18 %16
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,30 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 13
+; Schema: 0
+               OpCapability Shader
+               OpCapability ShaderViewportIndexLayerEXT
+               OpExtension "SPV_EXT_shader_viewport_index_layer"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %gl_Layer %gl_InstanceIndex
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %gl_Layer "gl_Layer"
+               OpName %gl_InstanceIndex "gl_InstanceIndex"
+               OpDecorate %gl_Layer BuiltIn Layer
+               OpDecorate %gl_InstanceIndex BuiltIn InstanceIndex
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+        %int = OpTypeInt 32 1
+%_ptr_Output_int = OpTypePointer Output %int
+   %gl_Layer = OpVariable %_ptr_Output_int Output
+%_ptr_Input_int = OpTypePointer Input %int
+%gl_InstanceIndex = OpVariable %_ptr_Input_int Input
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %12 = OpLoad %int %gl_InstanceIndex
+               OpStore %gl_Layer %12
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,33 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 17
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %a_transform %gl_Position
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %a_transform "a_transform"
+               OpName %gl_Position "gl_Position"
+               OpDecorate %a_transform Location 0
+               OpDecorate %gl_Position BuiltIn Position
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %float_1 = OpConstant %float 1
+    %v4float = OpTypeVector %float 4
+%mat4v4float = OpTypeMatrix %v4float 4
+%_ptr_Input_mat4v4float = OpTypePointer Input %mat4v4float
+%a_transform = OpVariable %_ptr_Input_mat4v4float Input
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+%gl_Position = OpVariable %_ptr_Output_v4float Output
+       %v4_one = OpConstantComposite %v4float %float_1 %float_1 %float_1 %float_1
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %14 = OpLoad %mat4v4float %a_transform
+         %15 = OpMatrixTimesVector %v4float %14 %v4_one
+               OpStore %gl_Position %15
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,33 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 16
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %v_transform %v_color
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %v_transform "v_transform"
+               OpName %v_color "v_color"
+               OpDecorate %v_transform Location 0
+               OpDecorate %v_color Location 4
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %float_1 = OpConstant %float 1
+    %v4float = OpTypeVector %float 4
+%mat4v4float = OpTypeMatrix %v4float 4
+%_ptr_Output_mat4v4float = OpTypePointer Output %mat4v4float
+%v_transform = OpVariable %_ptr_Output_mat4v4float Output
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+    %v_color = OpVariable %_ptr_Output_v4float Output
+     %v4_one = OpConstantComposite %v4float %float_1 %float_1 %float_1 %float_1
+   %mat4_one = OpConstantComposite %mat4v4float %v4_one %v4_one %v4_one %v4_one
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpStore %v_transform %mat4_one
+               OpStore %v_color %v4_one
+               OpReturn
+               OpFunctionEnd
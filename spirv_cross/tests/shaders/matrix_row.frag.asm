@@ -0,0 +1,39 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 19
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %v_row %v_color %v_unlocated %frag_color
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %v_row "v_row"
+               OpName %v_color "v_color"
+               OpName %v_unlocated "v_unlocated"
+               OpName %frag_color "frag_color"
+               OpDecorate %v_row Location 2
+               OpDecorate %v_color Location 4
+               OpDecorate %frag_color Location 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Input_v4float = OpTypePointer Input %v4float
+      %v_row = OpVariable %_ptr_Input_v4float Input
+    %v_color = OpVariable %_ptr_Input_v4float Input
+%v_unlocated = OpVariable %_ptr_Input_v4float Input
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+ %frag_color = OpVariable %_ptr_Output_v4float Output
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %14 = OpLoad %v4float %v_row
+         %15 = OpLoad %v4float %v_color
+         %16 = OpLoad %v4float %v_unlocated
+         %17 = OpFAdd %v4float %14 %15
+         %18 = OpFAdd %v4float %17 %16
+               OpStore %frag_color %18
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,35 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 17
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %color %v_a %v_b
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %color "color"
+               OpName %v_a "v_a"
+               OpName %v_b "v_b"
+               OpDecorate %color Location 0
+               OpDecorate %v_a Location 0
+               OpDecorate %v_b Location 1
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+      %color = OpVariable %_ptr_Output_v4float Output
+%_ptr_Input_v4float = OpTypePointer Input %v4float
+        %v_a = OpVariable %_ptr_Input_v4float Input
+        %v_b = OpVariable %_ptr_Input_v4float Input
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %14 = OpLoad %v4float %v_a
+         %15 = OpLoad %v4float %v_b
+         %16 = OpExtInst %v4float %1 NMin %14 %15
+               OpStore %color %16
+               OpReturn
+               OpFunctionEnd
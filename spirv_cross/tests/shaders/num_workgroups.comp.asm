@@ -0,0 +1,41 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 19
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main" %gl_NumWorkGroups
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Buffer "Buffer"
+               OpMemberName %Buffer 0 "count"
+               OpName %buf "buf"
+               OpName %gl_NumWorkGroups "gl_NumWorkGroups"
+               OpDecorate %Buffer BufferBlock
+               OpMemberDecorate %Buffer 0 Offset 0
+               OpDecorate %buf DescriptorSet 0
+               OpDecorate %buf Binding 0
+               OpDecorate %gl_NumWorkGroups BuiltIn NumWorkgroups
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+     %v3uint = OpTypeVector %uint 3
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+     %Buffer = OpTypeStruct %uint
+%_ptr_Uniform_Buffer = OpTypePointer Uniform %Buffer
+        %buf = OpVariable %_ptr_Uniform_Buffer Uniform
+%_ptr_Uniform_uint = OpTypePointer Uniform %uint
+%_ptr_Input_v3uint = OpTypePointer Input %v3uint
+%gl_NumWorkGroups = OpVariable %_ptr_Input_v3uint Input
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %16 = OpLoad %v3uint %gl_NumWorkGroups
+         %17 = OpCompositeExtract %uint %16 0
+         %18 = OpAccessChain %_ptr_Uniform_uint %buf %int_0
+               OpStore %18 %17
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,57 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 28
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %_
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %gl_PerVertex "gl_PerVertex"
+               OpMemberName %gl_PerVertex 0 "gl_Position"
+               OpName %_ ""
+               OpName %Globals "Globals"
+               OpMemberName %Globals 0 "tint"
+               OpMemberName %Globals 1 "unused"
+               OpMemberName %Globals 2 "offset"
+               OpName %globals "globals"
+               OpMemberDecorate %gl_PerVertex 0 BuiltIn Position
+               OpDecorate %gl_PerVertex Block
+               OpMemberDecorate %Globals 0 Offset 0
+               OpMemberDecorate %Globals 1 Offset 16
+               OpMemberDecorate %Globals 2 Offset 272
+               OpDecorate %_arr_v4float_uint_16 ArrayStride 16
+               OpDecorate %Globals Block
+               OpDecorate %globals DescriptorSet 0
+               OpDecorate %globals Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+       %uint = OpTypeInt 32 0
+    %uint_16 = OpConstant %uint 16
+%_arr_v4float_uint_16 = OpTypeArray %v4float %uint_16
+%gl_PerVertex = OpTypeStruct %v4float
+%_ptr_Output_gl_PerVertex = OpTypePointer Output %gl_PerVertex
+          %_ = OpVariable %_ptr_Output_gl_PerVertex Output
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+      %int_2 = OpConstant %int 2
+    %Globals = OpTypeStruct %v4float %_arr_v4float_uint_16 %v4float
+%_ptr_Uniform_Globals = OpTypePointer Uniform %Globals
+    %globals = OpVariable %_ptr_Uniform_Globals Uniform
+%_ptr_Uniform_v4float = OpTypePointer Uniform %v4float
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpAccessChain %_ptr_Uniform_v4float %globals %int_0
+         %21 = OpLoad %v4float %20
+         %22 = OpAccessChain %_ptr_Uniform_v4float %globals %int_2
+         %23 = OpLoad %v4float %22
+         %24 = OpFAdd %v4float %21 %23
+         %25 = OpAccessChain %_ptr_Output_v4float %_ %int_0
+               OpStore %25 %24
+               OpReturn
+               OpFunctionEnd
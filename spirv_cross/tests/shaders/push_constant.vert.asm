@@ -0,0 +1,43 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 21
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %_
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %gl_PerVertex "gl_PerVertex"
+               OpMemberName %gl_PerVertex 0 "gl_Position"
+               OpName %_ ""
+               OpName %push_constants "push_constants"
+               OpMemberName %push_constants 0 "offset"
+               OpName %pc "pc"
+               OpMemberDecorate %gl_PerVertex 0 BuiltIn Position
+               OpDecorate %gl_PerVertex Block
+               OpMemberDecorate %push_constants 0 Offset 0
+               OpDecorate %push_constants Block
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%gl_PerVertex = OpTypeStruct %v4float
+%_ptr_Output_gl_PerVertex = OpTypePointer Output %gl_PerVertex
+          %_ = OpVariable %_ptr_Output_gl_PerVertex Output
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+%push_constants = OpTypeStruct %v4float
+%_ptr_PushConstant_push_constants = OpTypePointer PushConstant %push_constants
+         %pc = OpVariable %_ptr_PushConstant_push_constants PushConstant
+%_ptr_PushConstant_v4float = OpTypePointer PushConstant %v4float
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %18 = OpAccessChain %_ptr_PushConstant_v4float %pc %int_0
+         %19 = OpLoad %v4float %18
+         %20 = OpAccessChain %_ptr_Output_v4float %_ %int_0
+               OpStore %20 %19
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,45 @@
+; SPIR-V
+; Version: 1.4
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 26
+; Schema: 0
+               OpCapability Shader
+               OpCapability RayQueryKHR
+               OpExtension "SPV_KHR_ray_query"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main" %tlas
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 460
+               OpSourceExtension "GL_EXT_ray_query"
+               OpName %main "main"
+               OpName %query "query"
+               OpName %tlas "tlas"
+               OpDecorate %tlas DescriptorSet 0
+               OpDecorate %tlas Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %bool = OpTypeBool
+       %uint = OpTypeInt 32 0
+     %uint_0 = OpConstant %uint 0
+   %uint_255 = OpConstant %uint 255
+      %float = OpTypeFloat 32
+    %float_0 = OpConstant %float 0
+    %float_1 = OpConstant %float 1
+  %float_100 = OpConstant %float 100
+    %v3float = OpTypeVector %float 3
+   %v3_zero = OpConstantComposite %v3float %float_0 %float_0 %float_0
+    %v3_dir = OpConstantComposite %v3float %float_0 %float_0 %float_1
+%accel = OpTypeAccelerationStructureKHR
+%_ptr_UniformConstant_accel = OpTypePointer UniformConstant %accel
+       %tlas = OpVariable %_ptr_UniformConstant_accel UniformConstant
+   %rayQuery = OpTypeRayQueryKHR
+%_ptr_Function_rayQuery = OpTypePointer Function %rayQuery
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+      %query = OpVariable %_ptr_Function_rayQuery Function
+         %24 = OpLoad %accel %tlas
+               OpRayQueryInitializeKHR %query %24 %uint_0 %uint_255 %v3_zero %float_0 %v3_dir %float_100
+         %25 = OpRayQueryProceedKHR %bool %query
+               OpReturn
+               OpFunctionEnd
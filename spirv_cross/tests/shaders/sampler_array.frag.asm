@@ -0,0 +1,49 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 28
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %color %v_index
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %color "color"
+               OpName %u_textures "u_textures"
+               OpName %v_index "v_index"
+               OpDecorate %color Location 0
+               OpDecorate %u_textures DescriptorSet 0
+               OpDecorate %u_textures Binding 0
+               OpDecorate %v_index Flat
+               OpDecorate %v_index Location 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+      %color = OpVariable %_ptr_Output_v4float Output
+         %10 = OpTypeImage %float 2D 0 0 0 1 Unknown
+         %11 = OpTypeSampledImage %10
+       %uint = OpTypeInt 32 0
+     %uint_4 = OpConstant %uint 4
+%_arr_11_uint_4 = OpTypeArray %11 %uint_4
+%_ptr_UniformConstant__arr_11_uint_4 = OpTypePointer UniformConstant %_arr_11_uint_4
+ %u_textures = OpVariable %_ptr_UniformConstant__arr_11_uint_4 UniformConstant
+        %int = OpTypeInt 32 1
+%_ptr_Input_int = OpTypePointer Input %int
+    %v_index = OpVariable %_ptr_Input_int Input
+%_ptr_UniformConstant_11 = OpTypePointer UniformConstant %11
+    %v2float = OpTypeVector %float 2
+    %float_0 = OpConstant %float 0
+         %25 = OpConstantComposite %v2float %float_0 %float_0
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpLoad %int %v_index
+         %21 = OpAccessChain %_ptr_UniformConstant_11 %u_textures %20
+         %22 = OpLoad %11 %21
+         %26 = OpImageSampleImplicitLod %v4float %22 %25
+               OpStore %color %26
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,34 @@
+; SPIR-V
+; Version: 1.4
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 14
+; Schema: 0
+               OpCapability RayTracingKHR
+               OpExtension "SPV_KHR_ray_tracing"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint RayGenerationKHR %main "main" %tlas %record
+               OpSource GLSL 460
+               OpName %main "main"
+               OpName %tlas "tlas"
+               OpName %Record "Record"
+               OpMemberName %Record 0 "color"
+               OpName %record "record"
+               OpDecorate %tlas DescriptorSet 0
+               OpDecorate %tlas Binding 0
+               OpMemberDecorate %Record 0 Offset 0
+               OpDecorate %Record Block
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+      %accel = OpTypeAccelerationStructureKHR
+%_ptr_UniformConstant_accel = OpTypePointer UniformConstant %accel
+       %tlas = OpVariable %_ptr_UniformConstant_accel UniformConstant
+     %Record = OpTypeStruct %v4float
+%_ptr_ShaderRecordBufferKHR_Record = OpTypePointer ShaderRecordBufferKHR %Record
+     %record = OpVariable %_ptr_ShaderRecordBufferKHR_Record ShaderRecordBufferKHR
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,37 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 14
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpEntryPoint GLCompute %other "other"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpExecutionMode %other LocalSize 1 1 1
+          %2 = OpString "shaders/main.comp"
+          %3 = OpString "shaders/common.glsl"
+          %4 = OpString "shaders/other.comp"
+               OpSource GLSL 450 %2
+               OpName %main "main"
+               OpName %other "other"
+               OpName %helper "helper("
+       %void = OpTypeVoid
+          %6 = OpTypeFunction %void
+       %main = OpFunction %void None %6
+          %7 = OpLabel
+               OpLine %2 4 0
+          %8 = OpFunctionCall %void %helper
+               OpReturn
+               OpFunctionEnd
+     %helper = OpFunction %void None %6
+          %9 = OpLabel
+               OpLine %3 2 0
+               OpReturn
+               OpFunctionEnd
+      %other = OpFunction %void None %6
+         %10 = OpLabel
+               OpLine %4 1 0
+               OpReturn
+               OpFunctionEnd
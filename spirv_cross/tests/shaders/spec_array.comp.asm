@@ -0,0 +1,41 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 21
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %COUNT "COUNT"
+               OpName %Values "Values"
+               OpMemberName %Values 0 "data"
+               OpName %values "values"
+               OpDecorate %COUNT SpecId 0
+               OpDecorate %_arr_float_COUNT ArrayStride 4
+               OpMemberDecorate %Values 0 Offset 0
+               OpDecorate %Values BufferBlock
+               OpDecorate %values DescriptorSet 0
+               OpDecorate %values Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+       %uint = OpTypeInt 32 0
+      %COUNT = OpSpecConstant %uint 4
+%_arr_float_COUNT = OpTypeArray %float %COUNT
+     %Values = OpTypeStruct %_arr_float_COUNT
+%_ptr_Uniform_Values = OpTypePointer Uniform %Values
+     %values = OpVariable %_ptr_Uniform_Values Uniform
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+    %float_1 = OpConstant %float 1
+%_ptr_Uniform_float = OpTypePointer Uniform %float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpAccessChain %_ptr_Uniform_float %values %int_0 %int_0
+               OpStore %20 %float_1
+               OpReturn
+               OpFunctionEnd
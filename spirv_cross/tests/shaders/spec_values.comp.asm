@@ -0,0 +1,56 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 24
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %SCALE "SCALE"
+               OpName %COUNT "COUNT"
+               OpName %ENABLED "ENABLED"
+               OpName %Values "Values"
+               OpMemberName %Values 0 "scale"
+               OpMemberName %Values 1 "count"
+               OpName %values "values"
+               OpDecorate %SCALE SpecId 0
+               OpDecorate %COUNT SpecId 1
+               OpDecorate %ENABLED SpecId 2
+               OpMemberDecorate %Values 0 Offset 0
+               OpMemberDecorate %Values 1 Offset 4
+               OpDecorate %Values BufferBlock
+               OpDecorate %values DescriptorSet 0
+               OpDecorate %values Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+       %uint = OpTypeInt 32 0
+       %bool = OpTypeBool
+        %int = OpTypeInt 32 1
+      %SCALE = OpSpecConstant %float 1.0
+      %COUNT = OpSpecConstant %uint 1
+    %ENABLED = OpSpecConstantFalse %bool
+     %Values = OpTypeStruct %float %uint
+%_ptr_Uniform_Values = OpTypePointer Uniform %Values
+     %values = OpVariable %_ptr_Uniform_Values Uniform
+      %int_0 = OpConstant %int 0
+      %int_1 = OpConstant %int 1
+%_ptr_Uniform_float = OpTypePointer Uniform %float
+%_ptr_Uniform_uint = OpTypePointer Uniform %uint
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpAccessChain %_ptr_Uniform_float %values %int_0
+               OpStore %20 %SCALE
+               OpSelectionMerge %22 None
+               OpBranchConditional %ENABLED %21 %22
+         %21 = OpLabel
+         %23 = OpAccessChain %_ptr_Uniform_uint %values %int_1
+               OpStore %23 %COUNT
+               OpBranch %22
+         %22 = OpLabel
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,58 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 34
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %a_position %a_unused %out_position
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %a_position "a_position"
+               OpName %a_unused "a_unused"
+               OpName %out_position "out_position"
+               OpName %Used "Used"
+               OpMemberName %Used 0 "offset"
+               OpName %used "used"
+               OpName %Unused "Unused"
+               OpMemberName %Unused 0 "scale"
+               OpName %unused "unused"
+               OpDecorate %a_position Location 0
+               OpDecorate %a_unused Location 1
+               OpDecorate %out_position BuiltIn Position
+               OpMemberDecorate %Used 0 Offset 0
+               OpDecorate %Used Block
+               OpDecorate %used DescriptorSet 0
+               OpDecorate %used Binding 0
+               OpMemberDecorate %Unused 0 Offset 0
+               OpDecorate %Unused Block
+               OpDecorate %unused DescriptorSet 0
+               OpDecorate %unused Binding 1
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Input_v4float = OpTypePointer Input %v4float
+ %a_position = OpVariable %_ptr_Input_v4float Input
+   %a_unused = OpVariable %_ptr_Input_v4float Input
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+%out_position = OpVariable %_ptr_Output_v4float Output
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+       %Used = OpTypeStruct %v4float
+%_ptr_Uniform_Used = OpTypePointer Uniform %Used
+       %used = OpVariable %_ptr_Uniform_Used Uniform
+     %Unused = OpTypeStruct %v4float
+%_ptr_Uniform_Unused = OpTypePointer Uniform %Unused
+     %unused = OpVariable %_ptr_Uniform_Unused Uniform
+%_ptr_Uniform_v4float = OpTypePointer Uniform %v4float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %30 = OpLoad %v4float %a_position
+         %31 = OpAccessChain %_ptr_Uniform_v4float %used %int_0
+         %32 = OpLoad %v4float %31
+         %33 = OpFAdd %v4float %30 %32
+               OpStore %out_position %33
+               OpReturn
+               OpFunctionEnd
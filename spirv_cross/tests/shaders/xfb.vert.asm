@@ -0,0 +1,50 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos Glslang Reference Front End; 10
+; Bound: 24
+; Schema: 0
+               OpCapability Shader
+               OpCapability TransformFeedback
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %v_position %outputs
+               OpExecutionMode %main Xfb
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %v_position "v_position"
+               OpName %Outputs "Outputs"
+               OpMemberName %Outputs 0 "normal"
+               OpMemberName %Outputs 1 "color"
+               OpMemberName %Outputs 2 "uv"
+               OpName %outputs "outputs"
+               OpDecorate %v_position Location 0
+               OpDecorate %v_position XfbBuffer 0
+               OpDecorate %v_position XfbStride 16
+               OpDecorate %v_position Offset 0
+               OpDecorate %Outputs Block
+               OpMemberDecorate %Outputs 0 Offset 16
+               OpMemberDecorate %Outputs 2 Offset 0
+               OpDecorate %outputs Location 1
+               OpDecorate %outputs XfbBuffer 1
+               OpDecorate %outputs XfbStride 32
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+    %v2float = OpTypeVector %float 2
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+ %v_position = OpVariable %_ptr_Output_v4float Output
+    %Outputs = OpTypeStruct %v4float %v4float %v2float
+%_ptr_Output_Outputs = OpTypePointer Output %Outputs
+    %outputs = OpVariable %_ptr_Output_Outputs Output
+    %float_1 = OpConstant %float 1
+         %20 = OpConstantComposite %v4float %float_1 %float_1 %float_1 %float_1
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpStore %v_position %20
+         %23 = OpAccessChain %_ptr_Output_v4float %outputs %int_0
+               OpStore %23 %20
+               OpReturn
+               OpFunctionEnd
@@ -148,6 +148,71 @@ fn ast_gets_array_dimensions() {
     assert!(is_struct);
 }
 
+#[test]
+fn ast_gets_type_strings() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/array.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let base_type_id = ast.get_shader_resources().unwrap().uniform_buffers[0].base_type_id;
+    let member_types = match ast.get_type(base_type_id).unwrap() {
+        spirv::Type::Struct { member_types, .. } => member_types,
+        _ => panic!("uniform buffer is not a struct"),
+    };
+
+    assert_eq!(
+        ast.get_type_string(base_type_id).unwrap(),
+        "uniform_buffer_object"
+    );
+    assert_eq!(ast.get_type_string(member_types[0]).unwrap(), "mat4x4<f32>");
+    assert_eq!(ast.get_type_string(member_types[1]).unwrap(), "f32");
+    assert_eq!(
+        ast.get_type_string(member_types[2]).unwrap(),
+        "array<vec3<f32>, 3>"
+    );
+}
+
+#[test]
+fn ast_gets_type_string_of_images_and_specialization_constant_arrays() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/image_types.comp.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let resources = ast.get_shader_resources().unwrap();
+    assert_eq!(
+        ast.get_type_string(resources.sampled_images[0].base_type_id)
+            .unwrap(),
+        "sampled_texture_depth_2d_array"
+    );
+    let storage_images = resources
+        .storage_images
+        .iter()
+        .map(|image| ast.get_type_string(image.base_type_id).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        storage_images,
+        [
+            "texture_storage_2d",
+            "texture_storage_3d",
+            "texture_storage_multisampled_2d"
+        ]
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/spec_array.comp.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let storage_buffer = &ast.get_shader_resources().unwrap().storage_buffers[0];
+    let member_types = match ast.get_type(storage_buffer.base_type_id).unwrap() {
+        spirv::Type::Struct { member_types, .. } => member_types,
+        _ => panic!("storage buffer should be a struct"),
+    };
+    assert_eq!(
+        ast.get_type_string(member_types[0]).unwrap(),
+        "array<f32, COUNT>"
+    );
+}
+
 #[test]
 fn ast_gets_declared_struct_size_and_struct_member_size() {
     let module =
@@ -236,6 +301,72 @@ fn ast_sets_member_decoration() {
     );
 }
 
+#[test]
+fn ast_unsets_decoration() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let stage_inputs = ast.get_shader_resources().unwrap().stage_inputs;
+    let id = stage_inputs[0].id;
+    ast.set_decoration(id, spirv::Decoration::NonUniform, 0)
+        .unwrap();
+    assert!(ast
+        .has_decoration(id, spirv::Decoration::NonUniform)
+        .unwrap());
+    ast.unset_decoration(id, spirv::Decoration::NonUniform)
+        .unwrap();
+    assert!(!ast
+        .has_decoration(id, spirv::Decoration::NonUniform)
+        .unwrap());
+}
+
+#[test]
+fn ast_unsets_member_decoration() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    let id = uniform_buffers[0].base_type_id;
+    assert!(ast
+        .has_member_decoration(id, 0, spirv::Decoration::ColMajor)
+        .unwrap());
+    ast.unset_member_decoration(id, 0, spirv::Decoration::ColMajor)
+        .unwrap();
+    assert!(!ast
+        .has_member_decoration(id, 0, spirv::Decoration::ColMajor)
+        .unwrap());
+}
+
+#[test]
+fn ast_tracks_state_and_resets_to_parsed() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(ast.state(), spirv::AstState::Parsed);
+
+    ast.set_compiler_options(&lang::CompilerOptions::default())
+        .unwrap();
+    assert_eq!(ast.state(), spirv::AstState::OptionsSet);
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    let id = uniform_buffers[0].id;
+    let name = ast.get_name(id).unwrap();
+    ast.set_name(id, "renamed").unwrap();
+    let compiled = ast.compile().unwrap();
+    assert_eq!(ast.state(), spirv::AstState::Compiled);
+    assert!(compiled.contains("renamed"));
+
+    ast.reset_to_parsed().unwrap();
+    assert_eq!(ast.state(), spirv::AstState::Parsed);
+    assert_eq!(ast.get_name(id).unwrap(), name);
+    assert!(ast
+        .get_cleansed_entry_point_name("main", spirv::ExecutionModel::Vertex)
+        .is_err());
+    assert!(!ast.compile().unwrap().contains("renamed"));
+}
+
 #[test]
 fn ast_gets_specialization_constants() {
     let comp = spirv::Module::from_words(words_from_bytes(include_bytes!(
@@ -246,6 +377,28 @@ fn ast_gets_specialization_constants() {
     assert_eq!(specialization_constants[0].constant_id, 10);
 }
 
+#[test]
+fn ast_sets_typed_scalar_constants() {
+    let comp = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/spec_values.comp.spv"
+    )));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&comp).unwrap();
+    let ids = ast
+        .get_specialization_constants()
+        .unwrap()
+        .into_iter()
+        .map(|constant| (constant.constant_id, constant.id))
+        .collect::<std::collections::BTreeMap<_, _>>();
+
+    ast.set_scalar_constant_f32(ids[&0], 2.5).unwrap();
+    ast.set_scalar_constant_u32(ids[&1], 7).unwrap();
+    ast.set_scalar_constant_bool(ids[&2], true).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("2.5"));
+    assert!(shader.contains("7u"));
+    assert!(shader.contains("true"));
+}
+
 #[test]
 fn ast_gets_work_group_size_specialization_constants() {
     let comp = spirv::Module::from_words(words_from_bytes(include_bytes!(
@@ -274,6 +427,54 @@ fn ast_gets_work_group_size_specialization_constants() {
     );
 }
 
+#[test]
+fn ast_overrides_work_group_size_specialization_constants() {
+    let comp = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/workgroup.comp.spv"
+    )));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&comp).unwrap();
+    let work_group_size = ast.get_work_group_size_specialization_constants().unwrap();
+    ast.set_scalar_constant_u32(work_group_size.x.id, 64)
+        .unwrap();
+    assert!(ast.compile().unwrap().contains("64"));
+}
+
+#[test]
+fn ast_gets_active_interface_variables() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/unused_interface.vert.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let resources = ast.get_shader_resources().unwrap();
+    assert_eq!(resources.stage_inputs.len(), 2);
+    assert_eq!(resources.uniform_buffers.len(), 2);
+    let id_of = |resources: &[spirv::Resource], name: &str| {
+        resources.iter().find(|r| r.name == name).unwrap().id
+    };
+    let a_position = id_of(&resources.stage_inputs, "a_position");
+    let a_unused = id_of(&resources.stage_inputs, "a_unused");
+    let used = id_of(&resources.uniform_buffers, "Used");
+    let unused = id_of(&resources.uniform_buffers, "Unused");
+
+    let active = ast.get_active_interface_variables().unwrap();
+    assert!(active.contains(&a_position));
+    assert!(active.contains(&used));
+    assert!(!active.contains(&a_unused));
+    assert!(!active.contains(&unused));
+
+    let active_resources = ast.get_active_shader_resources().unwrap();
+    let names = |resources: &[spirv::Resource]| {
+        resources.iter().map(|r| r.name.clone()).collect::<Vec<_>>()
+    };
+    assert_eq!(names(&active_resources.stage_inputs), ["a_position"]);
+    assert_eq!(names(&active_resources.uniform_buffers), ["Used"]);
+
+    assert!(ast.compile().unwrap().contains("a_unused"));
+    ast.set_enabled_interface_variables(&active).unwrap();
+    assert!(!ast.compile().unwrap().contains("a_unused"));
+}
+
 #[test]
 fn ast_gets_active_buffer_ranges() {
     let module =
@@ -327,3 +528,972 @@ fn ast_gets_active_buffer_ranges() {
         ]
     );
 }
+
+#[test]
+fn ast_omits_unused_members_from_active_buffer_ranges() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/partial_ubo.vert.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    let ranges = ast.get_active_buffer_ranges(uniform_buffers[0].id).unwrap();
+    assert_eq!(
+        ranges,
+        [
+            spirv::BufferRange {
+                index: 0,
+                offset: 0,
+                range: 16,
+            },
+            spirv::BufferRange {
+                index: 2,
+                offset: 272,
+                range: 16,
+            }
+        ]
+    );
+    assert_eq!(
+        ast.get_member_name(uniform_buffers[0].base_type_id, ranges[1].index)
+            .unwrap(),
+        "offset"
+    );
+}
+
+#[test]
+fn ast_gets_declared_capabilities_and_extensions() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/multiple_entry_points.cl.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert_eq!(
+        ast.get_declared_capabilities().unwrap(),
+        [spirv::Capability::Shader]
+    );
+    assert_eq!(
+        ast.get_declared_extensions().unwrap(),
+        ["SPV_KHR_storage_buffer_storage_class"]
+    );
+}
+
+#[test]
+fn ast_gets_geometry_execution_modes() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/geometry.geom.asm.spv"
+    )));
+    let modes = spirv::Ast::<lang::Target>::parse(&module)
+        .unwrap()
+        .get_geometry_execution_modes()
+        .unwrap();
+
+    assert_eq!(
+        modes,
+        spirv::GeometryExecutionModes {
+            invocations: 2,
+            input_primitive: Some(spirv::GeometryInputPrimitive::Triangles),
+            output_primitive: Some(spirv::GeometryOutputPrimitive::TriangleStrip),
+            max_vertices: 3,
+        }
+    );
+}
+
+#[test]
+fn ast_gets_execution_modes() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/geometry.geom.asm.spv"
+    )));
+    let modes = spirv::Ast::<lang::Target>::parse(&module)
+        .unwrap()
+        .get_execution_modes()
+        .unwrap();
+
+    let mode = |mode, arguments: &[u32]| spirv::ExecutionModeInfo {
+        mode,
+        arguments: arguments.to_vec(),
+    };
+    assert_eq!(
+        modes,
+        vec![
+            mode(spirv::ExecutionMode::Invocations, &[2]),
+            mode(spirv::ExecutionMode::Triangles, &[]),
+            mode(spirv::ExecutionMode::OutputVertices, &[3]),
+            mode(spirv::ExecutionMode::OutputTriangleStrip, &[]),
+        ]
+    );
+}
+
+#[test]
+fn ast_sets_and_unsets_execution_modes() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/geometry.geom.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_execution_mode_argument(spirv::ExecutionMode::OutputVertices, 0)
+            .unwrap(),
+        3
+    );
+
+    ast.set_execution_mode(spirv::ExecutionMode::OutputVertices, &[6])
+        .unwrap();
+    ast.unset_execution_mode(spirv::ExecutionMode::Invocations)
+        .unwrap();
+    assert_eq!(
+        ast.get_execution_mode_argument(spirv::ExecutionMode::OutputVertices, 0)
+            .unwrap(),
+        6
+    );
+    let modes = ast
+        .get_execution_modes()
+        .unwrap()
+        .into_iter()
+        .map(|info| info.mode)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        modes,
+        [
+            spirv::ExecutionMode::Triangles,
+            spirv::ExecutionMode::OutputVertices,
+            spirv::ExecutionMode::OutputTriangleStrip,
+        ]
+    );
+
+    assert!(ast
+        .set_execution_mode(spirv::ExecutionMode::LocalSize, &[1, 2, 3, 4])
+        .is_err());
+}
+
+#[test]
+fn ast_gets_shader_clock_usage() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/clock.comp.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_shader_clock_usage(),
+        spirv::ShaderClockUsage {
+            subgroup: true,
+            device: false,
+        }
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_shader_clock_usage(),
+        spirv::ShaderClockUsage::default()
+    );
+}
+
+#[test]
+fn ast_gets_interned_names() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffer = ast.get_shader_resources().unwrap().uniform_buffers[0].clone();
+    // Interned names can be held at once.
+    let member_name = ast
+        .get_member_name_interned(uniform_buffer.base_type_id, 0)
+        .unwrap();
+    let name = ast.get_name_interned(uniform_buffer.id).unwrap();
+    assert_eq!(&*member_name, "u_model_view_projection");
+    assert_eq!(*name, ast.get_name(uniform_buffer.id).unwrap());
+
+    ast.set_name(uniform_buffer.id, "renamed").unwrap();
+    assert_eq!(
+        &*ast.get_name_interned(uniform_buffer.id).unwrap(),
+        "renamed"
+    );
+    assert_eq!(&*member_name, "u_model_view_projection");
+}
+
+#[test]
+fn ast_gets_mesh_outputs() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/mesh.mesh.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let entry_points = ast.get_entry_points().unwrap();
+    assert_eq!(entry_points[0].execution_model, spirv::ExecutionModel::Mesh);
+
+    let spirv::MeshOutputs {
+        per_vertex,
+        per_primitive,
+    } = ast.get_mesh_outputs().unwrap();
+
+    let locations = |outputs: &[spirv::MeshOutput]| {
+        outputs
+            .iter()
+            .filter_map(|output| output.location)
+            .collect::<Vec<_>>()
+    };
+    let built_ins = |outputs: &[spirv::MeshOutput]| {
+        outputs
+            .iter()
+            .filter_map(|output| output.built_in)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(locations(&per_vertex), [0]);
+    assert_eq!(locations(&per_primitive), [1]);
+
+    let per_vertex_built_ins = built_ins(&per_vertex);
+    assert_eq!(per_vertex_built_ins.len(), 2);
+    assert!(per_vertex_built_ins.contains(&spirv::BuiltIn::Position));
+    assert!(per_vertex_built_ins.contains(&spirv::BuiltIn::PrimitiveTriangleIndicesExt));
+    assert_eq!(built_ins(&per_primitive), [spirv::BuiltIn::PrimitiveId]);
+}
+
+#[test]
+fn ast_groups_resources_by_descriptor_set() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let sets = ast.get_resources_by_descriptor_set().unwrap();
+    assert_eq!(sets.keys().copied().collect::<Vec<_>>(), [0]);
+    assert_eq!(sets[&0].len(), 2);
+    assert_eq!(ast.max_set().unwrap(), Some(0));
+    assert_eq!(ast.descriptor_set_count().unwrap(), 1);
+
+    let sampler = ast.get_shader_resources().unwrap().separate_samplers[0].clone();
+    ast.set_decoration(sampler.id, spirv::Decoration::DescriptorSet, 2)
+        .unwrap();
+
+    let sets = ast.get_resources_by_descriptor_set().unwrap();
+    assert_eq!(sets.keys().copied().collect::<Vec<_>>(), [0, 2]);
+    assert_eq!(sets[&2], [sampler]);
+    assert_eq!(ast.max_set().unwrap(), Some(2));
+    assert_eq!(ast.descriptor_set_count().unwrap(), 3);
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/struct.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(ast.max_set().unwrap(), None);
+    assert_eq!(ast.descriptor_set_count().unwrap(), 0);
+}
+
+#[test]
+fn ast_uses_resource_handles() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let mut other_ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let sampler = ast.get_shader_resources().unwrap().separate_samplers[0].clone();
+    let handle = ast.get_resource_handle(&sampler).unwrap();
+    assert_eq!(handle.id(), sampler.id);
+    assert_eq!(
+        ast.get_handle_decoration(handle, spirv::Decoration::Binding)
+            .unwrap(),
+        1
+    );
+
+    ast.set_handle_decoration(handle, spirv::Decoration::Binding, 4)
+        .unwrap();
+    assert_eq!(
+        ast.get_decoration(sampler.id, spirv::Decoration::Binding)
+            .unwrap(),
+        4
+    );
+    ast.set_handle_name(handle, "renamed_sampler").unwrap();
+    assert_eq!(ast.get_handle_name(handle).unwrap(), "renamed_sampler");
+    // The resource reflected before the rename is still found.
+    assert_eq!(ast.get_resource_handle(&sampler).unwrap(), handle);
+
+    assert!(other_ast
+        .get_handle_decoration(handle, spirv::Decoration::Binding)
+        .is_err());
+    assert!(other_ast.set_handle_name(handle, "other").is_err());
+
+    let mut unknown = sampler.clone();
+    unknown.id += 1000;
+    assert!(ast.get_resource_handle(&unknown).is_err());
+}
+
+#[test]
+fn ast_checks_interface_compatibility() {
+    let parse = |bytes: &[u8]| {
+        let module = spirv::Module::from_words(words_from_bytes(bytes));
+        spirv::Ast::<lang::Target>::parse(&module).unwrap()
+    };
+    let simple_vert = parse(include_bytes!("shaders/simple.vert.spv"));
+    let struct_vert = parse(include_bytes!("shaders/struct.vert.spv"));
+    let struct_frag = parse(include_bytes!("shaders/struct.frag.spv"));
+    let sampler_frag = parse(include_bytes!("shaders/sampler.frag.spv"));
+    let compute = parse(include_bytes!("shaders/workgroup.comp.spv"));
+
+    let options = spirv::InterfaceMatchOptions::default();
+    assert!(struct_vert
+        .check_interface_compatibility(&struct_frag, &options)
+        .unwrap()
+        .is_compatible());
+
+    let compatibility = simple_vert
+        .check_interface_compatibility(&sampler_frag, &options)
+        .unwrap();
+    assert_eq!(compatibility.mismatches.len(), 1);
+    match &compatibility.mismatches[0] {
+        spirv::InterfaceMismatch::TypeMismatch {
+            location,
+            component,
+            output,
+            input,
+        } => {
+            assert_eq!((*location, *component), (0, 0));
+            assert_eq!(output.name, "v_normal");
+            assert_eq!(input.name, "v_uv");
+        }
+        mismatch => panic!("unexpected mismatch {:?}", mismatch),
+    }
+    assert!(!simple_vert
+        .check_interface_compatibility(&struct_frag, &options)
+        .unwrap()
+        .is_compatible());
+
+    let mut lenient_options = spirv::InterfaceMatchOptions::default();
+    lenient_options.allow_component_truncation = true;
+    assert!(simple_vert
+        .check_interface_compatibility(&sampler_frag, &lenient_options)
+        .unwrap()
+        .is_compatible());
+
+    let compatibility = compute
+        .check_interface_compatibility(&sampler_frag, &options)
+        .unwrap();
+    match &compatibility.mismatches[..] {
+        [spirv::InterfaceMismatch::MissingOutput { input, .. }] => {
+            assert_eq!(input.name, "v_uv")
+        }
+        mismatches => panic!("unexpected mismatches {:?}", mismatches),
+    }
+
+    assert!(simple_vert
+        .check_interface_compatibility(&compute, &options)
+        .unwrap()
+        .is_compatible());
+    let mut strict_options = spirv::InterfaceMatchOptions::default();
+    strict_options.allow_unused_outputs = false;
+    let compatibility = simple_vert
+        .check_interface_compatibility(&compute, &strict_options)
+        .unwrap();
+    match &compatibility.mismatches[..] {
+        [spirv::InterfaceMismatch::UnusedOutput { output, .. }] => {
+            assert_eq!(output.name, "v_normal")
+        }
+        mismatches => panic!("unexpected mismatches {:?}", mismatches),
+    }
+}
+
+#[test]
+fn ast_checks_interface_compatibility_across_locations() {
+    let parse = |bytes: &[u8]| {
+        let module = spirv::Module::from_words(words_from_bytes(bytes));
+        spirv::Ast::<lang::Target>::parse(&module).unwrap()
+    };
+    let simple_vert = parse(include_bytes!("shaders/simple.vert.spv"));
+    let geom = parse(include_bytes!("shaders/interface.geom.asm.spv"));
+    let matrix_vert = parse(include_bytes!("shaders/matrix_output.vert.asm.spv"));
+    let row_frag = parse(include_bytes!("shaders/matrix_row.frag.asm.spv"));
+
+    let options = spirv::InterfaceMatchOptions::default();
+    assert!(simple_vert
+        .check_interface_compatibility(&geom, &options)
+        .unwrap()
+        .is_compatible());
+
+    let compatibility = matrix_vert
+        .check_interface_compatibility(&row_frag, &options)
+        .unwrap();
+    match &compatibility.mismatches[..] {
+        [spirv::InterfaceMismatch::MissingLocation { resource }, spirv::InterfaceMismatch::TypeMismatch {
+            location,
+            output,
+            input,
+            ..
+        }] => {
+            assert_eq!(resource.name, "v_unlocated");
+            assert_eq!(*location, 2);
+            assert_eq!(output.name, "v_transform");
+            assert_eq!(input.name, "v_row");
+        }
+        mismatches => panic!("unexpected mismatches {:?}", mismatches),
+    }
+}
+
+#[test]
+fn ast_sets_member_name() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let uniform_buffer = &ast.get_shader_resources().unwrap().uniform_buffers[0];
+    let type_id = uniform_buffer.base_type_id;
+
+    assert_eq!(
+        &*ast.get_member_name_interned(type_id, 0).unwrap(),
+        "u_model_view_projection"
+    );
+    ast.set_member_name(type_id, 0, "modelViewProjection")
+        .unwrap();
+    assert_eq!(
+        &*ast.get_member_name_interned(type_id, 0).unwrap(),
+        "modelViewProjection"
+    );
+    assert_eq!(ast.get_member_name(type_id, 1).unwrap(), "u_scale");
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("modelViewProjection"));
+    assert!(!shader.contains("u_model_view_projection"));
+}
+
+#[test]
+fn ast_renames_resources_for_reflection() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let uniform_buffer = ast.get_shader_resources().unwrap().uniform_buffers[0].clone();
+    assert_eq!(uniform_buffer.name, "uniform_buffer_object");
+
+    ast.set_name(uniform_buffer.base_type_id, "Globals")
+        .unwrap();
+    ast.set_member_name(uniform_buffer.base_type_id, 1, "scale")
+        .unwrap();
+    let ast = &ast;
+    assert_eq!(
+        ast.get_name(uniform_buffer.base_type_id).unwrap(),
+        "Globals"
+    );
+    assert_eq!(
+        ast.get_member_name(uniform_buffer.base_type_id, 1).unwrap(),
+        "scale"
+    );
+    assert_eq!(
+        ast.get_shader_resources().unwrap().uniform_buffers[0].name,
+        "Globals"
+    );
+}
+
+#[test]
+fn ast_gets_and_sets_decoration_strings() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/hlsl_semantics.frag.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let resources = ast.get_shader_resources().unwrap();
+    let input = resources.stage_inputs[0].id;
+    let output = resources.stage_outputs[0].id;
+    let texture = resources.separate_images[0].id;
+
+    assert_eq!(
+        ast.get_decoration_string(input, spirv::Decoration::HlslSemanticGoogle)
+            .unwrap(),
+        "TEXCOORD0"
+    );
+    assert_eq!(
+        ast.get_decoration_string(output, spirv::Decoration::HlslSemanticGoogle)
+            .unwrap(),
+        "SV_Target"
+    );
+    assert_eq!(
+        ast.get_decoration_string(texture, spirv::Decoration::UserTypeGoogle)
+            .unwrap(),
+        "texture2d:<float4>"
+    );
+    assert_eq!(
+        ast.get_decoration_string(texture, spirv::Decoration::HlslSemanticGoogle)
+            .unwrap(),
+        ""
+    );
+
+    ast.set_decoration_string(input, spirv::Decoration::HlslSemanticGoogle, "UV")
+        .unwrap();
+    assert_eq!(
+        ast.get_decoration_string(input, spirv::Decoration::HlslSemanticGoogle)
+            .unwrap(),
+        "UV"
+    );
+}
+
+#[test]
+fn module_reflects_source_files() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/source_files.comp.asm.spv"
+    )));
+    let files = module.get_source_files();
+    let summary = files
+        .iter()
+        .map(|file| (file.name.as_str(), file.entry_points.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            ("shaders/main.comp", vec![String::from("main")]),
+            ("shaders/common.glsl", vec![String::from("main")]),
+            ("shaders/other.comp", vec![String::from("other")]),
+        ]
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    assert!(module.get_source_files().is_empty());
+}
+
+#[test]
+fn compiles_concurrently_from_32_threads() {
+    let threads = (0..32)
+        .map(|thread_index| {
+            std::thread::spawn(move || {
+                let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+                    "shaders/simple.vert.spv"
+                )));
+                for iteration in 0..16 {
+                    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+                    let mut compiler_options = lang::CompilerOptions::default();
+                    if (thread_index + iteration) % 2 == 0 {
+                        ast.set_compiler_options(&compiler_options).unwrap();
+                        assert!(ast.compile().unwrap().contains("u_model_view_projection"));
+                    } else {
+                        // Failing calls exercise the error message path concurrently.
+                        compiler_options.entry_point =
+                            Some((String::from("missing"), spirv::ExecutionModel::Vertex));
+                        match ast.set_compiler_options(&compiler_options) {
+                            Err(spirv_cross::ErrorCode::CompilationError(message)) => {
+                                assert!(!message.is_empty())
+                            }
+                            result => panic!("unexpected result {:?}", result),
+                        }
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
+
+#[test]
+fn ast_gets_layered_rendering_outputs() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/layer.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_layered_rendering_outputs().unwrap(),
+        spirv::LayeredRenderingOutputs {
+            layer: true,
+            viewport_index: false,
+            from_vertex_processing: true,
+        }
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_layered_rendering_outputs().unwrap(),
+        spirv::LayeredRenderingOutputs {
+            layer: false,
+            viewport_index: false,
+            from_vertex_processing: false,
+        }
+    );
+}
+
+#[test]
+fn ast_gets_padded_struct_layout() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/array.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let base_type_id = ast.get_shader_resources().unwrap().uniform_buffers[0].base_type_id;
+
+    let fields = ast
+        .get_padded_struct_layout(base_type_id)
+        .unwrap()
+        .into_iter()
+        .map(|field| (field.name, field.offset, field.size, field.member_index))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        vec![
+            (String::from("u_model_view_projection"), 0, 64, Some(0)),
+            (String::from("u_scale"), 64, 4, Some(1)),
+            (String::from("_pad2"), 68, 12, None),
+            (String::from("u_bias"), 80, 48, Some(2)),
+        ]
+    );
+}
+
+#[test]
+fn ast_gets_fragment_depth_modes() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/depth.frag.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_fragment_depth_modes().unwrap(),
+        spirv::FragmentDepthModes {
+            writes_depth: true,
+            conservative_depth: Some(spirv::ConservativeDepth::Greater),
+            early_fragment_tests: false,
+        }
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_fragment_depth_modes().unwrap(),
+        spirv::FragmentDepthModes::default()
+    );
+}
+
+#[test]
+fn ast_gets_type_details() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/array.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let uniform_buffer = &ast.get_shader_resources().unwrap().uniform_buffers[0];
+    let details = ast.get_type_details(uniform_buffer.type_id).unwrap();
+    assert_eq!(
+        details.pointer,
+        Some(spirv::PointerType {
+            storage_class: spirv::StorageClass::Uniform,
+            pointee_type_id: uniform_buffer.base_type_id,
+        })
+    );
+
+    let member_types = match ast.get_type(uniform_buffer.base_type_id).unwrap() {
+        spirv::Type::Struct { member_types, .. } => member_types,
+        _ => panic!("uniform buffer should be a struct"),
+    };
+    let details = ast.get_type_details(member_types[2]).unwrap();
+    assert_eq!(details.pointer, None);
+    assert_eq!(
+        details.array_dimensions,
+        vec![spirv::ArrayDimension::Literal(3)]
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/spec_array.comp.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let storage_buffer = &ast.get_shader_resources().unwrap().storage_buffers[0];
+    let member_types = match ast.get_type(storage_buffer.base_type_id).unwrap() {
+        spirv::Type::Struct { member_types, .. } => member_types,
+        _ => panic!("storage buffer should be a struct"),
+    };
+    let constant_id = ast.get_specialization_constants().unwrap()[0].id;
+    assert_eq!(
+        ast.get_type_details(member_types[0])
+            .unwrap()
+            .array_dimensions,
+        vec![spirv::ArrayDimension::SpecializationConstant(constant_id)]
+    );
+}
+
+#[test]
+fn ast_gets_transform_feedback_outputs() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/xfb.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let outputs = ast
+        .get_transform_feedback_outputs()
+        .unwrap()
+        .into_iter()
+        .map(|output| {
+            (
+                output.name,
+                output.member_index,
+                output.buffer,
+                output.stride,
+                output.offset,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        outputs,
+        vec![
+            (String::from("v_position"), None, 0, 16, 0),
+            (String::from("Outputs.uv"), Some(2), 1, 32, 0),
+            (String::from("Outputs.normal"), Some(0), 1, 32, 16),
+        ]
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert!(ast.get_transform_feedback_outputs().unwrap().is_empty());
+}
+
+#[test]
+fn ast_gets_struct_member_layout() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/array.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let base_type_id = ast.get_shader_resources().unwrap().uniform_buffers[0].base_type_id;
+
+    assert_eq!(
+        ast.get_struct_member_layout(base_type_id, 0).unwrap(),
+        spirv::StructMemberLayout {
+            offset: 0,
+            size: 64,
+            array_stride: None,
+            matrix_stride: Some(16),
+            row_major: false,
+        }
+    );
+    assert_eq!(
+        ast.get_struct_member_layout(base_type_id, 2).unwrap(),
+        spirv::StructMemberLayout {
+            offset: 80,
+            size: 48,
+            array_stride: Some(16),
+            matrix_stride: None,
+            row_major: false,
+        }
+    );
+    assert!(ast.get_struct_member_layout(base_type_id, 3).is_err());
+}
+
+#[test]
+fn ast_compiles_into_reused_buffer() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let shader = ast.compile().unwrap();
+
+    let mut buffer = String::with_capacity(shader.len());
+    buffer.push_str("stale contents");
+    let capacity = buffer.capacity();
+    ast.compile_into(&mut buffer).unwrap();
+    assert_eq!(buffer, shader);
+    assert_eq!(buffer.capacity(), capacity);
+}
+
+#[test]
+fn ast_gets_required_builtins() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/base_vertex.vert.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let builtins = ast.required_builtins().unwrap();
+    assert_eq!(
+        builtins.iter().collect::<Vec<_>>(),
+        [
+            spirv::BuiltIn::Position,
+            spirv::BuiltIn::BaseVertex,
+            spirv::BuiltIn::BaseInstance,
+        ]
+    );
+    assert!(!builtins.contains(spirv::BuiltIn::VertexIndex));
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/num_workgroups.comp.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let builtins = ast.required_builtins().unwrap();
+    assert_eq!(builtins.len(), 1);
+    assert!(builtins.contains(spirv::BuiltIn::NumWorkgroups));
+}
+
+#[test]
+fn ast_gets_ray_tracing_resources() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/shader_record.rgen.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let resources = ast.get_shader_resources().unwrap();
+    assert_eq!(resources.acceleration_structures.len(), 1);
+    assert_eq!(resources.acceleration_structures[0].name, "tlas");
+    assert_eq!(resources.shader_record_buffers.len(), 1);
+    assert_eq!(resources.shader_record_buffers[0].name, "Record");
+    assert!(resources.storage_buffers.is_empty());
+
+    let sets = ast.get_resources_by_descriptor_set().unwrap();
+    assert_eq!(sets[&0], resources.acceleration_structures);
+}
+
+#[test]
+fn ast_gets_image_resources() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/images.frag.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let images = ast
+        .get_image_resources()
+        .unwrap()
+        .into_iter()
+        .map(|image| {
+            (
+                image.resource.name,
+                image.kind,
+                image.image_type.dimension,
+                image.image_type.arrayed,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        images,
+        [
+            (
+                String::from("environment"),
+                spirv::ImageResourceKind::SampledImage,
+                spirv::ImageDimension::Cube,
+                false,
+            ),
+            (
+                String::from("volume"),
+                spirv::ImageResourceKind::SeparateImage,
+                spirv::ImageDimension::Dim3D,
+                false,
+            ),
+            (
+                String::from("layers"),
+                spirv::ImageResourceKind::StorageImage,
+                spirv::ImageDimension::Dim2D,
+                true,
+            ),
+        ]
+    );
+    let resources = ast.get_shader_resources().unwrap();
+    assert_eq!(resources.separate_samplers.len(), 1);
+    assert_eq!(resources.separate_samplers[0].name, "volume_sampler");
+}
+
+#[test]
+fn ast_gets_image_types() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/image_types.comp.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let resources = ast.get_shader_resources().unwrap();
+
+    assert_eq!(
+        ast.get_image_type(&resources.sampled_images[0]).unwrap(),
+        spirv::ImageType {
+            dimension: spirv::ImageDimension::Dim2D,
+            arrayed: true,
+            multisampled: false,
+            depth: true,
+            storage: false,
+            format: None,
+            access: spirv::ImageAccess::ReadOnly,
+        }
+    );
+    let storage_images = resources
+        .storage_images
+        .iter()
+        .map(|image| (image.name.as_str(), ast.get_image_type(image).unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        storage_images,
+        [
+            (
+                "source",
+                spirv::ImageType {
+                    dimension: spirv::ImageDimension::Dim2D,
+                    arrayed: false,
+                    multisampled: false,
+                    depth: false,
+                    storage: true,
+                    format: Some(spirv::ImageFormat::Rgba8),
+                    access: spirv::ImageAccess::ReadOnly,
+                }
+            ),
+            (
+                "destination",
+                spirv::ImageType {
+                    dimension: spirv::ImageDimension::Dim3D,
+                    arrayed: false,
+                    multisampled: false,
+                    depth: false,
+                    storage: true,
+                    format: Some(spirv::ImageFormat::R32f),
+                    access: spirv::ImageAccess::WriteOnly,
+                }
+            ),
+            (
+                "resolve",
+                spirv::ImageType {
+                    dimension: spirv::ImageDimension::Dim2D,
+                    arrayed: false,
+                    multisampled: true,
+                    depth: false,
+                    storage: true,
+                    format: Some(spirv::ImageFormat::Rgba16f),
+                    access: spirv::ImageAccess::ReadWrite,
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn ast_gets_image_type_of_subpass_input() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/subpass_input.frag.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let images = ast.get_image_resources().unwrap();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].kind, spirv::ImageResourceKind::SubpassInput);
+    assert_eq!(
+        images[0].image_type,
+        spirv::ImageType {
+            dimension: spirv::ImageDimension::SubpassData,
+            arrayed: false,
+            multisampled: false,
+            depth: false,
+            storage: false,
+            format: None,
+            access: spirv::ImageAccess::ReadOnly,
+        }
+    );
+}
+
+#[test]
+fn ast_fails_to_get_image_type_of_buffer() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let uniform_buffer = &ast.get_shader_resources().unwrap().uniform_buffers[0];
+    assert_eq!(
+        ast.get_image_type(uniform_buffer),
+        Err(spirv_cross::ErrorCode::CompilationError(String::from(
+            "`uniform_buffer_object` is not an image"
+        )))
+    );
+}
+
+#[test]
+fn ast_gets_buffer_block_flags() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/buffer_flags.comp.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let storage_buffers = ast.get_shader_resources().unwrap().storage_buffers;
+    let flags = |name: &str| {
+        let buffer = storage_buffers.iter().find(|b| b.name == name).unwrap();
+        ast.get_buffer_block_flags(buffer.id).unwrap()
+    };
+
+    assert_eq!(
+        flags("Input"),
+        spirv::BufferBlockFlags {
+            non_writable: true,
+            restrict: true,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        flags("Output"),
+        spirv::BufferBlockFlags {
+            non_readable: true,
+            coherent: true,
+            ..Default::default()
+        }
+    );
+}
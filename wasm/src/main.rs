@@ -34,6 +34,11 @@ fn main() {
                 "_sc_internal_compiler_glsl_get_combined_image_samplers",
                 "_sc_internal_compiler_glsl_add_header_line",
                 "_sc_internal_compiler_glsl_flatten_buffer_block",
+                "_sc_internal_compiler_glsl_is_base_instance_used",
+                "_sc_internal_compiler_glsl_build_dummy_sampler_for_combined_images",
+                "_sc_internal_compiler_glsl_require_extension",
+                "_sc_internal_compiler_glsl_remap_ext_framebuffer_fetch",
+                "_sc_internal_compiler_glsl_set_variable_type_remaps",
                 "_sc_internal_compiler_get_decoration",
                 "_sc_internal_compiler_set_decoration",
                 "_sc_internal_compiler_unset_decoration",
@@ -53,6 +58,31 @@ fn main() {
                 "_sc_internal_compiler_rename_interface_variable",
                 "_sc_internal_compiler_get_work_group_size_specialization_constants",
                 "_sc_internal_compiler_set_entry_point",
+                "_sc_internal_compiler_get_declared_capabilities",
+                "_sc_internal_compiler_get_declared_extensions",
+                "_sc_internal_compiler_get_geometry_execution_modes",
+                "_sc_internal_compiler_get_mesh_outputs",
+                "_sc_internal_compiler_get_current_id_bound",
+                "_sc_internal_compiler_set_member_name",
+                "_sc_internal_compiler_get_execution_model",
+                "_sc_internal_compiler_get_image_type",
+                "_sc_internal_compiler_get_type_self",
+                "_sc_internal_compiler_is_builtin_output_active",
+                "_sc_internal_compiler_get_depth_execution_modes",
+                "_sc_internal_compiler_has_decoration",
+                "_sc_internal_compiler_has_member_decoration",
+                "_sc_internal_compiler_unset_member_decoration",
+                "_sc_internal_compiler_get_execution_modes",
+                "_sc_internal_compiler_get_execution_mode_argument",
+                "_sc_internal_compiler_get_active_interface_variables",
+                "_sc_internal_compiler_get_active_shader_resources",
+                "_sc_internal_compiler_set_enabled_interface_variables",
+                "_sc_internal_compiler_get_active_builtins",
+                "_sc_internal_compiler_set_execution_mode",
+                "_sc_internal_compiler_unset_execution_mode",
+                "_sc_internal_compiler_get_buffer_block_flags",
+                "_sc_internal_compiler_get_decoration_string",
+                "_sc_internal_compiler_set_decoration_string",
                 "_sc_internal_compiler_compile",
                 "_sc_internal_compiler_delete",
                 "_sc_internal_free_pointer"